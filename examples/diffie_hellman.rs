@@ -1,10 +1,10 @@
 use ronkathon::{
   algebra::{field::prime::PlutoScalarField, group::FiniteCyclicGroup},
-  curve::{pluto_curve::PlutoBaseCurve, AffinePoint},
+  curve::{pluto_curve::PlutoBaseCurve, subgroup::PrimeOrderPoint},
   diffie_hellman::ecdh::compute_shared_secret,
 };
 
-const G: AffinePoint<PlutoBaseCurve> = AffinePoint::GENERATOR;
+const G: PrimeOrderPoint<PlutoBaseCurve> = PrimeOrderPoint::GENERATOR;
 
 fn main() {
   let alice_secret = PlutoScalarField::new(420);
@@ -13,6 +13,10 @@ fn main() {
   let alice_public = G * alice_secret;
   let bob_public = G * bob_secret;
 
+  // the public keys are sent as affine points, and checked to be in the prime order subgroup
+  let alice_public = PrimeOrderPoint::new(alice_public.point()).expect("invalid public key");
+  let bob_public = PrimeOrderPoint::new(bob_public.point()).expect("invalid public key");
+
   let shared_secret_alice = compute_shared_secret(alice_secret, bob_public);
   let shared_secret_bob = compute_shared_secret(bob_secret, alice_public);
 
@@ -1,121 +1,32 @@
-//! Implements [dihedral][dihedral] group of degree 3 and order 6 using ronkathon's [`Group`] and
-//! [`FiniteGroup`] trait.
+//! Uses ronkathon's [`SymmetricGroup`] of degree 3 and order 6, which is also the
+//! [dihedral][dihedral] group [`DihedralGroup`] of the symmetries of a triangle.
 //!
 //! Consider a symmetric group containing all permutation of 3 distinct
 //! elements: `[a, b, c]`. Total number of elements is 3! = 6. Each element of the group is a
 //! permutation operation.
 //!
 //! ## Example
-//! Let `a=[2, 1, 3]` be an element of the group, when applied to any 3-length vector performs the
-//! swap of 1st and 2nd element. So, `RGB->GRB`.
+//! Let `a=[1, 0, 2]` be an element of the group, when applied to any 3-length vector performs the
+//! swap of 1st and 2nd element. So, `RGB->GRB`. In cycle notation it is written `(0 1)`.
 //!
 //! ## Operation
 //! Group operation is defined as combined action of performing permutation twice, i.e. take `x,y`
 //! two distinct element of the group. `a·b` is applying permutation `b` first then `a`.
 //!
 //! [dihedral]: https://en.wikipedia.org/wiki/Dihedral_group_of_order_6
-use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-
-use ronkathon::algebra::{
-  group::{FiniteGroup, Group},
-  Finite,
+use ronkathon::algebra::group::{
+  dihedral::DihedralGroup, generate_subgroup, symmetric::SymmetricGroup, FiniteGroup, Group,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct DihedralGroup {
-  mapping: [usize; 3],
-}
-
-impl Finite for DihedralGroup {
-  const ORDER: usize = 6;
-}
-
-impl Group for DihedralGroup {
-  type Scalar = usize;
-
-  const IDENTITY: Self = Self::new([0, 1, 2]);
-
-  fn op(&self, rhs: &Self) -> Self {
-    let mut new_mapping = [0; 3];
-    for (i, &j) in self.mapping.iter().enumerate() {
-      new_mapping[i] = rhs.mapping[j];
-    }
-
-    Self::new(new_mapping)
-  }
-
-  fn inverse(&self) -> Option<Self> {
-    let mut inverse_mapping = [0; 3];
-    for (i, &j) in self.mapping.iter().enumerate() {
-      inverse_mapping[j] = i;
-    }
-
-    Some(Self::new(inverse_mapping))
-  }
-
-  fn scalar_mul(&self, scalar: Self::Scalar) -> Self {
-    let mut res = *self;
-    for _ in 0..scalar {
-      res = res.op(self);
-    }
-    res
-  }
-}
-
-impl DihedralGroup {
-  const fn new(mapping: [usize; 3]) -> Self { Self { mapping } }
-}
-
-impl FiniteGroup for DihedralGroup {}
-
-impl Default for DihedralGroup {
-  fn default() -> Self { Self::IDENTITY }
-}
-
-impl Add for DihedralGroup {
-  type Output = Self;
-
-  fn add(self, rhs: Self) -> Self::Output { Self::op(&self, &rhs) }
-}
-
-impl AddAssign for DihedralGroup {
-  fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
-}
-
-impl Neg for DihedralGroup {
-  type Output = Self;
-
-  fn neg(self) -> Self::Output { Self::inverse(&self).expect("inverse does not exist") }
-}
-
-impl Sub for DihedralGroup {
-  type Output = Self;
-
-  fn sub(self, rhs: Self) -> Self::Output { self + -rhs }
-}
-
-impl SubAssign for DihedralGroup {
-  fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
-}
-
-impl Mul<usize> for DihedralGroup {
-  type Output = Self;
-
-  fn mul(self, rhs: usize) -> Self::Output { Self::scalar_mul(&self, rhs) }
-}
-
-impl MulAssign<usize> for DihedralGroup {
-  fn mul_assign(&mut self, rhs: usize) { *self = *self * rhs; }
-}
-
 fn main() {
-  let ident = DihedralGroup::default();
-  let a = DihedralGroup::new([1, 0, 2]);
-  let b = DihedralGroup::new([0, 2, 1]);
+  let ident = SymmetricGroup::<3>::default();
+  let a = SymmetricGroup::new([1, 0, 2]);
+  let b: SymmetricGroup<3> = "(1 2)".parse().unwrap();
 
   // closure
   let ab = a.op(&b);
   let ba = b.op(&a);
+  println!("a = {a}, b = {b}, ab = {ab}, ba = {ba}");
 
   // identity
   assert_eq!(a, ident.op(&a));
@@ -132,4 +43,13 @@ fn main() {
   // inverse
   assert_eq!(a.op(&a.inverse().unwrap()), ident);
   assert_eq!(ab.inverse().unwrap(), ba);
+
+  // a transposition and a 3-cycle generate the whole group
+  assert_eq!(generate_subgroup(&[a, ab]).len(), 6);
+
+  // every permutation of the vertices of a triangle is one of its symmetries
+  let r = DihedralGroup::<3>::ROTATION;
+  let s = DihedralGroup::<3>::REFLECTION;
+  assert_eq!(DihedralGroup::try_from(ab).unwrap().to_permutation(), ab);
+  assert_eq!(r.op(&s).op(&s), r);
 }
@@ -0,0 +1,82 @@
+//! Exponentiation and inversion helpers that save multiplications over [`Field::pow`] and
+//! [`Field::inverse`].
+//!
+//! [`Field`] is a `#[const_trait]`, so its provided methods are checked as const functions and
+//! could not use iterators. These helpers live in [`BatchArithmetic`] instead, which every field
+//! implements.
+
+use super::*;
+
+/// Sliding-window exponentiation, multi-exponentiation and batch inversion, provided for every
+/// [`Field`].
+pub trait BatchArithmetic: Field {
+  /// Computes the power of the field element with a sliding window of up to 4 bits. After
+  /// precomputing the odd powers `x, x^3, ..., x^15`, every run of bits starting and ending with a
+  /// one costs a single multiplication, so about `log(power) / 5` multiplications are needed on top
+  /// of the squarings instead of one for every set bit.
+  fn pow_sliding_window(self, power: usize) -> Self {
+    let square = self * self;
+    let odd_powers: Vec<Self> =
+      std::iter::successors(Some(self), |&x| Some(x * square)).take(8).collect();
+
+    let mut result = Self::ONE;
+    // number of bits of `power` that are left to process
+    let mut bits = (usize::BITS - power.leading_zeros()) as usize;
+    while bits > 0 {
+      if (power >> (bits - 1)) & 1 == 0 {
+        result = result * result;
+        bits -= 1;
+        continue;
+      }
+      // the longest window of at most 4 bits starting at the current one and ending in a one
+      let low = (bits.saturating_sub(4)..bits).find(|&low| (power >> low) & 1 == 1).unwrap();
+      result = (low..bits).fold(result, |result, _| result * result);
+      result *= odd_powers[((power >> low) & ((1 << (bits - low)) - 1)) >> 1];
+      bits = low;
+    }
+    result
+  }
+
+  /// Computes `bases[0]^exponents[0] * ... * bases[N-1]^exponents[N-1]` with
+  /// [Shamir's trick](https://en.wikipedia.org/wiki/Exponentiation_by_squaring#Simultaneous_exponentiation):
+  /// the exponents are scanned together from the most significant bit, so all `N` powers share a
+  /// single chain of squarings.
+  fn multi_pow<const N: usize>(bases: [Self; N], exponents: [usize; N]) -> Self {
+    let max = exponents.iter().fold(0, |max, exponent| max | exponent);
+    (0..usize::BITS - max.leading_zeros()).rev().fold(Self::ONE, |result, bit| {
+      bases
+        .iter()
+        .zip(exponents)
+        .filter(|(_, exponent)| (exponent >> bit) & 1 == 1)
+        .fold(result * result, |result, (&base, _)| result * base)
+    })
+  }
+
+  /// Inverts all elements at once with Montgomery's trick: the running products
+  /// `a_0, a_0 a_1, ..., a_0 ... a_{N-1}` are inverted with a single call to [`Field::inverse`],
+  /// and the inverse of each `a_i` is peeled off walking back, for a total of `3(N - 1)`
+  /// multiplications. Zero has no inverse, so if any element is zero the product cannot be
+  /// inverted and the elements are inverted one at a time instead.
+  fn batch_inverse<const N: usize>(elements: [Self; N]) -> [Option<Self>; N] {
+    // prefix[i] = a_0 ... a_{i-1}
+    let mut prefix = [Self::ONE; N];
+    let mut product = Self::ONE;
+    for (prefix, &element) in prefix.iter_mut().zip(&elements) {
+      *prefix = product;
+      product *= element;
+    }
+
+    let Some(mut inverse) = product.inverse() else {
+      return elements.map(|element| element.inverse());
+    };
+    let mut inverses = [None; N];
+    for ((inverse_i, prefix), element) in inverses.iter_mut().zip(prefix).zip(elements).rev() {
+      // inverse = (a_0 ... a_i)^-1 at this point
+      *inverse_i = Some(inverse * prefix);
+      inverse *= element;
+    }
+    inverses
+  }
+}
+
+impl<F: Field> BatchArithmetic for F {}
@@ -0,0 +1,103 @@
+use super::*;
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Add for BigPrimeField<M, LIMBS> {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self { Self { value: self.value + rhs.value } }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> AddAssign for BigPrimeField<M, LIMBS> {
+  fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Sum for BigPrimeField<M, LIMBS> {
+  fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.reduce(|x, y| x + y).unwrap_or(Self::ZERO)
+  }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Sub for BigPrimeField<M, LIMBS> {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self { Self { value: self.value - rhs.value } }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> SubAssign for BigPrimeField<M, LIMBS> {
+  fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Mul for BigPrimeField<M, LIMBS> {
+  type Output = Self;
+
+  fn mul(self, rhs: Self) -> Self { Self { value: self.value * rhs.value } }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> MulAssign for BigPrimeField<M, LIMBS> {
+  fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Product for BigPrimeField<M, LIMBS> {
+  fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.reduce(|x, y| x * y).unwrap_or(Self::ONE)
+  }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Div for BigPrimeField<M, LIMBS> {
+  type Output = Self;
+
+  #[allow(clippy::suspicious_arithmetic_impl)]
+  fn div(self, rhs: Self) -> Self { self * rhs.inverse().unwrap() }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> DivAssign for BigPrimeField<M, LIMBS> {
+  fn div_assign(&mut self, rhs: Self) { *self = *self / rhs; }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Neg for BigPrimeField<M, LIMBS> {
+  type Output = Self;
+
+  fn neg(self) -> Self::Output { Self { value: -self.value } }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Rem for BigPrimeField<M, LIMBS> {
+  type Output = Self;
+
+  fn rem(self, rhs: Self) -> Self { self - (self / rhs) * rhs }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn combined_arithmetic() {
+    let mut rng = rand::thread_rng();
+    let x = rng.gen::<Bn254ScalarField>();
+    let y = rng.gen::<Bn254ScalarField>();
+    let z = rng.gen::<Bn254ScalarField>();
+    assert_eq!(x + (-x), Bn254ScalarField::ZERO);
+    assert_eq!(-x, Bn254ScalarField::ZERO - x);
+    assert_eq!(x + x, x * Bn254ScalarField::from(2u64));
+    assert_eq!(x, x.div(Bn254ScalarField::from(2u64)) * Bn254ScalarField::from(2u64));
+    assert_eq!(x * (-x), -(x * x));
+    assert_eq!(x * y, y * x);
+    assert_eq!(x * (y * z), (x * y) * z);
+    assert_eq!(x - (y + z), (x - y) - z);
+    assert_eq!((x + y) - z, x + (y - z));
+    assert_eq!(x * (y + z), x * y + x * z);
+    assert_eq!(x + y + z + x + y + z, [x, x, y, y, z, z].iter().cloned().sum());
+    assert_eq!(x * y * z, [x, y, z].iter().cloned().product());
+  }
+
+  #[test]
+  fn small_values() {
+    let a = Bn254ScalarField::from(12u64);
+    let b = Bn254ScalarField::from(5u64);
+    assert_eq!(a + b, Bn254ScalarField::from(17u64));
+    assert_eq!(a - b, Bn254ScalarField::from(7u64));
+    assert_eq!(b - a, -Bn254ScalarField::from(7u64));
+    assert_eq!(a * b, Bn254ScalarField::from(60u64));
+    assert_eq!(Bn254ScalarField::from(-3), -Bn254ScalarField::from(3u64));
+    assert_eq!(a.pow(3), Bn254ScalarField::from(1728u64));
+  }
+}
@@ -0,0 +1,478 @@
+//! This module contains prime fields whose modulus does not fit in a machine word.
+//! [`BigPrimeField`] stores its elements in Montgomery form using [`crypto_bigint`]'s
+//! [`ConstMontyForm`], so any prime that can be written as a [`Uint`] can be used as a modulus.
+//! The [`Field`] and [`FiniteField`] traits are implemented so the rest of the library (e.g.
+//! [`Polynomial`][crate::polynomial::Polynomial] or
+//! [`Poseidon`][crate::hashes::poseidon::Poseidon]) works over these fields without modification.
+//!
+//! A modulus is declared with [`crypto_bigint::impl_modulus`] and then tagged with a multiplicative
+//! generator through the [`BigPrimeParams`] trait, see [`Bn254ScalarModulus`] for an example.
+
+use std::{fmt, hash::Hasher, str::FromStr};
+
+use crypto_bigint::{
+  impl_modulus,
+  modular::{ConstMontyForm, ConstMontyParams},
+  Limb, NonZero, Uint, Word, U256, U384,
+};
+use rand::{distributions::Standard, prelude::Distribution, Rng};
+
+use super::*;
+use crate::algebra::Finite;
+
+mod arithmetic;
+
+/// Parameters of a [`BigPrimeField`]: the Montgomery constants of the modulus (usually derived with
+/// [`crypto_bigint::impl_modulus`]) together with a multiplicative generator of the field.
+pub trait BigPrimeParams<const LIMBS: usize>: ConstMontyParams<LIMBS> {
+  /// A [primitive element](https://en.wikipedia.org/wiki/Primitive_element_(finite_field)) of the
+  /// field given as a canonical integer.
+  const PRIMITIVE_ELEMENT: Uint<LIMBS>;
+}
+
+impl_modulus!(
+  Bn254ScalarModulus,
+  U256,
+  "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+  "Order of the prime order subgroup of the BN254 curve. `r - 1` is divisible by `2^28`, which \
+   makes it a convenient field for FFTs over 256-bit elements."
+);
+
+impl BigPrimeParams<{ U256::LIMBS }> for Bn254ScalarModulus {
+  const PRIMITIVE_ELEMENT: U256 = U256::from_u8(5);
+}
+
+/// The [`Bn254ScalarField`] is the scalar field of the BN254 curve, a 254-bit prime field.
+pub type Bn254ScalarField = BigPrimeField<Bn254ScalarModulus, { U256::LIMBS }>;
+
+impl_modulus!(
+  Bn254BaseModulus,
+  U256,
+  "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+  "The prime `36x^4 + 36x^3 + 24x^2 + 6x + 1` of the base field of the BN254 curve, for `x = \
+   4965661367192848881`."
+);
+
+impl BigPrimeParams<{ U256::LIMBS }> for Bn254BaseModulus {
+  const PRIMITIVE_ELEMENT: U256 = U256::from_u8(3);
+}
+
+/// The [`Bn254BaseField`] is the base field of the BN254 curve, also known as alt_bn128.
+pub type Bn254BaseField = BigPrimeField<Bn254BaseModulus, { U256::LIMBS }>;
+
+impl_modulus!(
+  Bls12381BaseModulus,
+  U384,
+  "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+  "The 381-bit prime `(x - 1)^2 (x^4 - x^2 + 1) / 3 + x` of the base field of the BLS12-381 \
+   curve, for `x = -0xd201000000010000`."
+);
+
+impl BigPrimeParams<{ U384::LIMBS }> for Bls12381BaseModulus {
+  const PRIMITIVE_ELEMENT: U384 = U384::from_u8(2);
+}
+
+/// The [`Bls12381BaseField`] is the base field of the BLS12-381 curve, with 6 limbs.
+pub type Bls12381BaseField = BigPrimeField<Bls12381BaseModulus, { U384::LIMBS }>;
+
+impl_modulus!(
+  Bls12381ScalarModulus,
+  U256,
+  "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+  "Order `x^4 - x^2 + 1` of the prime order subgroup of the BLS12-381 curve. `r - 1` is divisible \
+   by `2^32`."
+);
+
+impl BigPrimeParams<{ U256::LIMBS }> for Bls12381ScalarModulus {
+  const PRIMITIVE_ELEMENT: U256 = U256::from_u8(7);
+}
+
+/// The [`Bls12381ScalarField`] is the scalar field of the BLS12-381 curve.
+pub type Bls12381ScalarField = BigPrimeField<Bls12381ScalarModulus, { U256::LIMBS }>;
+
+impl_modulus!(
+  Secp256k1BaseModulus,
+  U256,
+  "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+  "The prime `2^256 - 2^32 - 977` of the base field of the secp256k1 curve."
+);
+
+impl BigPrimeParams<{ U256::LIMBS }> for Secp256k1BaseModulus {
+  const PRIMITIVE_ELEMENT: U256 = U256::from_u8(3);
+}
+
+/// The [`Secp256k1BaseField`] is the base field of the secp256k1 curve used by Bitcoin.
+pub type Secp256k1BaseField = BigPrimeField<Secp256k1BaseModulus, { U256::LIMBS }>;
+
+impl_modulus!(
+  Secp256k1ScalarModulus,
+  U256,
+  "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+  "The order of the secp256k1 curve, which is prime."
+);
+
+impl BigPrimeParams<{ U256::LIMBS }> for Secp256k1ScalarModulus {
+  const PRIMITIVE_ELEMENT: U256 = U256::from_u8(7);
+}
+
+/// The [`Secp256k1ScalarField`] is the scalar field of the secp256k1 curve.
+pub type Secp256k1ScalarField = BigPrimeField<Secp256k1ScalarModulus, { U256::LIMBS }>;
+
+impl_modulus!(
+  P256BaseModulus,
+  U256,
+  "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+  "The prime `2^256 - 2^224 + 2^192 + 2^96 - 1` of the base field of the NIST P-256 curve."
+);
+
+impl BigPrimeParams<{ U256::LIMBS }> for P256BaseModulus {
+  const PRIMITIVE_ELEMENT: U256 = U256::from_u8(6);
+}
+
+/// The [`P256BaseField`] is the base field of the NIST P-256 curve, also known as secp256r1.
+pub type P256BaseField = BigPrimeField<P256BaseModulus, { U256::LIMBS }>;
+
+impl_modulus!(
+  P256ScalarModulus,
+  U256,
+  "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+  "The order of the NIST P-256 curve, which is prime."
+);
+
+impl BigPrimeParams<{ U256::LIMBS }> for P256ScalarModulus {
+  const PRIMITIVE_ELEMENT: U256 = U256::from_u8(7);
+}
+
+/// The [`P256ScalarField`] is the scalar field of the NIST P-256 curve.
+pub type P256ScalarField = BigPrimeField<P256ScalarModulus, { U256::LIMBS }>;
+
+/// The [`BigPrimeField`] struct represents elements of a field with prime order given by the
+/// modulus of `M`. Elements are kept in Montgomery form, use [`BigPrimeField::value`] to get the
+/// canonical integer back.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct BigPrimeField<M: BigPrimeParams<LIMBS>, const LIMBS: usize> {
+  pub(crate) value: ConstMontyForm<M, LIMBS>,
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> BigPrimeField<M, LIMBS> {
+  /// The modulus `P` of the field.
+  pub const MODULUS: Uint<LIMBS> = *M::MODULUS.as_ref();
+
+  /// Creates a new element of the [`BigPrimeField`] from an integer, reducing it modulo `P`.
+  pub const fn new(value: Uint<LIMBS>) -> Self { Self { value: ConstMontyForm::new(&value) } }
+
+  /// Returns the canonical integer in `[0, P)` representing this element.
+  pub const fn value(&self) -> Uint<LIMBS> { self.value.retrieve() }
+
+  /// Raises the element to a big-integer power. [`Field::pow`] only takes a `usize` exponent, which
+  /// is not enough for e.g. Fermat inversion with `P - 2`.
+  pub fn pow_uint<const E: usize>(self, exponent: &Uint<E>) -> Self {
+    Self { value: self.value.pow(exponent) }
+  }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Finite for BigPrimeField<M, LIMBS> {
+  /// The order of the field. Evaluating it is a compile-time error when the modulus does not fit in
+  /// a `usize`, use [`FiniteField::order`] or [`BigPrimeField::MODULUS`] instead.
+  const ORDER: usize = {
+    let modulus = M::MODULUS.as_ref();
+    assert!(
+      modulus.bits_vartime() <= usize::BITS,
+      "the order does not fit in a usize, use `FiniteField::order`"
+    );
+    modulus.as_words()[0] as usize
+  };
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Field for BigPrimeField<M, LIMBS> {
+  const ONE: Self = Self { value: ConstMontyForm::ONE };
+  const ZERO: Self = Self { value: ConstMontyForm::ZERO };
+
+  fn inverse(&self) -> Option<Self> {
+    if *self == Self::ZERO {
+      return None;
+    }
+
+    // By fermat's little theorem: e^(P-2) = e^-1 mod P
+    Some(self.pow_uint(&Self::MODULUS.wrapping_sub(&Uint::from_u8(2))))
+  }
+
+  fn pow(self, power: usize) -> Self { self.pow_uint(&Uint::<LIMBS>::from_u64(power as u64)) }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> FiniteField for BigPrimeField<M, LIMBS> {
+  const PRIMITIVE_ELEMENT: Self = Self::new(M::PRIMITIVE_ELEMENT);
+
+  fn order() -> Vec<u64> { Self::MODULUS.as_words().to_vec() }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> SquareRoot for BigPrimeField<M, LIMBS> {}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> FieldEncoding for BigPrimeField<M, LIMBS> {
+  const ENCODED_LEN: usize = M::MODULUS.as_ref().bits_vartime().div_ceil(8) as usize;
+
+  fn to_be_bytes(&self) -> Vec<u8> {
+    let bytes: Vec<u8> =
+      self.value().as_words().iter().rev().flat_map(|w| w.to_be_bytes()).collect();
+    bytes[bytes.len() - Self::ENCODED_LEN..].to_vec()
+  }
+
+  fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+    if bytes.len() != Self::ENCODED_LEN {
+      return None;
+    }
+    let mut padded = vec![0; LIMBS * Limb::BYTES - bytes.len()];
+    padded.extend_from_slice(bytes);
+    let value = Uint::from_be_slice(&padded);
+    (value < Self::MODULUS).then(|| Self::new(value))
+  }
+
+  fn sgn0(&self) -> bool { self.value().as_words()[0] & 1 == 1 }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> LimbRepr for BigPrimeField<M, LIMBS> {
+  fn modulus() -> Vec<u64> { Self::MODULUS.as_words().to_vec() }
+
+  fn to_limbs(&self) -> Vec<u64> { self.value().as_words().to_vec() }
+
+  /// Reduces the integer with Horner's rule in base $2^{64}$, so any number of limbs is accepted.
+  fn from_limbs(limbs: &[u64]) -> Self {
+    let base = Self::from(u64::MAX) + Self::ONE;
+    limbs.iter().rev().fold(Self::ZERO, |acc, &limb| acc * base + Self::from(limb))
+  }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> FrobeniusMap for BigPrimeField<M, LIMBS> {
+  fn characteristic() -> Vec<u64> { Self::MODULUS.as_words().to_vec() }
+
+  /// By Fermat's little theorem `x^p = x`, so the Frobenius map is the identity on a prime field.
+  fn frobenius_map(&self, _power: usize) -> Self { *self }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Default for BigPrimeField<M, LIMBS> {
+  fn default() -> Self { Self::ZERO }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Hash for BigPrimeField<M, LIMBS> {
+  fn hash<H: Hasher>(&self, state: &mut H) { self.value.as_montgomery().as_words().hash(state) }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> fmt::Debug for BigPrimeField<M, LIMBS> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("BigPrimeField").field("value", &self.value()).finish()
+  }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> fmt::Display for BigPrimeField<M, LIMBS> {
+  /// Writes the canonical representative in decimal, by repeatedly dividing by ten.
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let ten = NonZero::<Limb>::new_unwrap(Limb(10));
+    let mut value = self.value();
+    let mut digits = Vec::new();
+    loop {
+      let (quotient, digit) = value.div_rem_limb(ten);
+      digits.push(char::from(b'0' + digit.0 as u8));
+      value = quotient;
+      if value == Uint::ZERO {
+        break;
+      }
+    }
+    write!(f, "{}", digits.iter().rev().collect::<String>())
+  }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> Distribution<BigPrimeField<M, LIMBS>>
+  for Standard
+{
+  /// Rejection samples integers with the bit length of the modulus until one is canonical.
+  #[inline]
+  fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigPrimeField<M, LIMBS> {
+    let modulus = BigPrimeField::<M, LIMBS>::MODULUS;
+    let bits = modulus.bits_vartime();
+    loop {
+      let words: [Word; LIMBS] = std::array::from_fn(|_| rng.gen());
+      let candidate = Uint::from_words(words).shr_vartime(Uint::<LIMBS>::BITS - bits);
+      if candidate < modulus {
+        return BigPrimeField::new(candidate);
+      }
+    }
+  }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> From<u32> for BigPrimeField<M, LIMBS> {
+  fn from(val: u32) -> Self { Self::new(Uint::from_u32(val)) }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> From<u64> for BigPrimeField<M, LIMBS> {
+  fn from(val: u64) -> Self { Self::new(Uint::from_u64(val)) }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> From<usize> for BigPrimeField<M, LIMBS> {
+  fn from(val: usize) -> Self { Self::new(Uint::from_u64(val as u64)) }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> From<i32> for BigPrimeField<M, LIMBS> {
+  fn from(value: i32) -> Self {
+    let abs = Self::from(value.unsigned_abs());
+    if value.is_positive() {
+      abs
+    } else {
+      -abs
+    }
+  }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> From<Uint<LIMBS>> for BigPrimeField<M, LIMBS> {
+  fn from(val: Uint<LIMBS>) -> Self { Self::new(val) }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> From<BigPrimeField<M, LIMBS>> for Uint<LIMBS> {
+  fn from(val: BigPrimeField<M, LIMBS>) -> Self { val.value() }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> FromStr for BigPrimeField<M, LIMBS> {
+  type Err = ();
+
+  /// Parses a decimal string, or a hexadecimal one when prefixed by `0x`. The value is reduced
+  /// modulo `P`, so inputs larger than the modulus are accepted.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (digits, radix) = match s.strip_prefix("0x") {
+      Some(hex) => (hex, 16),
+      None => (s, 10),
+    };
+    if digits.is_empty() {
+      return Err(());
+    }
+
+    let radix_elem = Self::from(radix);
+    digits.chars().try_fold(Self::ZERO, |acc, c| {
+      let digit = c.to_digit(radix).ok_or(())?;
+      Ok(acc * radix_elem + Self::from(digit))
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use ark_ff::{Fp256, MontBackend, MontConfig};
+  use rstest::rstest;
+
+  use super::*;
+  use crate::{
+    hashes::poseidon::Poseidon,
+    multi_var_poly::MultiVarPolynomial,
+    polynomial::{Lagrange, Monomial, Polynomial},
+  };
+
+  #[derive(MontConfig)]
+  #[modulus = "21888242871839275222246405745257275088548364400416034343698204186575808495617"]
+  #[generator = "5"]
+  pub struct ArkFrBackend;
+  type ArkFr = Fp256<MontBackend<ArkFrBackend, 4>>;
+
+  const MODULUS_DEC: &str =
+    "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+
+  #[test]
+  fn modulus() {
+    assert_eq!(Bn254ScalarField::order(), Bn254ScalarField::MODULUS.as_words().to_vec());
+    assert_eq!(Bn254ScalarField::from_str(MODULUS_DEC).unwrap(), Bn254ScalarField::ZERO);
+    assert_eq!((-Bn254ScalarField::ONE + Bn254ScalarField::ONE), Bn254ScalarField::ZERO);
+  }
+
+  #[rstest]
+  #[case("0", "0")]
+  #[case("1234567890", "1234567890")]
+  #[case("0x1f", "31")]
+  #[case("21888242871839275222246405745257275088548364400416034343698204186575808495618", "1")]
+  fn from_str_display(#[case] input: &str, #[case] expected: &str) {
+    assert_eq!(Bn254ScalarField::from_str(input).unwrap().to_string(), expected);
+  }
+
+  #[rstest]
+  #[case("")]
+  #[case("0x")]
+  #[case("12a")]
+  fn from_str_invalid(#[case] input: &str) {
+    assert!(Bn254ScalarField::from_str(input).is_err());
+  }
+
+  #[test]
+  fn matches_arkworks() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+      let a = rng.gen::<Bn254ScalarField>();
+      let b = rng.gen::<Bn254ScalarField>();
+      let ark_a = ArkFr::from_str(&a.to_string()).unwrap();
+      let ark_b = ArkFr::from_str(&b.to_string()).unwrap();
+
+      assert_eq!((a + b).to_string(), (ark_a + ark_b).to_string());
+      assert_eq!((a - b).to_string(), (ark_a - ark_b).to_string());
+      assert_eq!((a * b).to_string(), (ark_a * ark_b).to_string());
+      assert_eq!((a / b).to_string(), (ark_a / ark_b).to_string());
+      assert_eq!(a.pow(12345).to_string(), ark_ff::Field::pow(&ark_a, [12345u64]).to_string());
+    }
+  }
+
+  #[test]
+  fn inverse() {
+    let mut rng = rand::thread_rng();
+    let a = rng.gen::<Bn254ScalarField>();
+    assert_eq!(a * a.inverse().unwrap(), Bn254ScalarField::ONE);
+    assert_eq!(Bn254ScalarField::ZERO.inverse(), None);
+  }
+
+  #[test]
+  fn roots_of_unity() {
+    let two_adic = Bn254ScalarField::primitive_root_of_unity(1 << 28);
+    assert_eq!(two_adic.pow(1 << 28), Bn254ScalarField::ONE);
+    assert_ne!(two_adic.pow(1 << 27), Bn254ScalarField::ONE);
+
+    let generator = Bn254ScalarField::PRIMITIVE_ELEMENT;
+    let p_minus_one_half = Bn254ScalarField::MODULUS.shr_vartime(1);
+    assert_eq!(generator.pow_uint(&p_minus_one_half), -Bn254ScalarField::ONE);
+  }
+
+  #[test]
+  #[should_panic]
+  fn no_root_of_unity() { Bn254ScalarField::primitive_root_of_unity(7); }
+
+  #[test]
+  fn polynomial_fft() {
+    let coefficients = std::array::from_fn(|i| Bn254ScalarField::from(i + 1));
+    let poly = Polynomial::<Monomial, Bn254ScalarField, 8>::new(coefficients);
+    let evals = poly.fft();
+    assert_eq!(evals.coefficients, poly.dft().coefficients);
+    assert_eq!(evals.ifft().coefficients, coefficients);
+
+    let lagrange =
+      Polynomial::<Lagrange<Bn254ScalarField>, Bn254ScalarField, 8>::new(evals.coefficients);
+    let x = Bn254ScalarField::from(1234u64);
+    assert_eq!(lagrange.evaluate(x), poly.evaluate(x));
+  }
+
+  #[test]
+  fn multi_var_polynomial() {
+    // f(x, y) = 1 + 2y + 3x + 4xy
+    let poly = MultiVarPolynomial::<Bn254ScalarField>::new(
+      vec![1, 1],
+      (1..=4).map(Bn254ScalarField::from).collect(),
+    )
+    .unwrap();
+    let r = [Bn254ScalarField::from(2u64), Bn254ScalarField::from(3u64)];
+    assert_eq!(poly.evaluation(&r), Bn254ScalarField::from(1 + 2 * 3 + 3 * 2 + 4 * 6_usize));
+    assert_eq!(poly.sum_over_bool_hypercube(), Bn254ScalarField::from(1 + 3 + 4 + 10_usize));
+  }
+
+  #[test]
+  fn poseidon() {
+    let mut rng = rand::thread_rng();
+    let (width, num_f, num_p) = (3, 8, 57);
+    let rc = (0..width * (num_f + num_p)).map(|_| rng.gen::<Bn254ScalarField>()).collect();
+    let mds = (0..width).map(|_| (0..width).map(|_| rng.gen()).collect()).collect();
+    let mut poseidon = Poseidon::<Bn254ScalarField>::new(width, 5, num_p, num_f, rc, mds);
+
+    let input = vec![Bn254ScalarField::from(1u64), Bn254ScalarField::from(2u64)];
+    assert_eq!(poseidon.hash(input.clone()), poseidon.hash(input));
+  }
+}
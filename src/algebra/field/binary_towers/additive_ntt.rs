@@ -0,0 +1,246 @@
+//! Additive NTT over binary fields in the novel polynomial basis of
+//! [Lin, Chung and Han](https://arxiv.org/abs/1404.3458).
+//!
+//! Binary fields have no multiplicative subgroups of order `2^l`, so the radix-2 FFT of
+//! [`Polynomial::fft`] cannot be used. They do have plenty of additive subgroups: any `l` elements
+//! $\beta_0, \dots, \beta_{l-1}$ linearly independent over GF(2) span a subspace
+//! $S_l = \{\sum_i u_i \beta_i\}$ of size `2^l`, and polynomials are evaluated on its cosets
+//! $\alpha + S_l$ instead of on roots of unity.
+//!
+//! ## Novel polynomial basis
+//! The subspace vanishing polynomial $W_i(x) = \prod_{u \in S_i} (x - u)$ is linearized, i.e.
+//! $W_i(x + y) = W_i(x) + W_i(y)$, and satisfies $W_{i+1}(x) = W_i(x) (W_i(x) + W_i(\beta_i))$.
+//! Normalizing $\hat{W}_i = W_i / W_i(\beta_i)$, the novel basis polynomials are
+//! $X_j(x) = \prod_{i \in \text{bits}(j)} \hat{W}_i(x)$, so $X_j$ has degree `j`.
+//!
+//! ## Butterflies
+//! Write $P = P_0 + \hat{W}_{l-1} P_1$ with $P_0, P_1$ spanned by the first `2^{l-1}` basis
+//! polynomials. On the coset $\alpha + S_l$, $\hat{W}_{l-1}$ only takes the two values
+//! $t = \hat{W}_{l-1}(\alpha)$ and $t + 1$, so the evaluations are those of $P_0 + t P_1$ on
+//! $\alpha + S_{l-1}$ and of $P_0 + (t + 1) P_1$ on $\alpha + \beta_{l-1} + S_{l-1}$. Both are
+//! again in the novel basis, which gives an `O(n log n)` transform with one multiplication per
+//! butterfly.
+use super::*;
+use crate::polynomial::{Monomial, Polynomial};
+
+/// Additive NTT on the subspace spanned by `log_size` elements of a binary field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdditiveNtt<F: FiniteField> {
+  /// The basis $\beta_0, \dots, \beta_{l-1}$ of the evaluation subspace.
+  basis:     Vec<F>,
+  /// `vanishing[i][m]` is the coefficient of $x^{2^m}$ in $\hat{W}_i$.
+  vanishing: Vec<Vec<F>>,
+  /// `twiddles[i][u]` is $\hat{W}_i(\sum_k u_k \beta_{i+1+k})$.
+  twiddles:  Vec<Vec<F>>,
+  /// $W_i(\beta_i)$, the inverse of the leading coefficient of $\hat{W}_i$.
+  scale:     Vec<F>,
+}
+
+impl<F: FiniteField> AdditiveNtt<F> {
+  /// Creates the NTT on the subspace spanned by the first `log_size` elements of the standard
+  /// basis, `F::from(1 << i)`. For [`BinaryTowers`] and the packed towers these are the multilinear
+  /// basis elements $\beta_{2^i}$, so the domain is the first `2^log_size` elements in their
+  /// integer order.
+  pub fn new(log_size: usize) -> Self { Self::with_basis((0..log_size).map(|i| F::from(1 << i))) }
+
+  /// Creates the NTT on the subspace spanned by `basis`.
+  ///
+  /// ## Panics
+  /// - If the field does not have characteristic two.
+  /// - If the elements of `basis` are not linearly independent over GF(2).
+  pub fn with_basis(basis: impl IntoIterator<Item = F>) -> Self {
+    assert_eq!(F::ONE + F::ONE, F::ZERO, "the additive NTT needs a field of characteristic two");
+    let basis: Vec<F> = basis.into_iter().collect();
+    let l = basis.len();
+
+    // W_i as linearized coefficients, W_0(x) = x and W_{i+1} = W_i^2 + W_i(β_i) W_i
+    let mut unnormalized = vec![F::ONE];
+    let mut vanishing = Vec::with_capacity(l);
+    let mut scale = Vec::with_capacity(l);
+    for (i, &beta) in basis.iter().enumerate() {
+      let w_beta = evaluate_linearized(&unnormalized, beta);
+      let inverse = w_beta.inverse().expect("basis elements should be linearly independent");
+      vanishing.push(unnormalized.iter().map(|&c| c * inverse).collect::<Vec<F>>());
+      scale.push(w_beta);
+
+      if i + 1 < l {
+        let mut next = vec![F::ZERO; unnormalized.len() + 1];
+        for (m, &c) in unnormalized.iter().enumerate() {
+          next[m + 1] += c * c;
+          next[m] += w_beta * c;
+        }
+        unnormalized = next;
+      }
+    }
+
+    // Ŵ_i is linear, so its values on the span of β_{i+1}, ... are subset sums of the values on the
+    // basis
+    let twiddles = (0..l)
+      .map(|i| {
+        let mut table = vec![F::ZERO];
+        for &beta in &basis[i + 1..] {
+          let w = evaluate_linearized(&vanishing[i], beta);
+          let shifted: Vec<F> = table.iter().map(|&t| t + w).collect();
+          table.extend(shifted);
+        }
+        table
+      })
+      .collect();
+
+    Self { basis, vanishing, twiddles, scale }
+  }
+
+  /// Returns `l`, the evaluation domain having `2^l` elements.
+  pub fn log_size(&self) -> usize { self.basis.len() }
+
+  /// Returns the `index`-th point $\alpha + \sum_i u_i \beta_i$ of the coset of `shift`, where
+  /// $u_i$ are the bits of `index`.
+  pub fn domain_point(&self, index: usize, shift: F) -> F {
+    self
+      .basis
+      .iter()
+      .enumerate()
+      .filter(|(i, _)| (index >> i) & 1 == 1)
+      .fold(shift, |x, (_, &b)| x + b)
+  }
+
+  /// Evaluates the normalized vanishing polynomial $\hat{W}_i$ at `x`.
+  pub fn vanishing_polynomial(&self, i: usize, x: F) -> F {
+    evaluate_linearized(&self.vanishing[i], x)
+  }
+
+  /// Evaluates the novel basis polynomial $X_j(x) = \prod_{i \in \text{bits}(j)} \hat{W}_i(x)$.
+  pub fn novel_basis(&self, j: usize, x: F) -> F {
+    (0..self.log_size())
+      .filter(|i| (j >> i) & 1 == 1)
+      .map(|i| self.vanishing_polynomial(i, x))
+      .product()
+  }
+
+  /// Evaluates a polynomial given by its `coefficients` in the novel basis at `x` term by term.
+  pub fn evaluate_novel(&self, coefficients: &[F], x: F) -> F {
+    coefficients.iter().enumerate().map(|(j, &c)| c * self.novel_basis(j, x)).sum()
+  }
+
+  /// Transforms the coefficients of a polynomial in the novel basis in place into its evaluations
+  /// on the coset `shift` $+ S_l$, `data[index]` becoming the value at
+  /// [`AdditiveNtt::domain_point`]. A slice of length `2^m < 2^l` is evaluated on the subspace of
+  /// the first `m` basis elements.
+  ///
+  /// ## Panics
+  /// If the length of `data` is not a power of two or is larger than the domain.
+  pub fn forward(&self, data: &mut [F], shift: F) {
+    let log_len = self.check_length(data.len());
+    for i in (0..log_len).rev() {
+      self.layer(data, i, shift, |a, b, twiddle| {
+        *a += *b * twiddle;
+        *b += *a;
+      });
+    }
+  }
+
+  /// Inverse of [`AdditiveNtt::forward`]: recovers the novel basis coefficients of the polynomial
+  /// of degree less than `data.len()` taking the values of `data` on the coset `shift` $+ S_l$.
+  ///
+  /// ## Panics
+  /// If the length of `data` is not a power of two or is larger than the domain.
+  pub fn inverse(&self, data: &mut [F], shift: F) {
+    let log_len = self.check_length(data.len());
+    for i in 0..log_len {
+      self.layer(data, i, shift, |a, b, twiddle| {
+        *b += *a;
+        *a += *b * twiddle;
+      });
+    }
+  }
+
+  /// Evaluates a polynomial in the [`Monomial`] basis on the `D` points of the subspace by changing
+  /// it to the novel basis and applying [`AdditiveNtt::forward`].
+  ///
+  /// ## Panics
+  /// If `D` is not a power of two or is larger than the domain.
+  pub fn evaluate<const D: usize>(&self, polynomial: &Polynomial<Monomial, F, D>) -> [F; D] {
+    let mut data = polynomial.coefficients;
+    self.monomial_to_novel(&mut data);
+    self.forward(&mut data, F::ZERO);
+    data
+  }
+
+  /// Interpolates the polynomial of degree less than `D` taking the values `evaluations` on the `D`
+  /// points of the subspace, returned in the [`Monomial`] basis.
+  pub fn interpolate<const D: usize>(&self, evaluations: [F; D]) -> Polynomial<Monomial, F, D> {
+    let mut data = evaluations;
+    self.inverse(&mut data, F::ZERO);
+    self.novel_to_monomial(&mut data);
+    Polynomial::<Monomial, F, D>::new(data)
+  }
+
+  /// Changes the coefficients of a polynomial of degree less than `2^m` from the monomial basis to
+  /// the novel basis in place, by recursively dividing by $\hat{W}_{m-1}$:
+  /// $P = R + \hat{W}_{m-1} Q$ with $R$ and $Q$ of degree less than `2^{m-1}`. As $\hat{W}_{m-1}$
+  /// has only `m` terms, each level costs `O(n log n)`.
+  pub fn monomial_to_novel(&self, coefficients: &mut [F]) {
+    let log_len = self.check_length(coefficients.len());
+    for i in (0..log_len).rev() {
+      for chunk in coefficients.chunks_exact_mut(1 << (i + 1)) {
+        // long division by Ŵ_i, the quotient replacing the high half
+        for d in (1 << i..1 << (i + 1)).rev() {
+          let q = chunk[d] * self.scale[i];
+          for (m, &w) in self.vanishing[i].iter().enumerate().take(i) {
+            chunk[d - (1 << i) + (1 << m)] -= q * w;
+          }
+          chunk[d] = q;
+        }
+      }
+    }
+  }
+
+  /// Changes the coefficients of a polynomial from the novel basis back to the monomial basis in
+  /// place, undoing [`AdditiveNtt::monomial_to_novel`] by multiplying out $R + \hat{W}_{m-1} Q$.
+  pub fn novel_to_monomial(&self, coefficients: &mut [F]) {
+    let log_len = self.check_length(coefficients.len());
+    for i in 0..log_len {
+      for chunk in coefficients.chunks_exact_mut(1 << (i + 1)) {
+        for d in 1 << i..1 << (i + 1) {
+          let q = chunk[d];
+          chunk[d] = q * self.vanishing[i][i];
+          for (m, &w) in self.vanishing[i].iter().enumerate().take(i) {
+            chunk[d - (1 << i) + (1 << m)] += q * w;
+          }
+        }
+      }
+    }
+  }
+
+  /// Applies `butterfly(a, b, twiddle)` to all pairs of indices differing in bit `i`, the twiddle
+  /// being $\hat{W}_i$ at the point of the pair's block.
+  fn layer(&self, data: &mut [F], i: usize, shift: F, butterfly: impl Fn(&mut F, &mut F, F)) {
+    let shift_twiddle = self.vanishing_polynomial(i, shift);
+    for (u, block) in data.chunks_exact_mut(1 << (i + 1)).enumerate() {
+      let twiddle = self.twiddles[i][u] + shift_twiddle;
+      let (low, high) = block.split_at_mut(1 << i);
+      for (a, b) in low.iter_mut().zip(high) {
+        butterfly(a, b, twiddle);
+      }
+    }
+  }
+
+  /// Returns the base two logarithm of `len`.
+  fn check_length(&self, len: usize) -> usize {
+    assert!(len.is_power_of_two(), "length should be a power of two");
+    let log_len = len.trailing_zeros() as usize;
+    assert!(log_len <= self.log_size(), "length should not exceed the domain size");
+    log_len
+  }
+}
+
+/// Evaluates $\sum_m c_m x^{2^m}$.
+fn evaluate_linearized<F: Field>(coefficients: &[F], x: F) -> F {
+  let mut power = x;
+  let mut result = F::ZERO;
+  for &c in coefficients {
+    result += c * power;
+    power *= power;
+  }
+  result
+}
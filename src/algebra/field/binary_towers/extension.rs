@@ -10,7 +10,7 @@ use rand::{
   Rng,
 };
 
-use super::{BinaryField, Field, Finite, FiniteField};
+use super::{BinaryField, Field, Finite, FiniteField, SquareRoot};
 
 /// Binary extension field GF_{2^{2^K}} using binary towers arithmetic as explained in Section 2.3 of [DP23b](https://eprint.iacr.org/2023/1784.pdf)
 /// represented as vector of 2^K [`BinaryField`] components in multilinear basis,
@@ -76,8 +76,18 @@ where [(); 1 << K]:
 {
   // TODO: incorrect
   const PRIMITIVE_ELEMENT: Self = Self::ONE;
+
+  /// `2^(2^K)`, which overflows [`Finite::ORDER`] from `K = 6` on.
+  fn order() -> Vec<u64> {
+    let bits = 1 << K;
+    let mut limbs = vec![0; bits / 64 + 1];
+    limbs[bits / 64] = 1 << (bits % 64);
+    limbs
+  }
 }
 
+impl<const K: usize> SquareRoot for BinaryTowers<K> where [(); 1 << K]: {}
+
 impl<const K: usize> Default for BinaryTowers<K>
 where [(); 1 << K]:
 {
@@ -6,8 +6,15 @@ use std::{
 
 use super::*;
 
+pub mod additive_ntt;
 pub mod extension;
+pub mod packed;
+pub use additive_ntt::AdditiveNtt;
 pub use extension::BinaryTowers;
+pub use packed::{
+  BinaryTower128b, BinaryTower16b, BinaryTower1b, BinaryTower2b, BinaryTower32b, BinaryTower4b,
+  BinaryTower64b, BinaryTower8b,
+};
 
 #[cfg(test)] mod tests;
 
@@ -43,6 +50,8 @@ impl FiniteField for BinaryField {
   const PRIMITIVE_ELEMENT: Self = Self::ONE;
 }
 
+impl SquareRoot for BinaryField {}
+
 impl From<usize> for BinaryField {
   fn from(value: usize) -> Self {
     match value {
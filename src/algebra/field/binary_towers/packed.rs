@@ -0,0 +1,329 @@
+//! Packed binary tower fields: an element of GF(2^{2^K}) is stored as the `2^K` bits of a single
+//! machine integer instead of an array of [`BinaryField`]s.
+//!
+//! The basis is the same multilinear basis as [`BinaryTowers`], bit `i` of the integer being the
+//! coefficient of $\beta_i$, so the two representations convert into each other bit by bit. Every
+//! level `K` is split into halves of level `K - 1`, $a = a_0 + a_1 X_{K-1}$, and arithmetic
+//! recurses on the halves:
+//! - multiplication is Karatsuba with three half-sized products, reduced by $X_{K-1}^2 = X_{K-1}
+//!   X_{K-2} + 1$,
+//! - squaring is two half-sized squarings as the cross term vanishes in characteristic two,
+//! - inversion multiplies by the conjugate $(a_0 + a_1 X_{K-2}) + a_1 X_{K-1}$ and divides by the
+//!   norm $a_0^2 + a_0 a_1 X_{K-2} + a_1^2$, which lives in the level below, so a single inversion
+//!   costs a handful of multiplications at each level instead of an exponentiation.
+//!
+//! Every level is a subfield of the next one, so a primitive element must have a nonzero `X_{K-1}`
+//! half. The [`FiniteField::PRIMITIVE_ELEMENT`]s are the smallest such elements generating the
+//! whole multiplicative group.
+use std::{
+  iter::{Product, Sum},
+  ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
+use rand::{
+  distributions::{Distribution, Standard},
+  Rng,
+};
+
+use super::{BinaryField, BinaryTowers, Field, Finite, FiniteField, SquareRoot};
+
+/// Implements everything a tower level shares, given the level specific `multiply`, `square`,
+/// `invert` and `mul_alpha`.
+macro_rules! packed_tower {
+  ($(#[$doc:meta])* $name:ident($repr:ty), $k:literal, $generator:literal) => {
+    $(#[$doc])*
+    #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    pub struct $name {
+      pub(crate) value: $repr,
+    }
+
+    impl $name {
+      /// Number of bits `2^K` of an element.
+      pub const BITS: usize = 1 << $k;
+      const MASK: $repr = <$repr>::MAX >> (<$repr>::BITS as usize - Self::BITS);
+
+      /// Creates an element from its coefficients in the multilinear basis, bit `i` of `value`
+      /// being the coefficient of $\beta_i$.
+      ///
+      /// ## Panics
+      /// If `value` does not fit in `2^K` bits.
+      pub const fn new(value: $repr) -> Self {
+        assert!(value <= Self::MASK, "value does not fit in the tower level");
+        Self { value }
+      }
+
+      /// Returns the packed coefficients of the element.
+      pub const fn value(&self) -> $repr { self.value }
+    }
+
+    impl Finite for $name {
+      /// `2^(2^K)`. Evaluating it is a compile-time error from `K = 6` on, use
+      /// [`FiniteField::order`] instead.
+      const ORDER: usize = {
+        assert!(
+          Self::BITS < usize::BITS as usize,
+          "the order does not fit in a usize, use `FiniteField::order`"
+        );
+        1 << Self::BITS
+      };
+    }
+
+    impl Field for $name {
+      const ONE: Self = Self { value: 1 };
+      const ZERO: Self = Self { value: 0 };
+
+      fn inverse(&self) -> Option<Self> { self.invert() }
+    }
+
+    impl FiniteField for $name {
+      const PRIMITIVE_ELEMENT: Self = Self { value: $generator };
+
+      fn order() -> Vec<u64> {
+        let mut limbs = vec![0; Self::BITS / 64 + 1];
+        limbs[Self::BITS / 64] = 1 << (Self::BITS % 64);
+        limbs
+      }
+    }
+
+    impl SquareRoot for $name {}
+
+    impl Add for $name {
+      type Output = Self;
+
+      #[allow(clippy::suspicious_arithmetic_impl)]
+      fn add(self, rhs: Self) -> Self::Output { Self { value: self.value ^ rhs.value } }
+    }
+
+    impl AddAssign for $name {
+      fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+    }
+
+    impl Sum for $name {
+      fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|x, y| x + y).unwrap_or(Self::ZERO)
+      }
+    }
+
+    impl Sub for $name {
+      type Output = Self;
+
+      #[allow(clippy::suspicious_arithmetic_impl)]
+      fn sub(self, rhs: Self) -> Self::Output { self + rhs }
+    }
+
+    impl SubAssign for $name {
+      fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+    }
+
+    impl Neg for $name {
+      type Output = Self;
+
+      fn neg(self) -> Self::Output { self }
+    }
+
+    impl Mul for $name {
+      type Output = Self;
+
+      fn mul(self, rhs: Self) -> Self::Output { self.multiply(rhs) }
+    }
+
+    impl MulAssign for $name {
+      fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+    }
+
+    impl Product for $name {
+      fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(|x, y| x * y).unwrap_or(Self::ONE)
+      }
+    }
+
+    impl Div for $name {
+      type Output = Self;
+
+      #[allow(clippy::suspicious_arithmetic_impl)]
+      fn div(self, rhs: Self) -> Self::Output { self * rhs.inverse().expect("divide by zero") }
+    }
+
+    impl DivAssign for $name {
+      fn div_assign(&mut self, rhs: Self) { *self = *self / rhs; }
+    }
+
+    impl Rem for $name {
+      type Output = Self;
+
+      fn rem(self, rhs: Self) -> Self::Output { self - (self / rhs) * rhs }
+    }
+
+    impl From<usize> for $name {
+      fn from(value: usize) -> Self {
+        Self::new(<$repr>::try_from(value).expect("value does not fit in the tower level"))
+      }
+    }
+
+    impl From<BinaryTowers<$k>> for $name {
+      fn from(value: BinaryTowers<$k>) -> Self {
+        let value = value
+          .coefficients
+          .iter()
+          .rev()
+          .fold(0, |acc, &bit| (acc << 1) | (bit == BinaryField::One) as $repr);
+        Self { value }
+      }
+    }
+
+    impl From<$name> for BinaryTowers<$k> {
+      fn from(value: $name) -> Self {
+        BinaryTowers::new(std::array::from_fn(|i| match (value.value >> i) & 1 {
+          0 => BinaryField::Zero,
+          _ => BinaryField::One,
+        }))
+      }
+    }
+
+    impl Distribution<$name> for Standard {
+      #[inline]
+      fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $name {
+        $name { value: rng.gen_range(1..=$name::MASK) }
+      }
+    }
+  };
+}
+
+/// Implements the arithmetic of level `K` on top of the halves of level `K - 1`.
+macro_rules! tower_level {
+  ($name:ident($repr:ty), $half:ident($half_repr:ty)) => {
+    impl $name {
+      /// Splits `a = a_0 + a_1 X_{K-1}` into `(a_0, a_1)`.
+      const fn split(self) -> ($half, $half) {
+        let low = (self.value & $half::MASK as $repr) as $half_repr;
+        let high = (self.value >> $half::BITS) as $half_repr;
+        ($half { value: low }, $half { value: high })
+      }
+
+      /// Recombines `(a_0, a_1)` into `a_0 + a_1 X_{K-1}`.
+      const fn join(low: $half, high: $half) -> Self {
+        Self { value: low.value as $repr | (high.value as $repr) << $half::BITS }
+      }
+
+      /// Multiplies by the generator `X_{K-1}` of the level:
+      /// `X_{K-1} (a_0 + a_1 X_{K-1}) = a_1 + X_{K-1} (a_0 + a_1 X_{K-2})`.
+      pub fn mul_alpha(self) -> Self {
+        let (a0, a1) = self.split();
+        Self::join(a1, a0 + a1.mul_alpha())
+      }
+
+      /// Karatsuba multiplication: with `z_0 = a_0 b_0`, `z_2 = a_1 b_1`, the product is
+      /// `(z_0 + z_2) + X_{K-1} ((a_0 + a_1)(b_0 + b_1) - z_0 - z_2 + z_2 X_{K-2})`.
+      fn multiply(self, rhs: Self) -> Self {
+        let (a0, a1) = self.split();
+        let (b0, b1) = rhs.split();
+        let z0 = a0 * b0;
+        let z2 = a1 * b1;
+        let z1 = (a0 + a1) * (b0 + b1);
+        Self::join(z0 + z2, z1 + z0 + z2 + z2.mul_alpha())
+      }
+
+      /// Computes `a^2 = (a_0^2 + a_1^2) + X_{K-1} a_1^2 X_{K-2}`.
+      pub fn square(self) -> Self {
+        let (a0, a1) = self.split();
+        let (a0, a1) = (a0.square(), a1.square());
+        Self::join(a0 + a1, a1.mul_alpha())
+      }
+
+      fn invert(self) -> Option<Self> {
+        let (a0, a1) = self.split();
+        let norm = a0.square() + (a0 * a1).mul_alpha() + a1.square();
+        let norm_inverse = norm.invert()?;
+        Some(Self::join((a0 + a1.mul_alpha()) * norm_inverse, a1 * norm_inverse))
+      }
+    }
+
+    impl From<$half> for $name {
+      /// Embeds the subfield of level `K - 1` as the elements with no `X_{K-1}` component.
+      fn from(value: $half) -> Self { Self::join(value, $half::ZERO) }
+    }
+
+    impl From<$name> for ($half, $half) {
+      fn from(value: $name) -> Self { value.split() }
+    }
+
+    impl From<($half, $half)> for $name {
+      fn from(value: ($half, $half)) -> Self { Self::join(value.0, value.1) }
+    }
+  };
+}
+
+packed_tower!(
+  /// GF(2) packed in a `u8`, the base of the tower.
+  BinaryTower1b(u8),
+  0,
+  1
+);
+packed_tower!(
+  /// GF(2^2) packed in a `u8`.
+  BinaryTower2b(u8),
+  1,
+  0x2
+);
+packed_tower!(
+  /// GF(2^4) packed in a `u8`.
+  BinaryTower4b(u8),
+  2,
+  0x5
+);
+packed_tower!(
+  /// GF(2^8) packed in a `u8`.
+  BinaryTower8b(u8),
+  3,
+  0x13
+);
+packed_tower!(
+  /// GF(2^16) packed in a `u16`.
+  BinaryTower16b(u16),
+  4,
+  0x102
+);
+packed_tower!(
+  /// GF(2^32) packed in a `u32`.
+  BinaryTower32b(u32),
+  5,
+  0x1_0005
+);
+packed_tower!(
+  /// GF(2^64) packed in a `u64`.
+  BinaryTower64b(u64),
+  6,
+  0x1_0000_0004
+);
+packed_tower!(
+  /// GF(2^128) packed in a `u128`.
+  BinaryTower128b(u128),
+  7,
+  0x1_0000_0000_0000_0005
+);
+
+impl BinaryTower1b {
+  /// Multiplies by `X_{-1}`, which is taken to be one so that `X_0^2 = X_0 + 1` follows the
+  /// reduction rule of the other levels.
+  pub const fn mul_alpha(self) -> Self { self }
+
+  const fn multiply(self, rhs: Self) -> Self { Self { value: self.value & rhs.value } }
+
+  /// Squaring is the identity on GF(2).
+  pub const fn square(self) -> Self { self }
+
+  const fn invert(self) -> Option<Self> {
+    match self.value {
+      0 => None,
+      _ => Some(self),
+    }
+  }
+}
+
+tower_level!(BinaryTower2b(u8), BinaryTower1b(u8));
+tower_level!(BinaryTower4b(u8), BinaryTower2b(u8));
+tower_level!(BinaryTower8b(u8), BinaryTower4b(u8));
+tower_level!(BinaryTower16b(u16), BinaryTower8b(u8));
+tower_level!(BinaryTower32b(u32), BinaryTower16b(u16));
+tower_level!(BinaryTower64b(u64), BinaryTower32b(u32));
+tower_level!(BinaryTower128b(u128), BinaryTower64b(u64));
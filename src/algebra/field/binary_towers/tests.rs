@@ -2,7 +2,14 @@ use rand::{thread_rng, Rng};
 use rstest::rstest;
 
 use super::*;
-use crate::PrimeField;
+use crate::{
+  algebra::{
+    field::pow_limbs,
+    limbs::{bit_length, div_rem_small, sub_small},
+  },
+  polynomial::{Monomial, Polynomial},
+  PrimeField,
+};
 
 type TestBinaryField = PrimeField<2>;
 
@@ -120,3 +127,193 @@ fn efficient_embedding() {
 
   assert_eq!(a, b);
 }
+
+#[test]
+fn square_root() {
+  fn check<const K: usize>(rng: &mut impl Rng)
+  where [(); 1 << K]: {
+    let a = rng.gen::<BinaryTowers<K>>();
+    // squaring is a bijection in characteristic two, so the root is unique
+    assert_eq!((a * a).square_root(), Some(a));
+    assert!(a.is_square());
+  }
+
+  let mut rng = thread_rng();
+  for _ in 0..10 {
+    check::<3>(&mut rng);
+    check::<5>(&mut rng);
+    check::<4>(&mut rng);
+  }
+  assert_eq!(BinaryTowers::<6>::order(), vec![0, 1]);
+  assert_eq!(BinaryField::One.square_root(), Some(BinaryField::One));
+}
+
+#[test]
+fn batch_inverse_and_powers() {
+  let mut rng = thread_rng();
+  let elements: [BinaryTowers<4>; 8] = std::array::from_fn(|_| rng.gen());
+  for (x, inverse) in elements.iter().zip(BinaryTowers::<4>::batch_inverse(elements)) {
+    assert_eq!(inverse, x.inverse());
+  }
+
+  let x = elements[0];
+  for power in [0, 1, 5, 31, 32, 65535, 65536, 1 << 40] {
+    assert_eq!(x.pow_sliding_window(power), x.pow(power));
+  }
+}
+
+/// Checks a packed level against the [`BinaryTowers`] of the same size.
+macro_rules! packed_matches_coefficients {
+  ($name:ident, $packed:ident, $k:literal) => {
+    #[test]
+    fn $name() {
+      let mut rng = thread_rng();
+      for _ in 0..10 {
+        let a = rng.gen::<$packed>();
+        let b = rng.gen::<$packed>();
+        let (a_unpacked, b_unpacked) = (BinaryTowers::<$k>::from(a), BinaryTowers::<$k>::from(b));
+        assert_eq!($packed::from(a_unpacked), a);
+
+        assert_eq!($packed::from(a_unpacked + b_unpacked), a + b);
+        assert_eq!($packed::from(a_unpacked * b_unpacked), a * b);
+        assert_eq!(a.square(), a * a);
+        let inverse = BinaryTowers::<$k>::from(a.inverse().unwrap());
+        assert_eq!(a_unpacked * inverse, BinaryTowers::<$k>::ONE);
+        assert_eq!(a.square().square_root(), Some(a));
+
+        let (low, high) = a.into();
+        assert_eq!($packed::from((low, high)), a);
+        // the level below is a subfield
+        assert_eq!($packed::from(low * high), $packed::from(low) * $packed::from(high));
+      }
+      assert_eq!($packed::ZERO.inverse(), None);
+    }
+  };
+}
+
+packed_matches_coefficients!(packed_2b, BinaryTower2b, 1);
+packed_matches_coefficients!(packed_4b, BinaryTower4b, 2);
+packed_matches_coefficients!(packed_8b, BinaryTower8b, 3);
+packed_matches_coefficients!(packed_16b, BinaryTower16b, 4);
+packed_matches_coefficients!(packed_32b, BinaryTower32b, 5);
+packed_matches_coefficients!(packed_64b, BinaryTower64b, 6);
+packed_matches_coefficients!(packed_128b, BinaryTower128b, 7);
+
+#[rstest]
+#[case(BinaryTower8b::from(160), BinaryTower8b::from(23), BinaryTower8b::from(90))]
+#[case(BinaryTower8b::from(217), BinaryTower8b::from(20), BinaryTower8b::from(151))]
+#[case(BinaryTower8b::from(203), BinaryTower8b::from(187), BinaryTower8b::from(4))]
+fn packed_mul(#[case] a: BinaryTower8b, #[case] b: BinaryTower8b, #[case] res: BinaryTower8b) {
+  assert_eq!(a * b, res);
+  assert_eq!(res / b, a);
+}
+
+#[test]
+fn packed_primitive_element() {
+  /// Checks that `x` generates the multiplicative group of order `q - 1`, whose prime factors
+  /// have to be given in full.
+  fn is_generator<F: FiniteField>(x: F, prime_factors: &[u64]) -> bool {
+    let q_minus_one = sub_small(&F::order(), 1);
+    let cofactor = prime_factors.iter().fold(q_minus_one.clone(), |n, &p| {
+      let (quotient, rem) = div_rem_small(&n, p);
+      assert_eq!(rem, 0, "{p} does not divide q - 1");
+      quotient
+    });
+    assert_eq!(bit_length(&cofactor), 1, "q - 1 has other prime factors");
+    pow_limbs(x, &q_minus_one) == F::ONE
+      && prime_factors.iter().all(|&p| pow_limbs(x, &div_rem_small(&q_minus_one, p).0) != F::ONE)
+  }
+
+  /// Checks that the primitive element is the smallest generator with a nonzero top half.
+  macro_rules! check {
+    ($tower:ident, $prime_factors:expr) => {
+      assert!(is_generator($tower::PRIMITIVE_ELEMENT, $prime_factors));
+      let half = 1 << ($tower::BITS / 2);
+      for value in half..$tower::PRIMITIVE_ELEMENT.value() {
+        assert!(!is_generator($tower::new(value), $prime_factors));
+      }
+    };
+  }
+
+  check!(BinaryTower2b, &[3]);
+  check!(BinaryTower4b, &[3, 5]);
+  check!(BinaryTower8b, &[3, 5, 17]);
+  check!(BinaryTower16b, &[3, 5, 17, 257]);
+  check!(BinaryTower32b, &[3, 5, 17, 257, 65537]);
+  check!(BinaryTower64b, &[3, 5, 17, 257, 641, 65537, 6700417]);
+  check!(BinaryTower128b, &[3, 5, 17, 257, 641, 65537, 274177, 6700417, 67280421310721]);
+}
+
+#[test]
+fn additive_ntt_novel_basis() {
+  let mut rng = thread_rng();
+  let ntt = AdditiveNtt::<BinaryTowers<4>>::new(3);
+
+  // X_j has degree j, so X_{2^i} = Ŵ_i vanishes exactly on the first 2^i points
+  for i in 0..3 {
+    for index in 0..8 {
+      let point = ntt.domain_point(index, BinaryTowers::ZERO);
+      assert_eq!(ntt.vanishing_polynomial(i, point) == BinaryTowers::ZERO, index < 1 << i);
+    }
+    assert_eq!(
+      ntt.vanishing_polynomial(i, ntt.domain_point(1 << i, BinaryTowers::ZERO)),
+      BinaryTowers::ONE
+    );
+  }
+
+  for _ in 0..5 {
+    let coefficients: [BinaryTowers<4>; 8] = std::array::from_fn(|_| rng.gen());
+    let shift = rng.gen::<BinaryTowers<4>>();
+
+    let mut evaluations = coefficients;
+    ntt.forward(&mut evaluations, shift);
+    for (index, &value) in evaluations.iter().enumerate() {
+      assert_eq!(ntt.evaluate_novel(&coefficients, ntt.domain_point(index, shift)), value);
+    }
+
+    ntt.inverse(&mut evaluations, shift);
+    assert_eq!(evaluations, coefficients);
+
+    // a smaller transform evaluates on the first basis elements only
+    let mut evaluations = [coefficients[0], coefficients[1]];
+    ntt.forward(&mut evaluations, shift);
+    assert_eq!(
+      evaluations[1],
+      ntt.evaluate_novel(&coefficients[..2], shift + ntt.domain_point(1, BinaryTowers::ZERO))
+    );
+  }
+}
+
+#[test]
+fn additive_ntt_evaluation_and_interpolation() {
+  let mut rng = thread_rng();
+  let ntt = AdditiveNtt::<BinaryTowers<3>>::new(4);
+  let polynomial =
+    Polynomial::<Monomial, BinaryTowers<3>, 16>::new(std::array::from_fn(|_| rng.gen()));
+
+  let mut novel = polynomial.coefficients;
+  ntt.monomial_to_novel(&mut novel);
+  let x = rng.gen::<BinaryTowers<3>>();
+  assert_eq!(ntt.evaluate_novel(&novel, x), polynomial.evaluate(x));
+  ntt.novel_to_monomial(&mut novel);
+  assert_eq!(novel, polynomial.coefficients);
+
+  let evaluations = ntt.evaluate(&polynomial);
+  for (index, &value) in evaluations.iter().enumerate() {
+    // the default basis spans the elements 0..16 in their integer order
+    assert_eq!(ntt.domain_point(index, BinaryTowers::ZERO), BinaryTowers::<3>::from(index));
+    assert_eq!(polynomial.evaluate(BinaryTowers::from(index)), value);
+  }
+  assert_eq!(ntt.interpolate(evaluations), polynomial);
+
+  // packed fields and an arbitrary basis
+  let basis: [BinaryTower128b; 3] = std::array::from_fn(|_| rng.gen());
+  let ntt = AdditiveNtt::with_basis(basis);
+  let polynomial =
+    Polynomial::<Monomial, BinaryTower128b, 8>::new(std::array::from_fn(|_| rng.gen()));
+  let evaluations = ntt.evaluate(&polynomial);
+  for (index, &value) in evaluations.iter().enumerate() {
+    assert_eq!(polynomial.evaluate(ntt.domain_point(index, BinaryTower128b::ZERO)), value);
+  }
+  assert_eq!(ntt.interpolate(evaluations), polynomial);
+}
@@ -0,0 +1,98 @@
+//! Canonical byte encodings of field elements.
+//!
+//! An element of a prime field $\mathbb{F}_p$ is encoded as the big-endian bytes of its canonical
+//! integer in $[0, p)$, padded to the byte length of $p$. This is the `FieldElement-to-OctetString`
+//! conversion of [SEC1](https://www.secg.org/sec1-v2.pdf), section 2.3.5. An element
+//! $c_0 + c_1 X + \ldots + c_{N-1} X^{N-1}$ of an extension is encoded as its coefficients from
+//! $c_{N-1}$ down to $c_0$, so that the base field embeds as the encodings starting with zeros.
+//!
+//! Decoding is strict: an input of the wrong length, or an integer that is not reduced modulo $p$,
+//! is rejected, so that every element has exactly one encoding.
+
+use super::*;
+
+/// Finite fields whose elements have a canonical fixed-length byte encoding.
+pub trait FieldEncoding: FiniteField {
+  /// The number of bytes of an encoded element.
+  const ENCODED_LEN: usize;
+
+  /// Encodes the element as [`FieldEncoding::ENCODED_LEN`] big-endian bytes.
+  fn to_be_bytes(&self) -> Vec<u8>;
+
+  /// Decodes an element from [`FieldEncoding::ENCODED_LEN`] big-endian bytes, or returns `None` if
+  /// the length is wrong or the encoding is not canonical.
+  fn from_be_bytes(bytes: &[u8]) -> Option<Self>;
+
+  /// The sign of the element as defined by [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380#section-4.1):
+  /// the parity of the canonical integer in a prime field, and the parity of the first nonzero
+  /// coefficient, starting from $c_0$, in an extension. Exactly one of $x$ and $-x$ is odd unless
+  /// $x = 0$, which is what point compression relies on.
+  fn sgn0(&self) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::algebra::field::{
+    big_prime::Bn254ScalarField,
+    extension::PlutoBaseFieldExtension,
+    prime::{Goldilocks, PlutoBaseField},
+  };
+
+  fn round_trip<F: FieldEncoding>(x: F) {
+    let bytes = x.to_be_bytes();
+    assert_eq!(bytes.len(), F::ENCODED_LEN);
+    assert_eq!(F::from_be_bytes(&bytes), Some(x));
+    assert_eq!(F::from_be_bytes(&bytes[1..]), None);
+    if x != F::ZERO {
+      assert_ne!(x.sgn0(), (-x).sgn0());
+    }
+  }
+
+  #[test]
+  fn prime_field() {
+    assert_eq!(PlutoBaseField::ENCODED_LEN, 1);
+    assert_eq!(Goldilocks::ENCODED_LEN, 8);
+    for x in 0..101 {
+      round_trip(PlutoBaseField::new(x));
+      assert_eq!(PlutoBaseField::new(x).sgn0(), x % 2 == 1);
+    }
+    assert_eq!(PlutoBaseField::from_be_bytes(&[101]), None);
+    assert_eq!(PlutoBaseField::from_be_bytes(&[255]), None);
+    round_trip(-Goldilocks::ONE);
+    assert_eq!(Goldilocks::from_be_bytes(&[0xff; 8]), None);
+  }
+
+  #[test]
+  fn extension_field() {
+    assert_eq!(PlutoBaseFieldExtension::ENCODED_LEN, 2);
+    let x = PlutoBaseFieldExtension::new([PlutoBaseField::new(3), PlutoBaseField::new(70)]);
+    assert_eq!(x.to_be_bytes(), vec![70, 3]);
+    assert!(x.sgn0());
+    round_trip(x);
+    // the sign comes from c_1 when c_0 is zero
+    let y = PlutoBaseFieldExtension::new([PlutoBaseField::ZERO, PlutoBaseField::new(70)]);
+    assert!(!y.sgn0());
+    round_trip(y);
+    round_trip(PlutoBaseFieldExtension::ZERO);
+    assert_eq!(PlutoBaseFieldExtension::from_be_bytes(&[101, 0]), None);
+  }
+
+  #[test]
+  fn big_prime_field() {
+    assert_eq!(Bn254ScalarField::ENCODED_LEN, 32);
+    let minus_one = -Bn254ScalarField::ONE;
+    let bytes = minus_one.to_be_bytes();
+    assert_eq!(
+      hex::encode(&bytes),
+      "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000"
+    );
+    round_trip(minus_one);
+    round_trip(Bn254ScalarField::from(12345u64));
+    assert!(!minus_one.sgn0());
+
+    let mut modulus = bytes;
+    modulus[31] += 1;
+    assert_eq!(Bn254ScalarField::from_be_bytes(&modulus), None);
+  }
+}
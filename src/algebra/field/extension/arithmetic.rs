@@ -52,49 +52,47 @@ impl<const N: usize, const P: usize> SubAssign for GaloisField<N, P> {
   fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
 }
 
-// TODO: These should be able to be implemented generically for any `N` and `P`.
-// /// Returns the multiplication of two [`GaloisField<2, GF101>`] elements by reducing result
-// modulo /// irreducible polynomial.
-// impl<const N: usize, const P: u32> Mul for GaloisField<N, P> {
-//     type Output = Self;
-
-//     fn mul(self, rhs: Self) -> Self::Output {
-//       let poly_self = Polynomial::<Monomial, PrimeField<P>>::from(self);
-//       let poly_rhs = Polynomial::<Monomial, PrimeField<P>>::from(rhs);
-//       let poly_irred =
-//         Polynomial::<Monomial, PrimeField<P>>::from(Self::IRREDUCIBLE_POLYNOMIAL_COEFFS);
-//       let product = (poly_self * poly_rhs) % poly_irred;
-//       let res: [PrimeField<P>; N] =
-//         array::from_fn(|i|
-// product.coefficients.get(i).cloned().unwrap_or(PrimeField::<P>::ZERO));       Self::new(res)
-//     }
-//   }
-
-//   impl<const N: usize, const P: u32> MulAssign for GaloisField<N, P> {
-//     fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
-//   }
-//   impl<const N: usize, const P: u32> Product for GaloisField<N, P> {
-//     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-//       iter.reduce(|x, y| x * y).unwrap_or(Self::ONE)
-//     }
-//   }
-
-//   impl<const N: usize, const P: u32> Div for GaloisField<N, P> {
-//     type Output = Self;
-
-//     #[allow(clippy::suspicious_arithmetic_impl)]
-//     fn div(self, rhs: Self) -> Self::Output { self * rhs.inverse().expect("invalid inverse") }
-//   }
-
-//   impl<const N: usize, const P: u32> DivAssign for GaloisField<N, P> {
-//     fn div_assign(&mut self, rhs: Self) { *self = *self / rhs }
-//   }
-
-//   impl<const N: usize, const P: u32> Rem for GaloisField<N, P> {
-//     type Output = Self;
-
-//     fn rem(self, rhs: Self) -> Self::Output { self - (self / rhs) * rhs }
-//   }
+/// Multiplication of two [`GaloisField`] elements, reducing the product modulo the irreducible
+/// polynomial of the field.
+impl<const N: usize, const P: usize> Mul for GaloisField<N, P> {
+  type Output = Self;
+
+  fn mul(self, rhs: Self) -> Self::Output {
+    Self::from_values(irreducible::mul::<N, P>(&self.values(), &rhs.values(), &Self::MODULUS))
+  }
+}
+
+/// Multiplication assignment of two [`GaloisField`] elements.
+impl<const N: usize, const P: usize> MulAssign for GaloisField<N, P> {
+  fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+/// Product of a collection of [`GaloisField`] elements.
+impl<const N: usize, const P: usize> Product for GaloisField<N, P> {
+  fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.reduce(|x, y| x * y).unwrap_or(Self::ONE)
+  }
+}
+
+/// Division of two [`GaloisField`] elements.
+impl<const N: usize, const P: usize> Div for GaloisField<N, P> {
+  type Output = Self;
+
+  #[allow(clippy::suspicious_arithmetic_impl)]
+  fn div(self, rhs: Self) -> Self::Output { self * rhs.inverse().expect("invalid inverse") }
+}
+
+/// Division assignment of two [`GaloisField`] elements.
+impl<const N: usize, const P: usize> DivAssign for GaloisField<N, P> {
+  fn div_assign(&mut self, rhs: Self) { *self = *self / rhs }
+}
+
+/// Remainder of two [`GaloisField`] elements, which is always zero in a field.
+impl<const N: usize, const P: usize> Rem for GaloisField<N, P> {
+  type Output = Self;
+
+  fn rem(self, rhs: Self) -> Self::Output { self - (self / rhs) * rhs }
+}
 
 ///////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -0,0 +1,124 @@
+//! The extension tower of the BLS12-381 curve, on top of the 381-bit [`Bls12381BaseField`]:
+//! - `Fp2 = Fp[u]/(u^2 + 1)`, as `-1` is not a square since `p = 3 mod 4`;
+//! - `Fp6 = Fp2[v]/(v^3 - ξ)` with `ξ = 1 + u`, neither a square nor a cube in `Fp2`;
+//! - `Fp12 = Fp6[w]/(w^2 - v)`, so that `w^6 = ξ`.
+//!
+//! This is the same shape as the [BN254 tower](super::bn254) with a smaller non-residue, and the
+//! towers of the [pairing-friendly curves draft](https://datatracker.ietf.org/doc/draft-irtf-cfrg-pairing-friendly-curves/).
+use crypto_bigint::U384;
+
+use super::tower::*;
+use crate::algebra::field::{big_prime::Bls12381BaseField, Field};
+
+/// `Fp2 = Fp[u]/(u^2 + 1)` over the [`Bls12381BaseField`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bls12381Fp2Config;
+
+impl TowerConfig<2> for Bls12381Fp2Config {
+  type BaseField = Bls12381BaseField;
+
+  /// `ξ^((p - 1)/2) = -1`, as `ξ = -1` is not a square.
+  const FROBENIUS_COEFFS: [Bls12381BaseField; 2] = [Bls12381BaseField::ONE, Self::NON_RESIDUE];
+  const NON_RESIDUE: Bls12381BaseField = Bls12381BaseField::new(U384::from_be_hex(
+    "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaaa",
+  ));
+}
+
+impl TowerPrimitiveElement<2> for Bls12381Fp2Config {
+  /// `1 + 2u`, checked against the factorization of `p^2 - 1 = (p - 1)(p + 1)`.
+  const PRIMITIVE_ELEMENT: [Bls12381BaseField; 2] =
+    [Bls12381BaseField::ONE, Bls12381BaseField::new(U384::from_u8(2))];
+}
+
+/// The quadratic extension of the [`Bls12381BaseField`], the base field of the twist of BLS12-381.
+pub type Bls12381Fp2 = QuadraticExtension<Bls12381Fp2Config>;
+
+/// `Fp6 = Fp2[v]/(v^3 - (1 + u))`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bls12381Fp6Config;
+
+impl TowerConfig<3> for Bls12381Fp6Config {
+  type BaseField = Bls12381Fp2;
+
+  /// `ξ^⌊ip/3⌋ = ξ^(i(p - 1)/3)`, as `p = 1 mod 3`.
+  const FROBENIUS_COEFFS: [Bls12381Fp2; 3] = [
+    Bls12381Fp2::ONE,
+    Bls12381Fp2::new([
+      Bls12381BaseField::ZERO,
+      Bls12381BaseField::new(U384::from_be_hex(
+        "1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaac",
+      )),
+    ]),
+    Bls12381Fp2::new([
+      Bls12381BaseField::new(U384::from_be_hex(
+        "1a0111ea397fe699ec02408663d4de85aa0d857d89759ad4897d29650fb85f9b409427eb4f49fffd8bfd00000000aaad",
+      )),
+      Bls12381BaseField::ZERO,
+    ]),
+  ];
+  const NON_RESIDUE: Bls12381Fp2 =
+    Bls12381Fp2::new([Bls12381BaseField::ONE, Bls12381BaseField::ONE]);
+}
+
+/// The degree 6 extension of the [`Bls12381BaseField`].
+pub type Bls12381Fp6 = CubicExtension<Bls12381Fp6Config>;
+
+/// `Fp12 = Fp6[w]/(w^2 - v)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bls12381Fp12Config;
+
+impl TowerConfig<2> for Bls12381Fp12Config {
+  type BaseField = Bls12381Fp6;
+
+  /// `v^((p - 1)/2) = ξ^((p - 1)/6)`, which lies in `Fp2` as `p = 1 mod 6`.
+  const FROBENIUS_COEFFS: [Bls12381Fp6; 2] = [
+    Bls12381Fp6::ONE,
+    Bls12381Fp6::new([
+      Bls12381Fp2::new([
+        Bls12381BaseField::new(U384::from_be_hex(
+          "1904d3bf02bb0667c231beb4202c0d1f0fd603fd3cbd5f4f7b2443d784bab9c4f67ea53d63e7813d8d0775ed92235fb8",
+        )),
+        Bls12381BaseField::new(U384::from_be_hex(
+          "00fc3e2b36c4e03288e9e902231f9fb854a14787b6c7b36fec0c8ec971f63c5f282d5ac14d6c7ec22cf78a126ddc4af3",
+        )),
+      ]),
+      Bls12381Fp2::ZERO,
+      Bls12381Fp2::ZERO,
+    ]),
+  ];
+  const NON_RESIDUE: Bls12381Fp6 =
+    Bls12381Fp6::new([Bls12381Fp2::ZERO, Bls12381Fp2::ONE, Bls12381Fp2::ZERO]);
+}
+
+/// The degree 12 extension of the [`Bls12381BaseField`], where the BLS12-381 pairing takes its
+/// values.
+pub type Bls12381Fp12 = QuadraticExtension<Bls12381Fp12Config>;
+
+#[cfg(test)]
+mod tests {
+  use rand::Rng;
+
+  use super::*;
+  use crate::algebra::field::{pow_limbs, FrobeniusMap, LimbRepr};
+
+  #[test]
+  fn non_residues() {
+    assert_eq!(Bls12381Fp2Config::NON_RESIDUE, -Bls12381BaseField::ONE);
+    let u = Bls12381Fp2::new([Bls12381BaseField::ZERO, Bls12381BaseField::ONE]);
+    assert_eq!(u * u, -Bls12381Fp2::ONE);
+    let w = Bls12381Fp12::new([Bls12381Fp6::ZERO, Bls12381Fp6::ONE]);
+    let xi = Bls12381Fp12::from_base(Bls12381Fp6::from_base(Bls12381Fp6Config::NON_RESIDUE));
+    assert_eq!(w.pow(6), xi);
+  }
+
+  #[test]
+  fn arithmetic() {
+    let mut rng = rand::thread_rng();
+    let (x, y) = (rng.gen::<Bls12381Fp12>(), rng.gen::<Bls12381Fp12>());
+    assert_eq!(x * x.inverse().unwrap(), Bls12381Fp12::ONE);
+    assert_eq!((x * y) / y, x);
+    assert_eq!(x.frobenius_map(1), pow_limbs(x, &Bls12381BaseField::modulus()));
+    assert_eq!(x.frobenius_map(6), x.conjugate());
+    assert_eq!(x.frobenius_map(12), x);
+  }
+}
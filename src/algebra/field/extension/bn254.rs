@@ -0,0 +1,126 @@
+//! The extension tower of the BN254 curve, on top of the 254-bit [`Bn254BaseField`]:
+//! - `Fp2 = Fp[u]/(u^2 + 1)`, as `-1` is not a square since `p = 3 mod 4`;
+//! - `Fp6 = Fp2[v]/(v^3 - ξ)` with `ξ = 9 + u`, neither a square nor a cube in `Fp2`;
+//! - `Fp12 = Fp6[w]/(w^2 - v)`, so that `w^6 = ξ`.
+//!
+//! The points of the sextic twist used for `G2` live in `Fp2`, and the pairing takes its values in
+//! `Fp12`. These are the towers of [EIP-197](https://eips.ethereum.org/EIPS/eip-197).
+use crypto_bigint::U256;
+
+use super::tower::*;
+use crate::algebra::field::{big_prime::Bn254BaseField, Field};
+
+/// `Fp2 = Fp[u]/(u^2 + 1)` over the [`Bn254BaseField`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bn254Fp2Config;
+
+impl TowerConfig<2> for Bn254Fp2Config {
+  type BaseField = Bn254BaseField;
+
+  /// `ξ^((p - 1)/2) = -1`, as `ξ = -1` is not a square.
+  const FROBENIUS_COEFFS: [Bn254BaseField; 2] = [Bn254BaseField::ONE, Self::NON_RESIDUE];
+  const NON_RESIDUE: Bn254BaseField = Bn254BaseField::new(U256::from_be_hex(
+    "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46",
+  ));
+}
+
+impl TowerPrimitiveElement<2> for Bn254Fp2Config {
+  /// `1 + 9u`, checked against the factorization of `p^2 - 1 = (p - 1)(p + 1)`.
+  const PRIMITIVE_ELEMENT: [Bn254BaseField; 2] =
+    [Bn254BaseField::ONE, Bn254BaseField::new(U256::from_u8(9))];
+}
+
+/// The quadratic extension of the [`Bn254BaseField`], the base field of the twist of BN254.
+pub type Bn254Fp2 = QuadraticExtension<Bn254Fp2Config>;
+
+/// `Fp6 = Fp2[v]/(v^3 - (9 + u))`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bn254Fp6Config;
+
+impl TowerConfig<3> for Bn254Fp6Config {
+  type BaseField = Bn254Fp2;
+
+  /// `ξ^⌊ip/3⌋ = ξ^(i(p - 1)/3)`, as `p = 1 mod 3`.
+  const FROBENIUS_COEFFS: [Bn254Fp2; 3] = [
+    Bn254Fp2::ONE,
+    Bn254Fp2::new([
+      Bn254BaseField::new(U256::from_be_hex(
+        "2fb347984f7911f74c0bec3cf559b143b78cc310c2c3330c99e39557176f553d",
+      )),
+      Bn254BaseField::new(U256::from_be_hex(
+        "16c9e55061ebae204ba4cc8bd75a079432ae2a1d0b7c9dce1665d51c640fcba2",
+      )),
+    ]),
+    Bn254Fp2::new([
+      Bn254BaseField::new(U256::from_be_hex(
+        "05b54f5e64eea80180f3c0b75a181e84d33365f7be94ec72848a1f55921ea762",
+      )),
+      Bn254BaseField::new(U256::from_be_hex(
+        "2c145edbe7fd8aee9f3a80b03b0b1c923685d2ea1bdec763c13b4711cd2b8126",
+      )),
+    ]),
+  ];
+  const NON_RESIDUE: Bn254Fp2 =
+    Bn254Fp2::new([Bn254BaseField::new(U256::from_u8(9)), Bn254BaseField::ONE]);
+}
+
+/// The degree 6 extension of the [`Bn254BaseField`].
+pub type Bn254Fp6 = CubicExtension<Bn254Fp6Config>;
+
+/// `Fp12 = Fp6[w]/(w^2 - v)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bn254Fp12Config;
+
+impl TowerConfig<2> for Bn254Fp12Config {
+  type BaseField = Bn254Fp6;
+
+  /// `v^((p - 1)/2) = ξ^((p - 1)/6)`, which lies in `Fp2` as `p = 1 mod 6`.
+  const FROBENIUS_COEFFS: [Bn254Fp6; 2] = [
+    Bn254Fp6::ONE,
+    Bn254Fp6::new([
+      Bn254Fp2::new([
+        Bn254BaseField::new(U256::from_be_hex(
+          "1284b71c2865a7dfe8b99fdd76e68b605c521e08292f2176d60b35dadcc9e470",
+        )),
+        Bn254BaseField::new(U256::from_be_hex(
+          "246996f3b4fae7e6a6327cfe12150b8e747992778eeec7e5ca5cf05f80f362ac",
+        )),
+      ]),
+      Bn254Fp2::ZERO,
+      Bn254Fp2::ZERO,
+    ]),
+  ];
+  const NON_RESIDUE: Bn254Fp6 = Bn254Fp6::new([Bn254Fp2::ZERO, Bn254Fp2::ONE, Bn254Fp2::ZERO]);
+}
+
+/// The degree 12 extension of the [`Bn254BaseField`], where the BN254 pairing takes its values.
+pub type Bn254Fp12 = QuadraticExtension<Bn254Fp12Config>;
+
+#[cfg(test)]
+mod tests {
+  use rand::Rng;
+
+  use super::*;
+  use crate::algebra::field::{pow_limbs, FrobeniusMap, LimbRepr};
+
+  #[test]
+  fn non_residues() {
+    assert_eq!(Bn254Fp2Config::NON_RESIDUE, -Bn254BaseField::ONE);
+    let u = Bn254Fp2::new([Bn254BaseField::ZERO, Bn254BaseField::ONE]);
+    assert_eq!(u * u, -Bn254Fp2::ONE);
+    let w = Bn254Fp12::new([Bn254Fp6::ZERO, Bn254Fp6::ONE]);
+    let xi = Bn254Fp12::from_base(Bn254Fp6::from_base(Bn254Fp6Config::NON_RESIDUE));
+    assert_eq!(w.pow(6), xi);
+  }
+
+  #[test]
+  fn arithmetic() {
+    let mut rng = rand::thread_rng();
+    let (x, y) = (rng.gen::<Bn254Fp12>(), rng.gen::<Bn254Fp12>());
+    assert_eq!(x * x.inverse().unwrap(), Bn254Fp12::ONE);
+    assert_eq!((x * y) / y, x);
+    assert_eq!(x.frobenius_map(1), pow_limbs(x, &Bn254BaseField::modulus()));
+    assert_eq!(x.frobenius_map(6), x.conjugate());
+    assert_eq!(x.frobenius_map(12), x);
+  }
+}
@@ -6,125 +6,36 @@
 //! The curve used in [`curve::pluto_curve::PlutoBaseCurve`] supports degree two extension field
 //! [`curve::pluto_curve::PlutoExtendedCurve`] from GF(101) to have points in GF(101^2). This can be
 //! verified by finding out embedding degree of the curve, i.e. smallest k such that r|q^k-1.
+//!
+//! The multiplicative generator of GF(101^2) is kept as `14 + 9t`, which can be verified using sage
+//! script
+//! ```sage
+//! F = GF(101)
+//! Ft.<t> = F[]
+//! P = Ft(t ^ 2 + 2)
+//! F_2 = GF(101 ^ 2, name="t", modulus=P)
+//! f_2_primitive_element = F_2([14, 9])
+//! assert f_2_primitive_element.multiplicative_order() == 101^2-1
+//! ```
 
 use super::*;
-use crate::{Distribution, Monomial, Polynomial, Rng, Standard};
+use crate::{Distribution, Rng, Standard};
 
-impl ExtensionField<2, 101> for PlutoBaseFieldExtension {
-  /// irreducible polynomial used to reduce field polynomials to second degree:
-  /// `F[X]/(X^2+2)`
-  const IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS: [PlutoBaseField; 3] =
-    [PlutoBaseField::new(2), PlutoBaseField::ZERO, PlutoBaseField::ONE];
+impl PublishedGenerator<2> for PlutoBaseFieldExtension {
+  const PUBLISHED_PRIMITIVE_ELEMENT: Option<[usize; 2]> = Some([14, 9]);
 }
 
 impl PlutoBaseFieldExtension {
-  fn norm(&self) -> PlutoBaseField {
-    let mut result = self.coeffs[0].pow(2);
-    result -= -PlutoBaseField::new(2) * self.coeffs[1].pow(2);
-    result
-  }
-
   /// Computes euler criterion of the field element, i.e. Returns true if the element is a quadratic
   /// residue (a square number) in the field.
   pub fn euler_criterion(&self) -> bool { self.norm().euler_criterion() }
 
   /// Computes square root of the quadratic field element `(x_0 + x_1*u)` (if it exists) and return
-  /// a tuple of `(r, -r)` where `r` is lower.
-  ///
-  /// - `(a_0 + a_1*u)^2 = a_0^2+2*a_0*a_1*u+βa_1^2 = (x_0 + x_1*u)`. Equating x_0 and x_1 with LHS:
-  /// - `x_0 = a_0^2 + βa_1^2`
-  /// - `x_1 = 2a_0*a_1`
+  /// a tuple of `(r, -r)` where `r` is lower. As `101^2 = 1 mod 8` this is done with
+  /// [`tonelli_shanks`][crate::algebra::field::sqrt::tonelli_shanks], see [`SquareRoot`].
   pub fn sqrt(&self) -> Option<(Self, Self)> {
-    let (a0, a1) = (self.coeffs[0], self.coeffs[1]);
-
-    // irreducible poly: F[X]/(X^2+2)
-    let residue = -PlutoBaseFieldExtension::IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS[0];
-
-    // x_0 = a_0^2 + βa_1^2
-    if a1 == PlutoBaseField::ZERO {
-      // if a_1 = 0, then straight away compute sqrt of a_0 as base field element
-      if a0.euler_criterion() {
-        return a0.sqrt().map(|(res0, res1)| (Self::from(res0), Self::from(res1)));
-      } else {
-        // if a_0 is not a square, then compute a_1 = sqrt(x_0 / β)
-        return a0.div(residue).sqrt().map(|(res0, res1)| {
-          (Self::new([PlutoBaseField::ZERO, res0]), Self::new([PlutoBaseField::ZERO, res1]))
-        });
-      }
-    }
-
-    // x_0 = ((a_0 ± (a_0² − βa_1²)^½)/2)^½
-    // x_1 = a_1/(2x_0)
-
-    // α = (a_0² − βa_1²)
-    let alpha = self.norm();
-    let two_inv = PlutoBaseField::new(2).inverse().expect("2 should have an inverse");
-
-    alpha.sqrt().map(|(alpha, _)| {
-      let mut delta = (alpha + a0) * two_inv;
-      if !delta.euler_criterion() {
-        delta -= alpha;
-      }
-
-      let x0 = delta.sqrt().expect("delta must have an square root").0;
-      let x0_inv = x0.inverse().expect("x0 must have an inverse");
-      let x1 = a1 * two_inv * x0_inv;
-      let x = Self::new([x0, x1]);
-      if -x < x {
-        (-x, x)
-      } else {
-        (x, -x)
-      }
-    })
-  }
-}
-
-impl Field for PlutoBaseFieldExtension {
-  const ONE: Self = Self::new([PlutoBaseField::ONE, PlutoBaseField::ZERO]);
-  const ZERO: Self = Self::new([PlutoBaseField::ZERO, PlutoBaseField::ZERO]);
-
-  /// Computes the multiplicative inverse of `a`, i.e. 1 / (a0 + a1 * t).
-  /// Multiply by `a0 - a1 * t` in numerator and denominator.
-  /// Denominator equals `(a0 + a1 * t) * (a0 - a1 * t) = a0.pow(2) - a1.pow(2) * Q::residue()`
-  fn inverse(&self) -> Option<Self> {
-    if *self == Self::ZERO {
-      return None;
-    }
-
-    let mut res = Self::default();
-    let scalar = (self.coeffs[0].pow(2) + PlutoBaseField::from(2u32) * self.coeffs[1].pow(2))
-      .inverse()
-      .unwrap();
-    res.coeffs[0] = self.coeffs[0] * scalar;
-    res.coeffs[1] = -self.coeffs[1] * scalar;
-    Some(res)
+    self.square_root().map(|x| if -x < x { (-x, x) } else { (x, -x) })
   }
-
-  fn pow(self, power: usize) -> Self {
-    if power == 0 {
-      Self::ONE
-    } else if power == 1 {
-      self
-    } else if power % 2 == 0 {
-      self.pow(power / 2) * self.pow(power / 2)
-    } else {
-      self.pow(power / 2) * self.pow(power / 2) * self
-    }
-  }
-}
-
-impl FiniteField for PlutoBaseFieldExtension {
-  /// Retrieves a multiplicative generator for GF(101) inside of [`GaloisField<2, GF101>`].
-  /// This can be verified using sage script
-  /// ```sage
-  /// F = GF(101)
-  /// Ft.<t> = F[]
-  /// P = Ft(t ^ 2 + 2)
-  /// F_2 = GF(101 ^ 2, name="t", modulus=P)
-  /// f_2_primitive_element = F_2([14, 9])
-  /// assert f_2_primitive_element.multiplicative_order() == 101^2-1
-  /// ```
-  const PRIMITIVE_ELEMENT: Self = Self::new([PlutoBaseField::new(14), PlutoBaseField::new(9)]);
 }
 
 impl<const N: usize, const P: usize> Distribution<GaloisField<N, P>> for Standard {
@@ -135,50 +46,6 @@ impl<const N: usize, const P: usize> Distribution<GaloisField<N, P>> for Standar
   }
 }
 
-/// Returns the multiplication of two [`GaloisField<2, GF101>`] elements by reducing result modulo
-/// irreducible polynomial.
-impl Mul for PlutoBaseFieldExtension {
-  type Output = Self;
-
-  fn mul(self, rhs: Self) -> Self::Output {
-    let poly_self = Polynomial::<Monomial, PlutoBaseField, 2>::from(self);
-    let poly_rhs = Polynomial::<Monomial, PlutoBaseField, 2>::from(rhs);
-    let poly_irred =
-      Polynomial::<Monomial, PlutoBaseField, 3>::from(Self::IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS);
-    let product = (poly_self * poly_rhs) % poly_irred;
-    let res: [PlutoBaseField; 2] =
-      array::from_fn(|i| product.coefficients.get(i).cloned().unwrap_or(PlutoBaseField::ZERO));
-
-    Self::new(res)
-  }
-}
-
-impl MulAssign for PlutoBaseFieldExtension {
-  fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
-}
-impl Product for PlutoBaseFieldExtension {
-  fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-    iter.reduce(|x, y| x * y).unwrap_or(Self::ONE)
-  }
-}
-
-impl Div for PlutoBaseFieldExtension {
-  type Output = Self;
-
-  #[allow(clippy::suspicious_arithmetic_impl)]
-  fn div(self, rhs: Self) -> Self::Output { self * rhs.inverse().expect("invalid inverse") }
-}
-
-impl DivAssign for PlutoBaseFieldExtension {
-  fn div_assign(&mut self, rhs: Self) { *self = *self / rhs }
-}
-
-impl Rem for PlutoBaseFieldExtension {
-  type Output = Self;
-
-  fn rem(self, rhs: Self) -> Self::Output { self - (self / rhs) * rhs }
-}
-
 #[cfg(test)]
 mod tests {
   use rstest::rstest;
@@ -318,6 +185,36 @@ mod tests {
     assert_eq!((x * y) / z, x * (y / z));
   }
 
+  #[test]
+  fn irreducible_polynomial() {
+    assert_eq!(<PlutoBaseFieldExtension>::IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS, [
+      PlutoBaseField::new(2),
+      PlutoBaseField::ZERO,
+      PlutoBaseField::ONE
+    ]);
+    assert_eq!(
+      <PlutoBaseFieldExtension>::PRIMITIVE_ELEMENT,
+      <PlutoBaseFieldExtension>::new([PlutoBaseField::new(14), PlutoBaseField::new(9)])
+    );
+  }
+
+  #[test]
+  fn frobenius_is_conjugation() {
+    // t^101 = t (t^2)^50 = t (-2)^50 = -t, since -2 is not a square mod 101
+    let x = <PlutoBaseFieldExtension>::new([PlutoBaseField::new(10), PlutoBaseField::new(20)]);
+    let conjugate =
+      <PlutoBaseFieldExtension>::new([PlutoBaseField::new(10), -PlutoBaseField::new(20)]);
+    assert_eq!(x.frobenius(1), conjugate);
+    assert_eq!(x.frobenius(1), x.pow(101));
+    assert_eq!(x.conjugates(), [x, conjugate]);
+
+    // N(a + bt) = a^2 + 2b^2 and Tr(a + bt) = 2a
+    assert_eq!(x.norm(), PlutoBaseField::new(100 + 2 * 400));
+    assert_eq!(x.trace(), PlutoBaseField::new(20));
+    // X^2 - Tr(x) X + N(x)
+    assert_eq!(x.minimal_polynomial().coefficients, [x.norm(), -x.trace(), PlutoBaseField::ONE]);
+  }
+
   #[test]
   fn generator() {
     assert_eq!(
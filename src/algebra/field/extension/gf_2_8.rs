@@ -1,123 +1,41 @@
-//! This module contains an implementation of the quadratic extension field GF(2^8).
-//! Elements represented as coefficients of a [`Polynomial`] in the [`Monomial`] basis of degree 1
-//! in form: `a_0 + a_1*t`` where {a_0, a_1} \in \mathhbb{F}. Uses irreducible poly of the form:
-//! (X^2-K).
+//! This module pins down the representation of the extension field GF(2^8) used by our
+//! [AES implementation][`crate::encryption::symmetric::aes`]. Elements are represented as
+//! coefficients of a polynomial of degree 7 over [`AESField`] reduced modulo the irreducible
+//! polynomial `x^8 + x^4 + x^3 + x + 1` of the AES specification, which is exactly the polynomial
+//! found by [`irreducible::irreducible_polynomial`].
 //!
-//! This extension field is used for our [AES implementation][`crate::encryption::symmetric::aes`].
-use super::{prime::AESField, *};
-use crate::{Monomial, Polynomial};
+//! The generator of its multiplicative group is kept as `x^4 + x + 1`, i.e. `0x13`.
+use super::*;
 
-impl Field for GaloisField<8, 2> {
-  const ONE: Self = Self::new([
-    AESField::ONE,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-  ]);
-  const ZERO: Self = Self::new([
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-  ]);
+impl PublishedGenerator<8> for AESFieldExtension {
+  const PUBLISHED_PRIMITIVE_ELEMENT: Option<[usize; 8]> = Some([1, 1, 0, 0, 1, 0, 0, 0]);
+}
 
-  /// Computes the multiplicative inverse of `a`, i.e. 1 / (a0 + a1 * t).
-  fn inverse(&self) -> Option<Self> {
-    if *self == Self::ZERO {
-      return None;
-    }
+#[cfg(test)]
+mod tests {
+  use super::*;
 
-    let res = self.pow(Self::ORDER - 2);
-    Some(res)
+  #[test]
+  fn aes_polynomial() {
+    let coeffs = [1, 1, 0, 1, 1, 0, 0, 0, 1].map(AESField::new);
+    assert_eq!(AESFieldExtension::IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS, coeffs);
   }
 
-  fn pow(self, power: usize) -> Self {
-    if power == 0 {
-      Self::ONE
-    } else if power == 1 {
-      self
-    } else if power % 2 == 0 {
-      self.pow(power / 2) * self.pow(power / 2)
-    } else {
-      self.pow(power / 2) * self.pow(power / 2) * self
-    }
+  #[test]
+  fn generator() {
+    // `x^4 + x + 1`, i.e. `0x13`, generates the multiplicative group of the AES field.
+    let generator = AESFieldExtension::new([1, 1, 0, 0, 1, 0, 0, 0].map(AESField::new));
+    assert_eq!(AESFieldExtension::PRIMITIVE_ELEMENT, generator);
+    assert_eq!(generator.pow(255), AESFieldExtension::ONE);
+    assert_eq!((1..255).filter(|&i| generator.pow(i) == AESFieldExtension::ONE).count(), 0);
   }
-}
 
-impl FiniteField for GaloisField<8, 2> {
-  const PRIMITIVE_ELEMENT: Self = Self::new([
-    AESField::ONE,
-    AESField::ONE,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ONE,
-    AESField::ZERO,
-    AESField::ZERO,
-    AESField::ZERO,
-  ]);
-}
-
-impl ExtensionField<8, 2> for GaloisField<8, 2> {
-  /// Represents the irreducible polynomial x^8 + x^4 + x^3 + x + 1.
-  const IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS: [AESField; 9] = [
-    AESField::ONE,  // 1
-    AESField::ONE,  // a
-    AESField::ZERO, // a^2
-    AESField::ONE,  // a^3
-    AESField::ONE,  // a^4
-    AESField::ZERO, // a^5
-    AESField::ZERO, // a^6
-    AESField::ZERO, // a^7
-    AESField::ONE,  // a^8
-  ];
-}
-
-/// Returns the multiplication of two [`Ext<8, GF2>`] elements by reducing result modulo
-/// irreducible polynomial.
-impl Mul for GaloisField<8, 2> {
-  type Output = Self;
-
-  fn mul(self, rhs: Self) -> Self::Output {
-    let poly_self = Polynomial::<Monomial, AESField, 8>::from(self);
-    let poly_rhs = Polynomial::<Monomial, AESField, 8>::from(rhs);
-    let poly_irred =
-      Polynomial::<Monomial, AESField, 9>::from(Self::IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS);
-    let product = (poly_self * poly_rhs) % poly_irred;
-    let res: [AESField; 8] =
-      array::from_fn(|i| product.coefficients.get(i).cloned().unwrap_or(AESField::ZERO));
-    Self::new(res)
-  }
-}
-impl MulAssign for GaloisField<8, 2> {
-  fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
-}
-impl Product for GaloisField<8, 2> {
-  fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-    iter.reduce(|x, y| x * y).unwrap_or(Self::ONE)
+  #[test]
+  fn mul() {
+    // {57} * {83} = {c1} from FIPS 197, section 4.2
+    let from_byte =
+      |b: u8| AESFieldExtension::new(std::array::from_fn(|i| AESField::new((b >> i) as usize & 1)));
+    assert_eq!(from_byte(0x57) * from_byte(0x83), from_byte(0xc1));
+    assert_eq!(from_byte(0x57).inverse().unwrap() * from_byte(0x57), AESFieldExtension::ONE);
   }
 }
-
-impl Div for GaloisField<8, 2> {
-  type Output = Self;
-
-  #[allow(clippy::suspicious_arithmetic_impl)]
-  fn div(self, rhs: Self) -> Self::Output { self * rhs.inverse().expect("invalid inverse") }
-}
-
-impl DivAssign for GaloisField<8, 2> {
-  fn div_assign(&mut self, rhs: Self) { *self = *self / rhs }
-}
-
-impl Rem for GaloisField<8, 2> {
-  type Output = Self;
-
-  fn rem(self, rhs: Self) -> Self::Output { self - (self / rhs) * rhs }
-}
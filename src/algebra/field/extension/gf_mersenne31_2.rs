@@ -0,0 +1,39 @@
+//! This module pins down the representation of the complex extension of the
+//! [`Mersenne31`][crate::field::prime::Mersenne31] field. Elements are `a + b i` with `i^2 = -1`,
+//! stored as the coefficients `[a, b]`, since `x^2 + 1` is the first irreducible polynomial found
+//! by [`irreducible::irreducible_polynomial`]. Multiplication then is the familiar
+//! `(a + b i)(c + d i) = (ac - bd) + (ad + bc) i`.
+#[cfg(test)]
+mod tests {
+  use super::super::*;
+
+  type F = Mersenne31;
+
+  #[test]
+  fn complex_polynomial() {
+    let coeffs = [1, 0, 1].map(F::new);
+    assert_eq!(Mersenne31Complex::IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS, coeffs);
+  }
+
+  #[test]
+  fn mul() {
+    let i = Mersenne31Complex::new([F::ZERO, F::ONE]);
+    assert_eq!(i * i, -Mersenne31Complex::ONE);
+
+    let (a, b, c, d) = (F::new(1 << 30), F::new(12345), F::new(0x7FFF_0000), F::new(3));
+    let x = Mersenne31Complex::new([a, b]);
+    let y = Mersenne31Complex::new([c, d]);
+    assert_eq!(x * y, Mersenne31Complex::new([a * c - b * d, a * d + b * c]));
+    assert_eq!(x * x.inverse().unwrap(), Mersenne31Complex::ONE);
+  }
+
+  #[test]
+  fn two_adic_root_of_unity() {
+    assert_eq!(Mersenne31Complex::TWO_ADICITY, 32);
+    let root = Mersenne31Complex::TWO_ADIC_ROOT_OF_UNITY;
+    assert_eq!(root.pow(1 << 32), Mersenne31Complex::ONE);
+    assert_eq!(root.pow(1 << 31), -Mersenne31Complex::ONE);
+    // the base field only has the square roots of unity
+    assert_eq!(F::TWO_ADICITY, 1);
+  }
+}
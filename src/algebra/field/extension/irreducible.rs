@@ -0,0 +1,465 @@
+//! Compile-time construction of the modulus and a primitive element of a
+//! [`GaloisField`](super::GaloisField).
+//!
+//! A polynomial is represented by the array of its coefficients in order of increasing degree,
+//! each coefficient being the canonical representative in `0..P`. The moduli are monic of degree
+//! `N`, so only their `N` lower coefficients are stored, i.e. `f = [f_0, ..., f_{N-1}]` stands for
+//! `x^N + f_{N-1} x^{N-1} + ... + f_0`. Everything here is a `const fn` so that
+//! [`IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS`](super::ExtensionField::IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS)
+//! and [`PRIMITIVE_ELEMENT`](crate::algebra::field::FiniteField::PRIMITIVE_ELEMENT) are computed by
+//! the compiler.
+//!
+//! ## Irreducibility
+//! [Rabin's test](https://en.wikipedia.org/wiki/Factorization_of_polynomials_over_finite_fields#Rabin's_test_of_irreducibility)
+//! states that a monic `f` of degree `n` over `F_p` is irreducible if and only if
+//! - `f` divides `x^(p^n) - x`, and
+//! - `gcd(x^(p^(n/q)) - x, f) = 1` for every prime `q` dividing `n`.
+//!
+//! The first condition says all roots of `f` live in `F_{p^n}`, the second that none of them live
+//! in a proper subfield.
+//!
+//! ## Search order
+//! [`irreducible_polynomial`] prefers sparse polynomials since they make the reduction cheap:
+//! binomials `x^N + a` first, then trinomials `x^N + b x^k + a`, and only then every monic
+//! polynomial in order of its coefficients read as a base `P` number. For the fields used
+//! throughout `ronkathon` this reproduces the hand-picked moduli, e.g. `x^2 + 2` for
+//! [`PlutoBaseFieldExtension`](super::PlutoBaseFieldExtension) and the AES polynomial `x^8 + x^4 +
+//! x^3 + x + 1` for [`AESFieldExtension`](super::AESFieldExtension).
+
+/// `a * b mod P`, widened so that primes close to `usize::MAX` do not overflow.
+const fn mul_mod<const P: usize>(a: usize, b: usize) -> usize {
+  ((a as u128 * b as u128) % P as u128) as usize
+}
+
+/// `a + b mod P`
+const fn add_mod<const P: usize>(a: usize, b: usize) -> usize {
+  ((a as u128 + b as u128) % P as u128) as usize
+}
+
+/// `a - b mod P`
+const fn sub_mod<const P: usize>(a: usize, b: usize) -> usize { add_mod::<P>(a, P - b) }
+
+/// `a^-1 mod P` by Fermat's little theorem.
+const fn inv_mod<const P: usize>(a: usize) -> usize {
+  let mut result = 1;
+  let mut base = a;
+  let mut exp = P - 2;
+  while exp > 0 {
+    if exp & 1 == 1 {
+      result = mul_mod::<P>(result, base);
+    }
+    base = mul_mod::<P>(base, base);
+    exp >>= 1;
+  }
+  result
+}
+
+/// The degree of `a`, or `None` for the zero polynomial.
+const fn degree<const N: usize>(a: &[usize; N]) -> Option<usize> {
+  let mut i = N;
+  while i > 0 {
+    i -= 1;
+    if a[i] != 0 {
+      return Some(i);
+    }
+  }
+  None
+}
+
+const fn equal<const N: usize>(a: &[usize; N], b: &[usize; N]) -> bool {
+  let mut i = 0;
+  while i < N {
+    if a[i] != b[i] {
+      return false;
+    }
+    i += 1;
+  }
+  true
+}
+
+/// The constant polynomial `1`.
+const fn one<const N: usize>() -> [usize; N] {
+  let mut one = [0; N];
+  one[0] = 1;
+  one
+}
+
+/// `a * x mod f`, using `x^N = -(f_{N-1} x^{N-1} + ... + f_0)`.
+const fn mul_by_x<const N: usize, const P: usize>(a: [usize; N], f: &[usize; N]) -> [usize; N] {
+  let lead = a[N - 1];
+  let mut result = [0; N];
+  let mut i = N - 1;
+  while i > 0 {
+    result[i] = sub_mod::<P>(a[i - 1], mul_mod::<P>(lead, f[i]));
+    i -= 1;
+  }
+  result[0] = sub_mod::<P>(0, mul_mod::<P>(lead, f[0]));
+  result
+}
+
+/// `a * b mod f` using Horner's rule on the coefficients of `b`, so that no intermediate product
+/// ever exceeds degree `N - 1`.
+pub(crate) const fn mul<const N: usize, const P: usize>(
+  a: &[usize; N],
+  b: &[usize; N],
+  f: &[usize; N],
+) -> [usize; N] {
+  let mut result = [0; N];
+  let mut i = N;
+  while i > 0 {
+    i -= 1;
+    result = mul_by_x::<N, P>(result, f);
+    let mut j = 0;
+    while j < N {
+      result[j] = add_mod::<P>(result[j], mul_mod::<P>(b[i], a[j]));
+      j += 1;
+    }
+  }
+  result
+}
+
+/// `a^exp mod f` by square-and-multiply.
+pub(crate) const fn pow<const N: usize, const P: usize>(
+  a: &[usize; N],
+  exp: usize,
+  f: &[usize; N],
+) -> [usize; N] {
+  let mut result = one::<N>();
+  let mut base = *a;
+  let mut exp = exp;
+  while exp > 0 {
+    if exp & 1 == 1 {
+      result = mul::<N, P>(&result, &base, f);
+    }
+    base = mul::<N, P>(&base, &base, f);
+    exp >>= 1;
+  }
+  result
+}
+
+/// `x^(P^k) mod f`, i.e. `k` applications of the Frobenius map to `x`.
+const fn frobenius_of_x<const N: usize, const P: usize>(f: &[usize; N], k: usize) -> [usize; N] {
+  let mut result = mul_by_x::<N, P>(one::<N>(), f);
+  let mut i = 0;
+  while i < k {
+    result = pow::<N, P>(&result, P, f);
+    i += 1;
+  }
+  result
+}
+
+/// The images `(x^i)^P mod f` of the monomial basis under the Frobenius map, one row per `i`. As
+/// the map is `F_P`-linear this matrix determines it completely.
+pub(crate) const fn frobenius_matrix<const N: usize, const P: usize>(
+  f: &[usize; N],
+) -> [[usize; N]; N] {
+  let x_p = frobenius_of_x::<N, P>(f, 1);
+  let mut matrix = [[0; N]; N];
+  matrix[0] = one::<N>();
+  let mut i = 1;
+  while i < N {
+    matrix[i] = mul::<N, P>(&matrix[i - 1], &x_p, f);
+    i += 1;
+  }
+  matrix
+}
+
+/// `a mod b` for polynomials of degree less than `N`, with `b` non-zero.
+const fn rem<const N: usize, const P: usize>(a: [usize; N], b: &[usize; N]) -> [usize; N] {
+  let deg_b = match degree(b) {
+    Some(d) => d,
+    None => panic!("division by the zero polynomial"),
+  };
+  let lead_inv = inv_mod::<P>(b[deg_b]);
+  let mut a = a;
+  while let Some(deg_a) = degree(&a) {
+    if deg_a < deg_b {
+      break;
+    }
+    let factor = mul_mod::<P>(a[deg_a], lead_inv);
+    let shift = deg_a - deg_b;
+    let mut i = 0;
+    while i <= deg_b {
+      a[i + shift] = sub_mod::<P>(a[i + shift], mul_mod::<P>(factor, b[i]));
+      i += 1;
+    }
+  }
+  a
+}
+
+/// Whether `gcd(f, g) = 1` for the monic modulus `f` of degree `N` and `g` of degree less than `N`.
+const fn is_coprime<const N: usize, const P: usize>(f: &[usize; N], g: &[usize; N]) -> bool {
+  if degree(g).is_none() {
+    return false;
+  }
+
+  // The first step of Euclid's algorithm reduces `f = x^N + f_low` modulo `g`, which is done as
+  // `x * (x^(N-1) mod g) + f_low` since `x^N` does not fit in `N` coefficients.
+  let mut x_pow = [0; N];
+  x_pow[N - 1] = 1;
+  x_pow = rem::<N, P>(x_pow, g);
+  let mut shifted = [0; N];
+  let mut i = N - 1;
+  while i > 0 {
+    shifted[i] = x_pow[i - 1];
+    i -= 1;
+  }
+  let mut f_mod_g = rem::<N, P>(shifted, g);
+  let mut i = 0;
+  while i < N {
+    f_mod_g[i] = add_mod::<P>(f_mod_g[i], f[i]);
+    i += 1;
+  }
+  f_mod_g = rem::<N, P>(f_mod_g, g);
+
+  let mut a = *g;
+  let mut b = f_mod_g;
+  while degree(&b).is_some() {
+    let r = rem::<N, P>(a, &b);
+    a = b;
+    b = r;
+  }
+  matches!(degree(&a), Some(0))
+}
+
+/// Rabin's irreducibility test for the monic polynomial `x^N + f_{N-1} x^{N-1} + ... + f_0`.
+pub const fn is_irreducible<const N: usize, const P: usize>(f: &[usize; N]) -> bool {
+  if N == 1 {
+    return true;
+  }
+
+  // Condition 1: f | x^(P^N) - x
+  let x = mul_by_x::<N, P>(one::<N>(), f);
+  if !equal(&frobenius_of_x::<N, P>(f, N), &x) {
+    return false;
+  }
+
+  // Condition 2: gcd(x^(P^(N/q)) - x, f) = 1 for all primes q | N
+  let mut remaining = N;
+  let mut q = 2;
+  while q <= remaining {
+    if remaining % q == 0 {
+      let mut h = frobenius_of_x::<N, P>(f, N / q);
+      let mut i = 0;
+      while i < N {
+        h[i] = sub_mod::<P>(h[i], x[i]);
+        i += 1;
+      }
+      if !is_coprime::<N, P>(f, &h) {
+        return false;
+      }
+      while remaining % q == 0 {
+        remaining /= q;
+      }
+    }
+    q += 1;
+  }
+  true
+}
+
+/// Finds a monic irreducible polynomial of degree `N` over `F_P`, returned as its `N` lower
+/// coefficients. See the [module documentation](self) for the order in which candidates are tried.
+pub const fn irreducible_polynomial<const N: usize, const P: usize>() -> [usize; N] {
+  // Binomials x^N + a
+  let mut a = 1;
+  while a < P {
+    let mut f = [0; N];
+    f[0] = a;
+    if is_irreducible::<N, P>(&f) {
+      return f;
+    }
+    a += 1;
+  }
+
+  // Trinomials x^N + b x^k + a
+  let mut k = 1;
+  while k < N {
+    let mut b = 1;
+    while b < P {
+      let mut a = 1;
+      while a < P {
+        let mut f = [0; N];
+        f[0] = a;
+        f[k] = b;
+        if is_irreducible::<N, P>(&f) {
+          return f;
+        }
+        a += 1;
+      }
+      b += 1;
+    }
+    k += 1;
+  }
+
+  // Every monic polynomial, counting up in base P
+  let mut f = [0; N];
+  loop {
+    if is_irreducible::<N, P>(&f) {
+      return f;
+    }
+    if !increment::<N, P>(&f) {
+      panic!("no irreducible polynomial found");
+    }
+    f = next::<N, P>(f);
+  }
+}
+
+/// Whether `a` can be incremented as a base `P` number without wrapping around.
+const fn increment<const N: usize, const P: usize>(a: &[usize; N]) -> bool {
+  let mut i = 0;
+  while i < N {
+    if a[i] != P - 1 {
+      return true;
+    }
+    i += 1;
+  }
+  false
+}
+
+/// `a + 1` where `a` is read as a little-endian base `P` number.
+const fn next<const N: usize, const P: usize>(a: [usize; N]) -> [usize; N] {
+  let mut a = a;
+  let mut i = 0;
+  while i < N {
+    if a[i] + 1 < P {
+      a[i] += 1;
+      return a;
+    }
+    a[i] = 0;
+    i += 1;
+  }
+  a
+}
+
+/// The largest trial divisor used to factor `P^N - 1` in [`primitive_element`], which keeps the
+/// factorization within the limits of compile-time evaluation.
+const MAX_TRIAL_DIVISOR: usize = 1 << 16;
+
+/// Finds a generator of the multiplicative group of `F_P[x]/(f)` for an irreducible `f`. Elements
+/// are tried in order of their coefficients read as a base `P` number, and `g` is accepted if
+/// `g^((P^N - 1)/q) != 1` for every prime `q` dividing the group order `P^N - 1`. For `N > 1` the
+/// constants are skipped, as their order divides `P - 1` and they can never generate the group.
+///
+/// ## Panics
+/// If `P^N` does not fit in a `usize`, or if `P^N - 1` has two prime factors larger than `2^16`, as
+/// it is factored by trial division. When evaluated in a constant, this is a compile-time error.
+pub const fn primitive_element<const N: usize, const P: usize>(f: &[usize; N]) -> [usize; N] {
+  let order = match P.checked_pow(N as u32) {
+    Some(order) => order - 1,
+    None => panic!("P^N does not fit in a usize, the primitive element cannot be searched"),
+  };
+
+  // Distinct prime factors of the group order by trial division.
+  let mut factors = [0; usize::BITS as usize];
+  let mut num_factors = 0;
+  let mut remaining = order;
+  let mut d = 2;
+  while d * d <= remaining {
+    if d > MAX_TRIAL_DIVISOR {
+      panic!("P^N - 1 is too large to be factored by trial division at compile time");
+    }
+    if remaining % d == 0 {
+      factors[num_factors] = d;
+      num_factors += 1;
+      while remaining % d == 0 {
+        remaining /= d;
+      }
+    }
+    d += 1;
+  }
+  if remaining > 1 {
+    factors[num_factors] = remaining;
+    num_factors += 1;
+  }
+
+  let one = one::<N>();
+  let mut candidate = if N > 1 { mul_by_x::<N, P>(one, f) } else { one };
+  loop {
+    let mut is_generator = true;
+    let mut i = 0;
+    while i < num_factors {
+      if equal(&pow::<N, P>(&candidate, order / factors[i], f), &one) {
+        is_generator = false;
+        break;
+      }
+      i += 1;
+    }
+    if is_generator {
+      return candidate;
+    }
+    if !increment::<N, P>(&candidate) {
+      panic!("no primitive element found");
+    }
+    candidate = next::<N, P>(candidate);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+
+  #[rstest]
+  #[case([2, 0], true)] // x^2 + 2
+  #[case([1, 0], false)] // x^2 + 1 = (x + 10)(x - 10)
+  #[case([0, 0], false)] // x^2
+  #[case([1, 1], true)] // x^2 + x + 1, since 101 = 2 mod 3
+  #[case([2, 98], false)] // x^2 - 3x + 2 = (x - 1)(x - 2)
+  fn rabin_quadratic(#[case] f: [usize; 2], #[case] expected: bool) {
+    assert_eq!(is_irreducible::<2, 101>(&f), expected);
+  }
+
+  #[rstest]
+  #[case([1, 1, 0, 1, 1, 0, 0, 0], true)] // x^8 + x^4 + x^3 + x + 1
+  #[case([1, 0, 0, 0, 0, 0, 0, 0], false)] // x^8 + 1 = (x + 1)^8
+  #[case([1, 0, 1, 0, 0, 0, 0, 1], false)] // x^8 + x^7 + x^2 + 1 has the root 1
+  #[case([1, 0, 1, 1, 1, 0, 0, 0], true)] // x^8 + x^4 + x^3 + x^2 + 1
+  // x^8 + x^2 + 1 = (x^4 + x + 1)^2
+  #[case([1, 0, 1, 0, 0, 0, 0, 0], false)]
+  // (x^4 + x + 1)(x^4 + x^3 + 1) divides x^256 - x, only the gcd condition detects it
+  #[case([1, 1, 0, 1, 1, 1, 0, 1], false)]
+  fn rabin_aes(#[case] f: [usize; 8], #[case] expected: bool) {
+    assert_eq!(is_irreducible::<8, 2>(&f), expected);
+  }
+
+  #[test]
+  fn sparse_search() {
+    // Binomials
+    assert_eq!(irreducible_polynomial::<2, 101>(), [2, 0]);
+    assert_eq!(irreducible_polynomial::<2, 59>(), [1, 0]);
+    assert_eq!(irreducible_polynomial::<1, 17>(), [1]);
+    // Every element of GF(101) is a cube, so the first candidate is the trinomial x^3 + x + 1.
+    assert_eq!(irreducible_polynomial::<3, 101>(), [1, 1, 0]);
+    // There are no irreducible trinomials of degree 8 over GF(2), the AES polynomial is the
+    // smallest irreducible one.
+    assert_eq!(irreducible_polynomial::<8, 2>(), [1, 1, 0, 1, 1, 0, 0, 0]);
+  }
+
+  #[test]
+  fn generators() {
+    assert_eq!(primitive_element::<2, 101>(&[2, 0]), [1, 1]);
+    assert_eq!(primitive_element::<3, 101>(&[1, 1, 0]), [3, 1, 0]);
+    assert_eq!(primitive_element::<4, 7>(&[1, 1, 0, 0]), [5, 1, 0, 0]);
+    assert_eq!(primitive_element::<8, 2>(&[1, 1, 0, 1, 1, 0, 0, 0]), [1, 1, 0, 0, 0, 0, 0, 0]);
+  }
+
+  #[test]
+  #[should_panic(expected = "too large to be factored")]
+  fn unfactorable_order() {
+    // 9845080643 - 1 = 2 * 70001 * 70321 has two prime factors above the trial division bound
+    primitive_element::<1, 9_845_080_643>(&[0]);
+  }
+
+  #[test]
+  #[should_panic(expected = "does not fit in a usize")]
+  fn order_overflow() { primitive_element::<3, 4_294_967_311>(&[3, 0, 0]); }
+
+  #[test]
+  fn const_evaluation() {
+    const MODULUS: [usize; 4] = irreducible_polynomial::<4, 7>();
+    const GENERATOR: [usize; 4] = primitive_element::<4, 7>(&MODULUS);
+    assert!(is_irreducible::<4, 7>(&MODULUS));
+    assert_eq!(GENERATOR, [5, 1, 0, 0]);
+  }
+}
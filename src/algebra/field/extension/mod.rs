@@ -1,19 +1,25 @@
 //! This module contains the [`ExtensionField`] trait and the [`GaloisField`] struct that represents
 //! elements of an extension field. The extension field is constructed by adjoining the roots of a
-//! polynomial to the original field via the [`ExtensionField::IRREDUCIBLE_POLYNOMIAL_COEFFS`]
-//! array.
+//! polynomial to the original field via the
+//! [`ExtensionField::IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS`] array.
 //!
-//! This is still a work in progress to push towards a more generic implementation as the current
-//! still requires that specific irreducibles are given in order to construct the extension, though
-//! it should be possible to construct the extension at compile time.
+//! The irreducible polynomial and a primitive element are found at compile time by the
+//! [`irreducible`] module, so any `GaloisField<N, P>` can be used without further setup. Towers of
+//! extensions over an extension are built with [`tower::TowerExtension`].
 
 use std::array;
 
 use super::{prime::*, *};
+use crate::polynomial::{Monomial, Polynomial};
 
 mod arithmetic;
+pub mod bls12_381;
+pub mod bn254;
 pub mod gf_101_2;
 pub mod gf_2_8;
+pub mod gf_mersenne31_2;
+pub mod irreducible;
+pub mod tower;
 
 /// The [`PlutoBaseFieldExtension`] is a specific instance of the [`GaloisField`] struct with the
 /// order set to the prime number `101^2`. This is the quadratic extension field over the
@@ -30,6 +36,13 @@ pub type AESFieldExtension = GaloisField<8, 2>;
 /// [`field::prime::PlutoScalarField`] used in the Pluto `ronkathon` system.
 pub type PlutoScalarFieldExtension = GaloisField<2, { PlutoPrime::Scalar as usize }>;
 
+/// The [`Mersenne31Complex`] field is the quadratic extension of the
+/// [`Mersenne31`][crate::field::prime::Mersenne31] field. As `2^31 - 1 = 3 mod 4`, `-1` is not a
+/// square and the field is `F_P[i]/(i^2 + 1)`, the "complex numbers" over `F_P`. Its multiplicative
+/// group has order `P^2 - 1 = 2^32 (2^30 - 1)`, which gives the two-adic roots of unity the base
+/// field lacks.
+pub type Mersenne31Complex = GaloisField<2, 0x7FFF_FFFF>;
+
 /// Sizes of the fields for extensions on the [`PlutoPrime`]s.
 pub enum PlutoExtensions {
   /// The size of the quadratic extension field over the [`PlutoPrime::Base`] field.
@@ -57,6 +70,19 @@ where [PrimeField<P>; N + 1]: {
   const IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS: [PrimeField<P>; N + 1];
 }
 
+/// Extension fields whose generator was fixed before generators could be found at compile time.
+/// The published generator is kept so that the public [`FiniteField::PRIMITIVE_ELEMENT`] does not
+/// change, see [`gf_101_2`] and [`gf_2_8`].
+pub(crate) trait PublishedGenerator<const N: usize> {
+  /// Coefficients of the published generator, or `None` to use the first generator found by
+  /// [`irreducible::primitive_element`].
+  const PUBLISHED_PRIMITIVE_ELEMENT: Option<[usize; N]>;
+}
+
+impl<const N: usize, const P: usize> PublishedGenerator<N> for GaloisField<N, P> {
+  default const PUBLISHED_PRIMITIVE_ELEMENT: Option<[usize; N]> = None;
+}
+
 /// A struct that represents an element of an extension field. The element is represented as
 /// [`Monomial`] coefficients of a [`Polynomial`] of degree `N - 1` over the base [`FiniteField`]
 /// `F`.
@@ -66,91 +92,215 @@ pub struct GaloisField<const N: usize, const P: usize> {
 }
 
 impl<const N: usize, const P: usize> Finite for GaloisField<N, P> {
-  const ORDER: usize = PrimeField::<P>::ORDER.pow(N as u32);
-}
-
-// impl<const N: usize, const P: usize> FiniteField for GaloisField<N, P> {
-//   const GENERATOR: Self = panic!();
-//   const NEG_ONE: Self = Self::new(single_instance_array(PrimeField::<P>::NEG_ONE));
-//   const ONE: Self = Self::new(single_instance_array(PrimeField::<P>::ONE));
-//   const ORDER: usize = P.pow(N as usize) as usize;
-//   const THREE: Self = Self::new(single_instance_array(PrimeField::<P>::THREE));
-//   const TWO: Self = Self::new(single_instance_array(PrimeField::<P>::from(2usize)));
-//   const ZERO: Self = Self::new([PrimeField::<P>::ZERO; N]);
-
-//   fn inverse(&self) -> Option<Self> { todo!() }
-
-//   fn pow(self, power: usize) -> Self { todo!() }
-// }
-
-// TODO: This is useful for generating arrays at const time
-// const fn single_instance_array<const N: usize, const P: usize>(
-//   value: PrimeField<P>,
-// ) -> [PrimeField<P>; N] {
-//   let mut arr = [PrimeField::<P>::ZERO; N];
-//   arr[0] = value;
-//   arr
-// }
-
-// TODO: We could get the irreducible polynomial with const fn
-/// A polynomial is irreducible if `x^(p^q) - x` is divisible by the polynomial.
-// const fn get_irreducible_poly<const N: usize, const P: usize>() -> [PrimeField<P>; N + 1]
-// where [(); P as usize * N + 1]: {
-//   // Make our polynomial x^(p^q) - x
-//   let mut divisible = [PrimeField::<P>::ZERO; P as usize * N + 1];
-//   divisible[1] = PrimeField::<P>::NEG_ONE;
-//   divisible[P as usize * N] = PrimeField::<P>::ONE;
-//   let divisible = Polynomial::<Monomial, PrimeField<P>>::from(divisible);
-
-//   let mut poly = Polynomial::<Monomial, PrimeField<P>>::from([PrimeField::<P>::ZERO; N + 1]);
-
-//   for i in 0..N + 1 {
-//     for j in 0..P as usize * i {
-//       poly.coefficients[j / i] = PrimeField::<P>::new((j % i) as usize);
-//       if divisible.clone() % poly.clone()
-//         == Polynomial::<Monomial, PrimeField<P>>::from([PrimeField::<P>::ZERO; 1])
-//       {
-//         return array::from_fn(|i| {
-//           poly.coefficients.get(i).cloned().unwrap_or(PrimeField::<P>::ZERO)
-//         });
-//       }
-//     }
-//   }
-//   panic!();
-// }
-
-// ADDED THIS IN HERE FOR CONST GENERATOR
-// const fn remainder<const N: usize, const P: u32>(
-//   lhs: [PrimeField<P>; N * P as usize + 1],
-//   rhs: [PrimeField<P>; N],
-// ) -> [PrimeField<P>; N] {
-//   // Initial quotient value
-//   let mut q = [PrimeField::<P>::ZERO; N];
-
-//   // Initial remainder value is our numerator polynomial
-//   let mut p = [PrimeField::<P>::ZERO; N * P as usize + 1];
-
-//   // Leading coefficient of the denominator
-//   let c = rhs.leading_coefficient();
-
-//   // Create quotient polynomial
-//   let mut diff = p.degree() as isize - rhs.degree() as isize;
-//   if diff < 0 {
-//     return (Self::new(vec![F::ZERO]), p);
-//   }
-//   let mut q_coeffs = vec![F::ZERO; diff as usize + 1];
-
-//   // Perform the repeated long division algorithm
-//   while diff >= 0 {
-//     let s = p.leading_coefficient() * c.inverse().unwrap();
-//     q_coeffs[diff as usize] = s;
-//     p -= rhs.pow_mult(s, diff as usize);
-//     p.trim_zeros();
-//     diff = p.degree() as isize - rhs.degree() as isize;
-//   }
-//   q.coefficients = q_coeffs;
-//   (q, p)
-// }
+  const ORDER: usize = match PrimeField::<P>::ORDER.checked_pow(N as u32) {
+    Some(order) => order,
+    None => panic!("the order P^N of the field does not fit in a usize"),
+  };
+}
+
+impl<const N: usize, const P: usize> GaloisField<N, P> {
+  /// Lower coefficients of the monic irreducible polynomial `x^N + ... + f_0` defining the field,
+  /// found at compile time by [`irreducible::irreducible_polynomial`].
+  const MODULUS: [usize; N] = irreducible::irreducible_polynomial::<N, P>();
+  /// The largest `s` such that `2^s` divides `P^N - 1`, see [`PrimeField::TWO_ADICITY`].
+  pub const TWO_ADICITY: usize = (<Self as Finite>::ORDER - 1).trailing_zeros() as usize;
+  /// A primitive `2^TWO_ADICITY`-th root of unity, the [`FiniteField::PRIMITIVE_ELEMENT`] raised to
+  /// the odd part of `P^N - 1`.
+  pub const TWO_ADIC_ROOT_OF_UNITY: Self = Self::from_values(irreducible::pow::<N, P>(
+    &<Self as FiniteField>::PRIMITIVE_ELEMENT.values(),
+    (<Self as Finite>::ORDER - 1) >> Self::TWO_ADICITY,
+    &Self::MODULUS,
+  ));
+
+  /// Coefficients of the field element as canonical representatives in `0..P`.
+  pub(crate) const fn values(&self) -> [usize; N] {
+    let mut values = [0; N];
+    let mut i = 0;
+    while i < N {
+      values[i] = self.coeffs[i].value;
+      i += 1;
+    }
+    values
+  }
+
+  /// Inverse of [`GaloisField::values`], the entries must already be reduced modulo `P`.
+  pub(crate) const fn from_values(values: [usize; N]) -> Self {
+    let mut coeffs = [PrimeField::<P> { value: 0 }; N];
+    let mut i = 0;
+    while i < N {
+      coeffs[i] = PrimeField::<P> { value: values[i] };
+      i += 1;
+    }
+    Self { coeffs }
+  }
+}
+
+/// ## Field-theoretic maps
+///
+/// The Galois group of `GF(P^N)` over `GF(P)` is cyclic of order `N`, generated by the Frobenius
+/// endomorphism `x ↦ x^P`. The maps below are all built from it.
+impl<const N: usize, const P: usize> GaloisField<N, P> {
+  /// Images `(t^i)^P` of the monomial basis under the Frobenius map, see
+  /// [`irreducible::frobenius_matrix`].
+  const FROBENIUS_MATRIX: [[usize; N]; N] = irreducible::frobenius_matrix::<N, P>(&Self::MODULUS);
+
+  /// Applies the Frobenius endomorphism `k` times, i.e. computes `x^(P^k)`.
+  ///
+  /// The map fixes `GF(P)` and is linear over it, so `(Σ c_i t^i)^P = Σ c_i (t^i)^P`. With the
+  /// images `(t^i)^P` computed at compile time this is a matrix-vector product, which is much
+  /// cheaper than exponentiating by `P`. Since `x^(P^N) = x`, only `k mod N` applications are done.
+  pub fn frobenius(&self, k: usize) -> Self {
+    (0..k % N).fold(*self, |x, _| {
+      let mut coeffs = [PrimeField::<P>::ZERO; N];
+      for (&c, row) in x.coeffs.iter().zip(Self::FROBENIUS_MATRIX.iter()) {
+        for (coeff, &entry) in coeffs.iter_mut().zip(row) {
+          *coeff += c * PrimeField::<P> { value: entry };
+        }
+      }
+      Self { coeffs }
+    })
+  }
+
+  /// Returns the Galois conjugates `[x, x^P, x^(P^2), ..., x^(P^(N-1))]` of the element.
+  pub fn conjugates(&self) -> [Self; N] {
+    let mut conjugate = *self;
+    array::from_fn(|_| {
+      let current = conjugate;
+      conjugate = conjugate.frobenius(1);
+      current
+    })
+  }
+
+  /// The norm `N(x) = x · x^P ··· x^(P^(N-1))`, the product of all conjugates. It is fixed by the
+  /// Frobenius map and hence lies in the base field. The norm is multiplicative and `N(x) = 0` only
+  /// for `x = 0`.
+  pub fn norm(&self) -> PrimeField<P> { self.conjugates().into_iter().product::<Self>().coeffs[0] }
+
+  /// The trace `Tr(x) = x + x^P + ... + x^(P^(N-1))`, the sum of all conjugates. It is fixed by the
+  /// Frobenius map and hence lies in the base field. The trace is `GF(P)`-linear.
+  pub fn trace(&self) -> PrimeField<P> { self.conjugates().into_iter().sum::<Self>().coeffs[0] }
+}
+
+impl<const N: usize, const P: usize> GaloisField<N, P>
+where [(); N + 1]:
+{
+  /// The minimal polynomial of the element over `GF(P)`, i.e. the monic polynomial of least degree
+  /// with the element as a root. Its roots are exactly the distinct conjugates, so it is the
+  /// product of `(X - x^(P^i))` over the orbit of `x` under the Frobenius map. The degree divides
+  /// `N` and higher coefficients are padded with zeros.
+  pub fn minimal_polynomial(&self) -> Polynomial<Monomial, PrimeField<P>, { N + 1 }> {
+    let mut coeffs = [Self::ZERO; N + 1];
+    coeffs[0] = Self::ONE;
+    let mut conjugate = *self;
+    loop {
+      // Multiply by (X - conjugate)
+      for i in (0..N + 1).rev() {
+        let shifted = if i > 0 { coeffs[i - 1] } else { Self::ZERO };
+        coeffs[i] = shifted - conjugate * coeffs[i];
+      }
+      conjugate = conjugate.frobenius(1);
+      if conjugate == *self {
+        break;
+      }
+    }
+    Polynomial::<Monomial, _, { N + 1 }>::new(coeffs.map(|c| c.coeffs[0]))
+  }
+}
+
+impl<const N: usize, const P: usize> ExtensionField<N, P> for GaloisField<N, P>
+where [PrimeField<P>; N + 1]:
+{
+  /// The irreducible polynomial found by [`irreducible::irreducible_polynomial`]. Sparse
+  /// polynomials are preferred, so e.g. [`PlutoBaseFieldExtension`] is `F[X]/(X^2 + 2)`.
+  const IRREDUCIBLE_POLYNOMIAL_COEFFICIENTS: [PrimeField<P>; N + 1] = {
+    let mut coeffs = [PrimeField::<P> { value: 1 }; N + 1];
+    let mut i = 0;
+    while i < N {
+      coeffs[i] = PrimeField::<P> { value: Self::MODULUS[i] };
+      i += 1;
+    }
+    coeffs
+  };
+}
+
+impl<const N: usize, const P: usize> Field for GaloisField<N, P> {
+  const ONE: Self = {
+    let mut coeffs = [PrimeField::<P>::ZERO; N];
+    coeffs[0] = PrimeField::<P>::ONE;
+    Self { coeffs }
+  };
+  const ZERO: Self = Self { coeffs: [PrimeField::<P>::ZERO; N] };
+
+  /// Computes the multiplicative inverse by Fermat's little theorem, `a^-1 = a^(P^N - 2)`.
+  fn inverse(&self) -> Option<Self> {
+    if *self == Self::ZERO {
+      return None;
+    }
+
+    Some(self.pow(Self::ORDER - 2))
+  }
+
+  fn pow(self, power: usize) -> Self {
+    let mut result = Self::ONE;
+    let mut base = self;
+    let mut power = power;
+    while power > 0 {
+      if power & 1 == 1 {
+        result *= base;
+      }
+      base *= base;
+      power >>= 1;
+    }
+    result
+  }
+}
+
+impl<const N: usize, const P: usize> FiniteField for GaloisField<N, P> {
+  /// The published generator of the field if there is one, and otherwise the first generator found
+  /// by [`irreducible::primitive_element`], reading coefficients as a base `P` number.
+  const PRIMITIVE_ELEMENT: Self =
+    Self::from_values(match <Self as PublishedGenerator<N>>::PUBLISHED_PRIMITIVE_ELEMENT {
+      Some(generator) => generator,
+      None => irreducible::primitive_element::<N, P>(&Self::MODULUS),
+    });
+}
+
+impl<const N: usize, const P: usize> SquareRoot for GaloisField<N, P> {
+  /// Since `N(x) = x^((P^N - 1)/(P - 1))`, Euler's criterion in `GF(P^N)` is Euler's criterion of
+  /// the norm in `GF(P)`, which is computed with a few Frobenius maps instead of a long
+  /// exponentiation.
+  fn legendre(&self) -> LegendreSymbol { self.norm().legendre() }
+}
+
+impl<const N: usize, const P: usize> FieldEncoding for GaloisField<N, P> {
+  const ENCODED_LEN: usize = N * PrimeField::<P>::ENCODED_LEN;
+
+  /// Concatenates the encodings of the coefficients from the highest degree down.
+  fn to_be_bytes(&self) -> Vec<u8> {
+    self.coeffs.iter().rev().flat_map(|coeff| coeff.to_be_bytes()).collect()
+  }
+
+  fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+    if bytes.len() != Self::ENCODED_LEN {
+      return None;
+    }
+    let mut coeffs = [PrimeField::ZERO; N];
+    for (coeff, chunk) in coeffs.iter_mut().rev().zip(bytes.chunks(PrimeField::<P>::ENCODED_LEN)) {
+      *coeff = PrimeField::from_be_bytes(chunk)?;
+    }
+    Some(Self { coeffs })
+  }
+
+  fn sgn0(&self) -> bool {
+    self.coeffs.iter().find(|&&coeff| coeff != PrimeField::ZERO).is_some_and(|coeff| coeff.sgn0())
+  }
+}
+
+impl<const N: usize, const P: usize> FrobeniusMap for GaloisField<N, P> {
+  fn characteristic() -> Vec<u64> { vec![P as u64] }
+
+  fn frobenius_map(&self, power: usize) -> Self { self.frobenius(power) }
+}
 
 impl<const N: usize, const P: usize> Default for GaloisField<N, P> {
   fn default() -> Self { Self { coeffs: [PrimeField::<P>::ZERO; N] } }
@@ -192,3 +342,116 @@ impl<const N: usize, const P: usize> From<usize> for GaloisField<N, P> {
 impl<const N: usize, const P: usize> From<GaloisField<N, P>> for usize {
   fn from(value: GaloisField<N, P>) -> Self { value.coeffs[0].value }
 }
+
+#[cfg(test)]
+mod tests {
+  use rand::Rng;
+  use rstest::rstest;
+
+  use super::*;
+
+  type GF7_4 = GaloisField<4, 7>;
+
+  fn random<const N: usize, const P: usize>(rng: &mut impl Rng) -> GaloisField<N, P> {
+    GaloisField::new(array::from_fn(|_| PrimeField::new(rng.gen_range(0..P))))
+  }
+
+  /// Evaluates a polynomial over `GF(P)` at an element of the extension.
+  fn evaluate<const N: usize, const P: usize, const D: usize>(
+    poly: &Polynomial<Monomial, PrimeField<P>, D>,
+    x: GaloisField<N, P>,
+  ) -> GaloisField<N, P> {
+    poly.coefficients.iter().rev().fold(GaloisField::ZERO, |acc, &c| acc * x + c)
+  }
+
+  #[test]
+  fn frobenius_is_pth_power() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+      let x = random::<4, 7>(&mut rng);
+      assert_eq!(x.frobenius(0), x);
+      assert_eq!(x.frobenius(1), x.pow(7));
+      assert_eq!(x.frobenius(2), x.pow(49));
+      assert_eq!(x.frobenius(4), x);
+      assert_eq!(x.frobenius(6), x.frobenius(2));
+      assert_eq!(x.frobenius_map(3), x.pow(343));
+
+      let y = random::<4, 7>(&mut rng);
+      assert_eq!((x * y).frobenius(1), x.frobenius(1) * y.frobenius(1));
+      assert_eq!((x + y).frobenius(1), x.frobenius(1) + y.frobenius(1));
+    }
+  }
+
+  #[test]
+  fn norm_and_trace() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+      let (x, y) = (random::<4, 7>(&mut rng), random::<4, 7>(&mut rng));
+      let a = PrimeField::<7>::new(rng.gen_range(0..7));
+      assert_eq!((x * y).norm(), x.norm() * y.norm());
+      assert_eq!((x + y).trace(), x.trace() + y.trace());
+      assert_eq!((x * a).trace(), x.trace() * a);
+      // N(x) = x^((p^n - 1)/(p - 1))
+      assert_eq!(GF7_4::from(x.norm()), x.pow((GF7_4::ORDER - 1) / 6));
+    }
+
+    let a = PrimeField::<7>::new(3);
+    assert_eq!(GF7_4::from(a).norm(), a.pow(4));
+    assert_eq!(GF7_4::from(a).trace(), a * PrimeField::new(4));
+    assert_eq!(GF7_4::ZERO.norm(), PrimeField::ZERO);
+  }
+
+  #[test]
+  fn conjugates() {
+    let mut rng = rand::thread_rng();
+    let x = random::<4, 7>(&mut rng);
+    let conjugates = x.conjugates();
+    for (i, c) in conjugates.iter().enumerate() {
+      assert_eq!(*c, x.pow(7usize.pow(i as u32)));
+    }
+  }
+
+  #[rstest]
+  // t is a root of the defining polynomial x^4 + x + 1
+  #[case(GF7_4::new([0, 1, 0, 0].map(PrimeField::new)), [1, 1, 0, 0, 1])]
+  // elements of the base field have degree 1
+  #[case(GF7_4::from(PrimeField::new(3)), [4, 1, 0, 0, 0])]
+  fn minimal_polynomial(#[case] x: GF7_4, #[case] expected: [usize; 5]) {
+    assert_eq!(x.minimal_polynomial().coefficients, expected.map(PrimeField::new));
+  }
+
+  #[test]
+  fn minimal_polynomial_of_subfield() {
+    // x^((7^4 - 1)/(7^2 - 1)) generates GF(49) inside GF(7^4), so its minimal polynomial is
+    // quadratic.
+    let x = GF7_4::PRIMITIVE_ELEMENT.pow((GF7_4::ORDER - 1) / 48);
+    let poly = x.minimal_polynomial();
+    assert_eq!(poly.degree(), 2);
+    assert_eq!(poly.leading_coefficient(), PrimeField::ONE);
+    assert_eq!(evaluate(&poly, x), GF7_4::ZERO);
+    assert_eq!(evaluate(&poly, x.frobenius(1)), GF7_4::ZERO);
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+      let x = random::<4, 7>(&mut rng);
+      let poly = x.minimal_polynomial();
+      assert_eq!(4 % poly.degree(), 0);
+      assert_eq!(evaluate(&poly, x), GF7_4::ZERO);
+    }
+  }
+
+  #[test]
+  fn batch_inverse_and_powers() {
+    let mut rng = rand::thread_rng();
+    let elements: [GF7_4; 16] = array::from_fn(|_| random(&mut rng));
+    for (x, inverse) in elements.iter().zip(GF7_4::batch_inverse(elements)) {
+      assert_eq!(inverse, x.inverse());
+    }
+
+    let [x, y] = [elements[0], elements[1]];
+    for power in [0, 1, 2, 15, 16, 17, 2399, 2400, 123_456] {
+      assert_eq!(x.pow_sliding_window(power), x.pow(power));
+      assert_eq!(GF7_4::multi_pow([x, y], [power, 3 * power]), x.pow(power) * y.pow(3 * power));
+    }
+  }
+}
@@ -0,0 +1,497 @@
+//! Extension towers built one quadratic or cubic step at a time.
+//!
+//! [`GaloisField`] adjoins a root of an irreducible polynomial directly to a [`PrimeField`], which
+//! is enough for small extensions but cannot express the towers used by pairing-friendly curves,
+//! e.g. `Fp12 = Fp6[w]/(w^2 - v)`, `Fp6 = Fp2[v]/(v^3 - ξ)`, `Fp2 = Fp[u]/(u^2 - β)`.
+//! [`TowerExtension`] is a single such step: an element is `c_0 + c_1 w + ... + c_{N-1} w^{N-1}`
+//! with coefficients in a base field and `w^N = ξ`, where the non-residue `ξ` is provided by a
+//! [`TowerConfig`]. Since the base field can itself be a [`TowerExtension`], steps can be stacked
+//! to any height.
+//!
+//! Every step implements [`FrobeniusMap`]. The powers of the generator are mapped to
+//! `(w^i)^p = ξ^⌊ip/N⌋ w^(ip mod N)`, so with the coefficients `ξ^⌊ip/N⌋` precomputed in the
+//! [`TowerConfig`], the Frobenius map of an element only needs the Frobenius map of the base field
+//! and `N` base field multiplications.
+
+use super::*;
+use crate::{algebra::limbs, Distribution, Rng, Standard};
+
+/// Parameters of a single step `B[w]/(w^N - ξ)` of an extension tower.
+///
+/// `N` must be `2` (quadratic step) or `3` (cubic step), which is checked at compile time, and
+/// [`TowerConfig::NON_RESIDUE`] must be chosen such that `w^N - ξ` is irreducible over the base
+/// field, i.e. `ξ` must not be a square (resp. cube) in `B`.
+pub trait TowerConfig<const N: usize>:
+  'static + Copy + Clone + std::fmt::Debug + Default + PartialEq + Eq + Hash {
+  /// The field that is extended by this step.
+  type BaseField: Finite + FrobeniusMap;
+
+  /// The non-residue `ξ` with `w^N = ξ`.
+  const NON_RESIDUE: Self::BaseField;
+
+  /// The coefficients `ξ^⌊ip/N⌋` of the Frobenius map for `i = 0, ..., N - 1`, where `p` is the
+  /// characteristic, such that `(w^i)^p = ξ^⌊ip/N⌋ w^(ip mod N)`.
+  const FROBENIUS_COEFFS: [Self::BaseField; N];
+}
+
+/// A [`TowerConfig`] for which a multiplicative generator of the extension is known. This is only
+/// needed for [`FiniteField`], so it can be left out for large towers whose group order cannot be
+/// factored.
+pub trait TowerPrimitiveElement<const N: usize>: TowerConfig<N> {
+  /// Coefficients of a generator of the multiplicative group of the extension.
+  const PRIMITIVE_ELEMENT: [Self::BaseField; N];
+}
+
+/// An element `c_0 + c_1 w + ... + c_{N-1} w^{N-1}` of the extension `B[w]/(w^N - ξ)` described by
+/// the [`TowerConfig`] `C`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TowerExtension<C: TowerConfig<N>, const N: usize> {
+  pub(crate) coeffs: [C::BaseField; N],
+}
+
+/// A quadratic step `B[w]/(w^2 - ξ)` of an extension tower.
+pub type QuadraticExtension<C> = TowerExtension<C, 2>;
+
+/// A cubic step `B[w]/(w^3 - ξ)` of an extension tower.
+pub type CubicExtension<C> = TowerExtension<C, 3>;
+
+impl<C: TowerConfig<N>, const N: usize> TowerExtension<C, N> {
+  /// Only quadratic and cubic steps are supported, evaluated once at compile time.
+  const IS_QUADRATIC_OR_CUBIC: () =
+    assert!(N == 2 || N == 3, "only quadratic and cubic tower steps are supported");
+
+  /// Create a new element from its coefficients over the base field in order of increasing degree.
+  pub const fn new(coeffs: [C::BaseField; N]) -> Self {
+    #[allow(clippy::let_unit_value)]
+    let _ = Self::IS_QUADRATIC_OR_CUBIC;
+    Self { coeffs }
+  }
+
+  /// Embeds an element of the base field into the extension.
+  pub fn from_base(value: C::BaseField) -> Self {
+    let mut coeffs = [C::BaseField::ZERO; N];
+    coeffs[0] = value;
+    Self { coeffs }
+  }
+
+  /// Multiplies every coefficient by an element of the base field.
+  pub fn scale(self, scalar: C::BaseField) -> Self {
+    Self { coeffs: self.coeffs.map(|c| c * scalar) }
+  }
+}
+
+impl<C: TowerConfig<2>> TowerExtension<C, 2> {
+  /// Returns the conjugate `c_0 - c_1 w`, the image of the element under the non-trivial
+  /// automorphism fixing the base field.
+  pub fn conjugate(self) -> Self { Self { coeffs: [self.coeffs[0], -self.coeffs[1]] } }
+}
+
+impl<C: TowerConfig<N>, const N: usize> Finite for TowerExtension<C, N> {
+  /// `|B|^N`. Evaluating it is a compile-time error when it does not fit in a `usize`, use
+  /// [`FiniteField::order`] instead.
+  const ORDER: usize = match C::BaseField::ORDER.checked_pow(N as u32) {
+    Some(order) => order,
+    None => panic!("the order does not fit in a usize, use `FiniteField::order`"),
+  };
+}
+
+impl<C: TowerConfig<N>, const N: usize> Field for TowerExtension<C, N> {
+  const ONE: Self = {
+    let mut coeffs = [C::BaseField::ZERO; N];
+    coeffs[0] = C::BaseField::ONE;
+    Self { coeffs }
+  };
+  const ZERO: Self = Self { coeffs: [C::BaseField::ZERO; N] };
+
+  /// Computes the multiplicative inverse as the adjugate divided by the norm down to the base
+  /// field. For `N = 2` this is `(c_0 - c_1 w) / (c_0^2 - ξ c_1^2)`.
+  fn inverse(&self) -> Option<Self> {
+    #[allow(clippy::let_unit_value)]
+    let _ = Self::IS_QUADRATIC_OR_CUBIC;
+    if *self == Self::ZERO {
+      return None;
+    }
+
+    let xi = C::NON_RESIDUE;
+    let mut adjugate = [C::BaseField::ZERO; N];
+    let norm = match N {
+      2 => {
+        let (c0, c1) = (self.coeffs[0], self.coeffs[1]);
+        adjugate[0] = c0;
+        adjugate[1] = -c1;
+        c0 * c0 - xi * c1 * c1
+      },
+      3 => {
+        let (c0, c1, c2) = (self.coeffs[0], self.coeffs[1], self.coeffs[2]);
+        adjugate[0] = c0 * c0 - xi * c1 * c2;
+        adjugate[1] = xi * c2 * c2 - c0 * c1;
+        adjugate[2] = c1 * c1 - c0 * c2;
+        c0 * adjugate[0] + xi * (c2 * adjugate[1] + c1 * adjugate[2])
+      },
+      _ => unreachable!("only quadratic and cubic tower steps are supported"),
+    };
+
+    norm.inverse().map(|norm_inv| Self { coeffs: adjugate }.scale(norm_inv))
+  }
+
+  fn pow(self, power: usize) -> Self {
+    let mut result = Self::ONE;
+    let mut base = self;
+    let mut power = power;
+    while power > 0 {
+      if power & 1 == 1 {
+        result *= base;
+      }
+      base *= base;
+      power >>= 1;
+    }
+    result
+  }
+}
+
+impl<C: TowerPrimitiveElement<N>, const N: usize> FiniteField for TowerExtension<C, N>
+where C::BaseField: FiniteField
+{
+  const PRIMITIVE_ELEMENT: Self = Self { coeffs: C::PRIMITIVE_ELEMENT };
+
+  /// `|B|^N`, computed on limbs as it quickly outgrows [`Finite::ORDER`].
+  fn order() -> Vec<u64> {
+    let base = C::BaseField::order();
+    (1..N).fold(base.clone(), |order, _| limbs::mul_limbs(&order, &base))
+  }
+}
+
+impl<C: TowerPrimitiveElement<N, BaseField: SquareRoot>, const N: usize> SquareRoot
+  for TowerExtension<C, N>
+{
+}
+
+impl<C: TowerConfig<N>, const N: usize> FrobeniusMap for TowerExtension<C, N> {
+  fn characteristic() -> Vec<u64> { C::BaseField::characteristic() }
+
+  fn frobenius_map(&self, power: usize) -> Self {
+    // (w^i)^p = ξ^⌊ip/N⌋ w^(ip mod N), and ip = ir mod N for r = p mod N
+    let (_, r) = limbs::div_rem_small(&Self::characteristic(), N as u64);
+    (0..power).fold(*self, |x, _| {
+      let mut coeffs = [C::BaseField::ZERO; N];
+      for (i, (c, twist)) in x.coeffs.iter().zip(C::FROBENIUS_COEFFS).enumerate() {
+        coeffs[i * r as usize % N] += c.frobenius_map(1) * twist;
+      }
+      Self { coeffs }
+    })
+  }
+}
+
+impl<C: TowerConfig<N>, const N: usize> Default for TowerExtension<C, N> {
+  fn default() -> Self { Self::ZERO }
+}
+
+impl<C: TowerConfig<N>, const N: usize> From<usize> for TowerExtension<C, N> {
+  fn from(value: usize) -> Self { Self::from_base(C::BaseField::from(value)) }
+}
+
+impl<C: TowerConfig<N>, const N: usize> Distribution<TowerExtension<C, N>> for Standard
+where Standard: Distribution<C::BaseField>
+{
+  #[inline]
+  fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TowerExtension<C, N> {
+    TowerExtension { coeffs: std::array::from_fn(|_| rng.gen()) }
+  }
+}
+
+impl<C: TowerConfig<N>, const N: usize> Add for TowerExtension<C, N> {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self {
+    Self { coeffs: std::array::from_fn(|i| self.coeffs[i] + rhs.coeffs[i]) }
+  }
+}
+
+impl<C: TowerConfig<N>, const N: usize> AddAssign for TowerExtension<C, N> {
+  fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+
+impl<C: TowerConfig<N>, const N: usize> Sum for TowerExtension<C, N> {
+  fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.reduce(|x, y| x + y).unwrap_or(Self::ZERO)
+  }
+}
+
+impl<C: TowerConfig<N>, const N: usize> Neg for TowerExtension<C, N> {
+  type Output = Self;
+
+  fn neg(self) -> Self { Self { coeffs: self.coeffs.map(|c| -c) } }
+}
+
+impl<C: TowerConfig<N>, const N: usize> Sub for TowerExtension<C, N> {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self {
+    Self { coeffs: std::array::from_fn(|i| self.coeffs[i] - rhs.coeffs[i]) }
+  }
+}
+
+impl<C: TowerConfig<N>, const N: usize> SubAssign for TowerExtension<C, N> {
+  fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+
+/// Schoolbook multiplication followed by the reduction `w^(N+k) = ξ w^k`.
+impl<C: TowerConfig<N>, const N: usize> Mul for TowerExtension<C, N> {
+  type Output = Self;
+
+  fn mul(self, rhs: Self) -> Self {
+    let mut coeffs = [C::BaseField::ZERO; N];
+    for (i, &a) in self.coeffs.iter().enumerate() {
+      for (j, &b) in rhs.coeffs.iter().enumerate() {
+        if i + j < N {
+          coeffs[i + j] += a * b;
+        } else {
+          coeffs[i + j - N] += C::NON_RESIDUE * a * b;
+        }
+      }
+    }
+    Self { coeffs }
+  }
+}
+
+impl<C: TowerConfig<N>, const N: usize> MulAssign for TowerExtension<C, N> {
+  fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<C: TowerConfig<N>, const N: usize> Product for TowerExtension<C, N> {
+  fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+    iter.reduce(|x, y| x * y).unwrap_or(Self::ONE)
+  }
+}
+
+impl<C: TowerConfig<N>, const N: usize> Div for TowerExtension<C, N> {
+  type Output = Self;
+
+  #[allow(clippy::suspicious_arithmetic_impl)]
+  fn div(self, rhs: Self) -> Self { self * rhs.inverse().expect("invalid inverse") }
+}
+
+impl<C: TowerConfig<N>, const N: usize> DivAssign for TowerExtension<C, N> {
+  fn div_assign(&mut self, rhs: Self) { *self = *self / rhs; }
+}
+
+impl<C: TowerConfig<N>, const N: usize> Rem for TowerExtension<C, N> {
+  type Output = Self;
+
+  fn rem(self, rhs: Self) -> Self { self - (self / rhs) * rhs }
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+
+  /// `Fp2 = GF(101)[u]/(u^2 + 2)`, i.e. the same field as [`PlutoBaseFieldExtension`].
+  #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+  struct Fp2Config;
+  impl TowerConfig<2> for Fp2Config {
+    type BaseField = PlutoBaseField;
+
+    const FROBENIUS_COEFFS: [PlutoBaseField; 2] = [PlutoBaseField::ONE, PlutoBaseField::new(100)];
+    const NON_RESIDUE: PlutoBaseField = PlutoBaseField::new(99);
+  }
+  impl TowerPrimitiveElement<2> for Fp2Config {
+    const PRIMITIVE_ELEMENT: [PlutoBaseField; 2] =
+      [PlutoBaseField::new(14), PlutoBaseField::new(9)];
+  }
+  type Fp2 = QuadraticExtension<Fp2Config>;
+
+  /// `Fp6 = Fp2[v]/(v^3 - (1 + t))` on top of [`PlutoBaseFieldExtension`]. `1 + t` is neither a
+  /// square nor a cube in `GF(101^2)`.
+  #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+  struct Fp6Config;
+  impl TowerConfig<3> for Fp6Config {
+    type BaseField = PlutoBaseFieldExtension;
+
+    const FROBENIUS_COEFFS: [PlutoBaseFieldExtension; 3] = [
+      PlutoBaseFieldExtension::ONE,
+      PlutoBaseFieldExtension::new([PlutoBaseField::new(100), PlutoBaseField::new(38)]),
+      PlutoBaseFieldExtension::new([PlutoBaseField::new(93), PlutoBaseField::new(67)]),
+    ];
+    const NON_RESIDUE: PlutoBaseFieldExtension =
+      PlutoBaseFieldExtension::new([PlutoBaseField::ONE, PlutoBaseField::ONE]);
+  }
+  impl TowerPrimitiveElement<3> for Fp6Config {
+    /// ```sage
+    /// F2.<t> = GF(101^2, modulus=x^2 + 2)
+    /// F6.<v> = F2.extension(x^3 - (1 + t))
+    /// assert (t*v + (2 + 2*t)*v^2).multiplicative_order() == 101^6 - 1
+    /// ```
+    const PRIMITIVE_ELEMENT: [PlutoBaseFieldExtension; 3] = [
+      PlutoBaseFieldExtension::new([PlutoBaseField::ZERO, PlutoBaseField::ZERO]),
+      PlutoBaseFieldExtension::new([PlutoBaseField::ZERO, PlutoBaseField::ONE]),
+      PlutoBaseFieldExtension::new([PlutoBaseField::new(2), PlutoBaseField::new(2)]),
+    ];
+  }
+  type Fp6 = CubicExtension<Fp6Config>;
+
+  /// `Fp12 = Fp6[w]/(w^2 - v)`.
+  #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+  struct Fp12Config;
+  impl TowerConfig<2> for Fp12Config {
+    type BaseField = Fp6;
+
+    const FROBENIUS_COEFFS: [Fp6; 2] = [
+      Fp6::ONE,
+      Fp6::new([
+        PlutoBaseFieldExtension::ZERO,
+        PlutoBaseFieldExtension::ZERO,
+        PlutoBaseFieldExtension::new([PlutoBaseField::new(77), PlutoBaseField::new(86)]),
+      ]),
+    ];
+    const NON_RESIDUE: Fp6 = Fp6::new([
+      PlutoBaseFieldExtension::ZERO,
+      PlutoBaseFieldExtension::ONE,
+      PlutoBaseFieldExtension::ZERO,
+    ]);
+  }
+  type Fp12 = QuadraticExtension<Fp12Config>;
+
+  fn random_fp2(rng: &mut impl Rng) -> PlutoBaseFieldExtension {
+    PlutoBaseFieldExtension::new([rng.gen(), rng.gen()])
+  }
+
+  fn random_fp6(rng: &mut impl Rng) -> Fp6 {
+    Fp6::new([random_fp2(rng), random_fp2(rng), random_fp2(rng)])
+  }
+
+  fn random_fp12(rng: &mut impl Rng) -> Fp12 { Fp12::new([random_fp6(rng), random_fp6(rng)]) }
+
+  fn field_axioms<F: Field>(x: F, y: F, z: F) {
+    assert_eq!(x + (-x), F::ZERO);
+    assert_eq!(x * (y * z), (x * y) * z);
+    assert_eq!(x * y, y * x);
+    assert_eq!(x * (y + z), x * y + x * z);
+    assert_eq!(x - (y + z), (x - y) - z);
+    assert_eq!(x.pow(5), x * x * x * x * x);
+    if x != F::ZERO {
+      assert_eq!(x * x.inverse().unwrap(), F::ONE);
+      assert_eq!(y / x * x, y);
+    }
+    assert_eq!(F::ZERO.inverse(), None);
+  }
+
+  #[test]
+  fn arithmetic() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+      let [x, y, z] = std::array::from_fn(|_| Fp2::new(random_fp2(&mut rng).coeffs));
+      field_axioms(x, y, z);
+      field_axioms(random_fp6(&mut rng), random_fp6(&mut rng), random_fp6(&mut rng));
+      field_axioms(random_fp12(&mut rng), random_fp12(&mut rng), random_fp12(&mut rng));
+    }
+  }
+
+  #[test]
+  fn quadratic_step_matches_galois_field() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+      let (a, b) = (random_fp2(&mut rng), random_fp2(&mut rng));
+      let (x, y) = (Fp2::new(a.coeffs), Fp2::new(b.coeffs));
+      assert_eq!((x * y).coeffs, (a * b).coeffs);
+      if a != PlutoBaseFieldExtension::ZERO {
+        assert_eq!(x.inverse().unwrap().coeffs, a.inverse().unwrap().coeffs);
+      }
+    }
+  }
+
+  #[test]
+  fn generators() {
+    let w = Fp12::new([Fp6::ZERO, Fp6::ONE]);
+    let v = Fp6::new([
+      PlutoBaseFieldExtension::ZERO,
+      PlutoBaseFieldExtension::ONE,
+      PlutoBaseFieldExtension::ZERO,
+    ]);
+    assert_eq!(w * w, Fp12::from_base(v));
+    assert_eq!(v * v * v, Fp6::from_base(Fp6Config::NON_RESIDUE));
+  }
+
+  #[test]
+  fn square_roots() {
+    let mut rng = rand::thread_rng();
+    assert_eq!(Fp6::order(), vec![101u64.pow(6)]);
+    assert_eq!(Fp2::PRIMITIVE_ELEMENT.legendre(), LegendreSymbol::QuadraticNonResidue);
+    assert_eq!(Fp6::PRIMITIVE_ELEMENT.legendre(), LegendreSymbol::QuadraticNonResidue);
+    assert_eq!(Fp6::PRIMITIVE_ELEMENT.square_root(), None);
+    for _ in 0..20 {
+      let x = Fp2::new(random_fp2(&mut rng).coeffs);
+      let root = (x * x).square_root().unwrap();
+      assert!(root == x || root == -x);
+      assert_eq!((x * x * Fp2::PRIMITIVE_ELEMENT).is_square(), x == Fp2::ZERO);
+
+      let x = random_fp6(&mut rng);
+      let root = (x * x).square_root().unwrap();
+      assert!(root == x || root == -x);
+      // an odd degree extension does not add square roots of base field elements
+      let y = random_fp2(&mut rng);
+      assert_eq!(Fp6::from_base(y).is_square(), y.is_square());
+    }
+  }
+
+  #[test]
+  fn order() {
+    assert_eq!(Fp2::ORDER, 101 * 101);
+    assert_eq!(Fp6::ORDER, 101usize.pow(6));
+    assert_eq!(Fp2::order(), vec![101 * 101]);
+  }
+
+  #[rstest]
+  #[case(2 * 2 * 2)]
+  #[case(3 * 3)]
+  #[case(5 * 5)]
+  #[case(7)]
+  #[case(13)]
+  #[case(17)]
+  #[case(37)]
+  #[case(10303)]
+  fn primitive_element(#[case] factor: usize) {
+    let order = Fp6::ORDER - 1;
+    assert_eq!(Fp6::PRIMITIVE_ELEMENT.pow(order), Fp6::ONE);
+    // `factor` runs over the prime powers of `101^6 - 1`.
+    let prime = (2..=factor).find(|d| factor % d == 0).unwrap();
+    assert_ne!(Fp6::PRIMITIVE_ELEMENT.pow(order / prime), Fp6::ONE);
+    assert_eq!(Fp6::primitive_root_of_unity(factor).pow(factor), Fp6::ONE);
+  }
+
+  #[test]
+  fn frobenius_is_pth_power() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..10 {
+      let x = Fp2::new(random_fp2(&mut rng).coeffs);
+      assert_eq!(x.frobenius_map(1), x.pow(101));
+      assert_eq!(x.frobenius_map(1), x.conjugate());
+
+      let x = random_fp6(&mut rng);
+      assert_eq!(x.frobenius_map(1), x.pow(101));
+      assert_eq!(x.frobenius_map(2), x.pow(101 * 101));
+
+      let x = random_fp12(&mut rng);
+      assert_eq!(x.frobenius_map(1), x.pow(101));
+      assert_eq!(x.frobenius_map(3), x.pow(101 * 101 * 101));
+    }
+  }
+
+  #[test]
+  fn frobenius_automorphism() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..10 {
+      let (x, y) = (random_fp12(&mut rng), random_fp12(&mut rng));
+      assert_eq!((x * y).frobenius_map(5), x.frobenius_map(5) * y.frobenius_map(5));
+      assert_eq!((x + y).frobenius_map(5), x.frobenius_map(5) + y.frobenius_map(5));
+      assert_eq!(x.frobenius_map(12), x);
+      // `w^(p^6) = -w`, so the sixth power of the Frobenius map is the conjugation over `Fp6`.
+      assert_eq!(x.frobenius_map(6), x.conjugate());
+
+      let z = random_fp6(&mut rng);
+      assert_eq!(z.frobenius_map(6), z);
+      assert_eq!(Fp12::from_base(z).frobenius_map(6), Fp12::from_base(z));
+    }
+  }
+}
@@ -1,15 +1,23 @@
 //! This module contains the definition of groups, finite fields, and their extension fields.
 #![doc = include_str!("./README.md")]
+pub mod batch;
+pub mod big_prime;
 pub mod binary_towers;
+pub mod encoding;
 pub mod extension;
 pub mod prime;
+pub mod sqrt;
 use std::{
   hash::Hash,
   iter::{Product, Sum},
   ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
 };
 
-use super::Finite;
+pub use batch::BatchArithmetic;
+pub use encoding::FieldEncoding;
+pub use sqrt::{LegendreSymbol, SquareRoot};
+
+use super::{limbs, Finite};
 
 /// A field is a set of elements on which addition, subtraction, multiplication, and division are
 /// defined.
@@ -46,8 +54,21 @@ pub trait Field:
   /// Gets the multiplicative inverse of the field element (if it exists).
   fn inverse(&self) -> Option<Self>;
 
-  /// Computes the power of the field element.
-  fn pow(self, power: usize) -> Self;
+  /// Computes the power of the field element by square-and-multiply. The provided methods of this
+  /// `#[const_trait]` are checked as const functions, hence the `while` loop.
+  fn pow(self, power: usize) -> Self {
+    let mut result = Self::ONE;
+    let mut base = self;
+    let mut exp = power;
+    while exp > 0 {
+      if exp & 1 == 1 {
+        result *= base;
+      }
+      base *= base;
+      exp >>= 1;
+    }
+    result
+  }
 }
 
 /// fields with a finite number of elements.
@@ -55,6 +76,11 @@ pub trait FiniteField: Finite + Field {
   /// Returns a multiplicative generator of the field.
   const PRIMITIVE_ELEMENT: Self;
 
+  /// The order `q` of the field as little-endian 64-bit limbs. Implementors only have to override
+  /// this when [`Finite::ORDER`] does not fit in a `usize`, generic code should always use this
+  /// instead of [`Finite::ORDER`].
+  fn order() -> Vec<u64> { vec![Self::ORDER as u64] }
+
   /// Returns the primitive n-th root of unity in the field.
   ///
   /// ## Notes
@@ -68,9 +94,50 @@ pub trait FiniteField: Finite + Field {
   /// The primitive n-th root of unity 'w' is defined as: w = a^((p - 1) / n),
   /// and the roots of unity are generated by 'w', such that {w^i | i in [0, n - 1]}.
   fn primitive_root_of_unity(n: usize) -> Self {
-    let p_minus_one = Self::ORDER - 1;
-    assert!(p_minus_one % n == 0, "n must divide p^q - 1");
-    let pow = p_minus_one / n;
-    Self::PRIMITIVE_ELEMENT.pow(pow)
+    let (pow, rem) = limbs::div_rem_small(&limbs::sub_small(&Self::order(), 1), n as u64);
+    assert!(rem == 0, "n must divide p^q - 1");
+    pow_limbs(Self::PRIMITIVE_ELEMENT, &pow)
   }
 }
+
+/// A field of characteristic `p` equipped with its
+/// [Frobenius endomorphism](https://en.wikipedia.org/wiki/Frobenius_endomorphism) `x ↦ x^p`.
+///
+/// The map fixes the prime subfield and permutes the roots of any polynomial over it, which is what
+/// makes it cheap to evaluate in extension towers: each level only needs to know how `x ↦ x^p` acts
+/// on its own generator, see [`TowerExtension`][extension::tower::TowerExtension].
+pub trait FrobeniusMap: Field {
+  /// The characteristic `p` of the field as little-endian 64-bit limbs.
+  fn characteristic() -> Vec<u64>;
+
+  /// Applies the Frobenius endomorphism `power` times, i.e. computes `x^(p^power)`.
+  fn frobenius_map(&self, power: usize) -> Self;
+}
+
+/// Square-and-multiply with an exponent given as little-endian 64-bit limbs, for exponents that do
+/// not fit in the `usize` taken by [`Field::pow`].
+pub(crate) fn pow_limbs<F: Field>(base: F, exponent: &[u64]) -> F {
+  let bit = |i: usize| (exponent[i / 64] >> (i % 64)) & 1 == 1;
+  (0..exponent.len() * 64).rev().skip_while(|&i| !bit(i)).fold(F::ONE, |acc, i| {
+    let acc = acc * acc;
+    if bit(i) {
+      acc * base
+    } else {
+      acc
+    }
+  })
+}
+
+/// Fields of prime order `p`, whose elements are the integers in `[0, p)`, converted to and from
+/// little-endian 64-bit limbs. This is how integers too large for a `usize` are passed around, e.g.
+/// the scalars of [`scalar_mul`](crate::curve::scalar_mul).
+pub trait LimbRepr: FiniteField {
+  /// The modulus `p` as little-endian 64-bit limbs.
+  fn modulus() -> Vec<u64>;
+
+  /// Returns the canonical integer of the element as little-endian 64-bit limbs.
+  fn to_limbs(&self) -> Vec<u64>;
+
+  /// Reduces an integer given as little-endian 64-bit limbs modulo `p`.
+  fn from_limbs(limbs: &[u64]) -> Self;
+}
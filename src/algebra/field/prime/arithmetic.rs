@@ -3,7 +3,10 @@ use super::*;
 impl<const P: usize> Add for PrimeField<P> {
   type Output = Self;
 
-  fn add(self, rhs: Self) -> Self { Self { value: (self.value + rhs.value) % Self::ORDER } }
+  fn add(self, rhs: Self) -> Self {
+    let (sum, over) = self.value.overflowing_add(rhs.value);
+    Self { value: if over || sum >= P { sum.wrapping_sub(P) } else { sum } }
+  }
 }
 
 impl<const P: usize> AddAssign for PrimeField<P> {
@@ -34,7 +37,7 @@ impl<const P: usize> SubAssign for PrimeField<P> {
 impl<const P: usize> Mul for PrimeField<P> {
   type Output = Self;
 
-  fn mul(self, rhs: Self) -> Self { Self { value: (self.value * rhs.value) % Self::ORDER } }
+  fn mul(self, rhs: Self) -> Self { Self::reduce(self.value as u128 * rhs.value as u128) }
 }
 
 impl<const P: usize> MulAssign for PrimeField<P> {
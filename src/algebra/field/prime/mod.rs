@@ -34,6 +34,31 @@ pub type PlutoScalarField = PrimeField<{ PlutoPrime::Scalar as usize }>;
 /// [`AES`][crate::encryption::symmetric::aes]
 pub type AESField = PrimeField<2>;
 
+/// `2^64 - 2^32 + 1`, see [`Goldilocks`].
+const GOLDILOCKS: usize = 0xFFFF_FFFF_0000_0001;
+
+/// `15 * 2^27 + 1`, see [`BabyBear`].
+const BABY_BEAR: usize = 0x7800_0001;
+
+/// `2^31 - 1`, see [`Mersenne31`].
+const MERSENNE_31: usize = 0x7FFF_FFFF;
+
+/// The [`Goldilocks`] field has order `2^64 - 2^32 + 1`. Its elements fit in a single machine word,
+/// `P - 1` is divisible by `2^32` which allows for large FFTs, and products reduce without a
+/// division using `2^64 = 2^32 - 1 mod P` and `2^96 = -1 mod P`.
+pub type Goldilocks = PrimeField<GOLDILOCKS>;
+
+/// The [`BabyBear`] field has order `15 * 2^27 + 1`. Elements fit in 31 bits, so that sums of two
+/// elements fit in a `u32`, while `P - 1` is still divisible by `2^27`.
+pub type BabyBear = PrimeField<BABY_BEAR>;
+
+/// The [`Mersenne31`] field has order `2^31 - 1`. Reducing modulo a Mersenne prime only takes a
+/// shift and an addition, but `P - 1 = 2 * 3^2 * 7 * 11 * 31 * 151 * 331` has no large power of two
+/// factor. FFTs are done over the complex extension
+/// [`Mersenne31Complex`][crate::algebra::field::extension::Mersenne31Complex] instead, whose group
+/// order `P^2 - 1` is divisible by `2^32`.
+pub type Mersenne31 = PrimeField<MERSENNE_31>;
+
 /// The [`PrimeField`] struct represents elements of a field with prime order. The field is defined
 /// by a prime number `P`, and the elements are integers modulo `P`.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default, PartialOrd)]
@@ -42,14 +67,54 @@ pub struct PrimeField<const P: usize> {
 }
 
 impl<const P: usize> PrimeField<P> {
+  /// Whether `P` is prime, evaluated once at compile time.
+  const IS_PRIME: bool = is_prime(P);
+  /// The largest `s` such that `2^s` divides `P - 1`, i.e. the largest power of two for which the
+  /// field has roots of unity.
+  pub const TWO_ADICITY: usize = (P - 1).trailing_zeros() as usize;
+  /// A primitive `2^TWO_ADICITY`-th root of unity `g^((P - 1) / 2^TWO_ADICITY)`, where `g` is the
+  /// [`FiniteField::PRIMITIVE_ELEMENT`]. Squaring it `TWO_ADICITY - k` times gives a primitive
+  /// `2^k`-th root of unity.
+  pub const TWO_ADIC_ROOT_OF_UNITY: Self =
+    <Self as FiniteField>::PRIMITIVE_ELEMENT.pow((P - 1) >> Self::TWO_ADICITY);
+
   /// Creates a new element of the [`PrimeField`] and will automatically compute the modulus and
   /// return a congruent element between 0 and `P`. Given the `const fn is_prime`, a program that
   /// tries to compile for a non-prime `P` will fail at compile time.
   pub const fn new(value: usize) -> Self {
-    is_prime(P);
+    assert!(Self::IS_PRIME, "input is not a prime number");
     Self { value: value % P }
   }
 
+  /// Reduces the widened product of two elements modulo `P`. Any `P < 2^64` works by dividing the
+  /// `u128`, but the special shape of the [`Goldilocks`] and [`Mersenne31`] primes allows to reduce
+  /// with shifts and additions only.
+  const fn reduce(x: u128) -> Self {
+    let value = if P == GOLDILOCKS {
+      // x = lo + 2^64 (hi_lo + 2^32 hi_hi) = lo + (2^32 - 1) hi_lo - hi_hi
+      const EPSILON: u64 = (1 << 32) - 1;
+      let (lo, hi) = (x as u64, (x >> 64) as u64);
+      let (hi_hi, hi_lo) = (hi >> 32, hi & EPSILON);
+      let (mut t, borrow) = lo.overflowing_sub(hi_hi);
+      if borrow {
+        // wrapped around by 2^64 = 2^32 - 1
+        t = t.wrapping_sub(EPSILON);
+      }
+      let (mut t, carry) = t.overflowing_add(hi_lo * EPSILON);
+      if carry {
+        t = t.wrapping_add(EPSILON);
+      }
+      t as usize
+    } else if P == MERSENNE_31 {
+      // 2^31 = 1, so the bits above the 31st are added back in
+      let x = (x & MERSENNE_31 as u128) + (x >> 31);
+      ((x & MERSENNE_31 as u128) + (x >> 31)) as usize
+    } else {
+      (x % P as u128) as usize
+    };
+    Self { value: if value >= P { value - P } else { value } }
+  }
+
   /// Computes euler criterion of the field element, i.e. Returns true if the element is a quadratic
   /// residue (a square number) in the field.
   ///
@@ -78,59 +143,17 @@ impl<const P: usize> PrimeField<P> {
   /// More info here: https://www.youtube.com/watch?v=2IBPOI43jek
   pub fn euler_criterion(&self) -> bool { self.pow((P - 1) / 2).value == 1 }
 
-  /// Computes the square root of a field element using the [Tonelli-Shanks algorithm](https://en.wikipedia.org/wiki/Tonelli–Shanks_algorithm).
+  /// Computes the square roots of a field element and returns them as `(r, -r)` where `r` is the
+  /// smaller one. See [`SquareRoot`] for the algorithms used.
+  ///
+  /// ## Panics
+  /// If the element is not a quadratic residue.
   pub fn sqrt(&self) -> Option<(Self, Self)> {
-    if *self == Self::ZERO {
-      return Some((Self::ZERO, Self::ZERO));
-    }
-
-    assert!(self.euler_criterion(), "Element is not a quadratic residue");
-
-    // First define the Q and S values for the prime number P.
-    let q: usize;
-    let mut s = 1;
-    loop {
-      let lhs = P - 1;
-      let rhs = 2_usize.pow(s);
-      let check_value = lhs % rhs;
-      if check_value == 0 {
-        s += 1;
-      } else {
-        s -= 1;
-        q = (P - 1) / 2_usize.pow(s);
-        break;
-      }
-    }
-
-    // Find a z that is not a quadratic residue
-    let mut z = Self::new(2);
-    while z.euler_criterion() {
-      z += Self::ONE;
-    }
-    let mut m = s;
-    let mut c = z.pow(q);
-    let mut t = self.pow(q);
-    let mut r = self.pow((q + 1) / 2);
-    loop {
-      if t == Self::ONE {
-        if -r < r {
-          return Some((-r, r));
-        } else {
-          return Some((r, -r));
-        }
-      }
-      // Repeatedly square to find a t^2^i = 1
-      let mut i = 1;
-      let mut t_pow = t.pow(2);
-      while t_pow != Self::ONE {
-        t_pow = t_pow.pow(2);
-        i += 1;
-      }
-      let b = c.pow(2_usize.pow(m - i - 1));
-      m = i;
-      c = b.pow(2);
-      t *= c;
-      r *= b;
+    let root = self.square_root().expect("Element is not a quadratic residue");
+    if -root < root {
+      Some((-root, root))
+    } else {
+      Some((root, -root))
     }
   }
 }
@@ -156,31 +179,120 @@ impl<const P: usize> const Field for PrimeField<P> {
   }
 
   fn pow(self, power: usize) -> Self {
-    if power == 0 {
-      Self::ONE
-    } else if power == 1 {
-      self
-    } else if power % 2 == 0 {
-      Self::new((self.pow(power / 2).value * self.pow(power / 2).value) % Self::ORDER)
-    } else {
-      Self::new((self.pow(power / 2).value * self.pow(power / 2).value * self.value) % Self::ORDER)
+    let mut result = Self::ONE;
+    let mut base = self;
+    let mut power = power;
+    while power > 0 {
+      if power & 1 == 1 {
+        result = Self::reduce(result.value as u128 * base.value as u128);
+      }
+      base = Self::reduce(base.value as u128 * base.value as u128);
+      power >>= 1;
     }
+    result
   }
 }
 
+impl<const P: usize> FrobeniusMap for PrimeField<P> {
+  fn characteristic() -> Vec<u64> { vec![P as u64] }
+
+  /// By Fermat's little theorem `x^p = x`, so the Frobenius map is the identity on a prime field.
+  fn frobenius_map(&self, _power: usize) -> Self { *self }
+}
+
 impl<const P: usize> FiniteField for PrimeField<P> {
   const PRIMITIVE_ELEMENT: Self =
     if P == 2 { Self::ONE } else { Self::new(find_primitive_element::<P>()) };
 }
 
-const fn is_prime(n: usize) {
-  let mut i = 2;
-  while i * i <= n {
-    if n % i == 0 {
-      panic!("input is not a prime number");
+impl<const P: usize> SquareRoot for PrimeField<P> {}
+
+impl<const P: usize> FieldEncoding for PrimeField<P> {
+  const ENCODED_LEN: usize = (usize::BITS - P.leading_zeros()).div_ceil(8) as usize;
+
+  fn to_be_bytes(&self) -> Vec<u8> {
+    self.value.to_be_bytes()[size_of::<usize>() - Self::ENCODED_LEN..].to_vec()
+  }
+
+  fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+    if bytes.len() != Self::ENCODED_LEN {
+      return None;
+    }
+    let value = bytes.iter().fold(0, |acc, &byte| (acc << 8) | byte as usize);
+    (value < P).then_some(Self { value })
+  }
+
+  fn sgn0(&self) -> bool { self.value & 1 == 1 }
+}
+
+impl<const P: usize> LimbRepr for PrimeField<P> {
+  fn modulus() -> Vec<u64> { vec![P as u64] }
+
+  fn to_limbs(&self) -> Vec<u64> { vec![self.value as u64] }
+
+  fn from_limbs(limbs: &[u64]) -> Self {
+    let value = limbs
+      .iter()
+      .rev()
+      .fold(0, |acc, &limb| (((acc as u128) << 64 | limb as u128) % P as u128) as usize);
+    Self { value }
+  }
+}
+
+/// Deterministic [Miller-Rabin test](https://en.wikipedia.org/wiki/Miller–Rabin_primality_test).
+/// Writing `n - 1 = d * 2^s` with `d` odd, a prime `n` satisfies either `a^d = 1` or
+/// `a^(d * 2^r) = -1` for some `r < s`, for every base `a`. Checking the first twelve primes as
+/// bases is enough to rule out every composite `n < 2^64`.
+const fn is_prime(n: usize) -> bool {
+  const BASES: [usize; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+  let mut i = 0;
+  while i < BASES.len() {
+    if n == BASES[i] {
+      return true;
+    }
+    if n % BASES[i] == 0 {
+      return false;
     }
     i += 1;
   }
+  if n < 2 {
+    return false;
+  }
+
+  let s = (n - 1).trailing_zeros();
+  let d = (n - 1) >> s;
+  let mut i = 0;
+  'bases: while i < BASES.len() {
+    // x = a^d mod n
+    let (mut x, mut base, mut exp) = (1, BASES[i], d);
+    while exp > 0 {
+      if exp & 1 == 1 {
+        x = mul_mod(x, base, n);
+      }
+      base = mul_mod(base, base, n);
+      exp >>= 1;
+    }
+    i += 1;
+
+    if x == 1 || x == n - 1 {
+      continue;
+    }
+    let mut r = 1;
+    while r < s {
+      x = mul_mod(x, x, n);
+      if x == n - 1 {
+        continue 'bases;
+      }
+      r += 1;
+    }
+    return false;
+  }
+  true
+}
+
+/// Computes `a * b mod n` without overflowing.
+const fn mul_mod(a: usize, b: usize, n: usize) -> usize {
+  ((a as u128 * b as u128) % n as u128) as usize
 }
 
 /// This function takes in a prime number `P` and returns a multiplicative generator of the
@@ -188,24 +300,42 @@ const fn is_prime(n: usize) {
 /// of the field. The generator is found by iterating through the numbers from 2 to `P - 1` and
 /// checking if the number is a generator of the field.
 /// A primitive element `g` of a field `F` is an element such that the powers of `g` generate all
-/// the non-zero elements of the field, so we check here that `g` raised to the power of `(P-1)/g`
-/// where this division is integer division, is not equal to 1. In other words, we are checking that
-/// `g` is coprime to `P-1`. This follows from [Lagrange's theorem](https://en.wikipedia.org/wiki/Lagrange%27s_theorem_(group_theory)).
+/// the non-zero elements of the field. By [Lagrange's theorem](https://en.wikipedia.org/wiki/Lagrange%27s_theorem_(group_theory))
+/// the order of `g` divides `P-1`, so `g` is a generator exactly when `g^((P-1)/q) != 1` for every
+/// prime `q` dividing `P-1`.
 pub const fn find_primitive_element<const P: usize>() -> usize {
-  let mut i = 2;
-  while i * i <= P {
-    if (P - 1) % i == 0 {
-      if PrimeField::<P>::new(i).pow((P - 1) / i).value != PrimeField::<P>::ONE.value {
-        return i;
-      } else if PrimeField::<P>::new(P + 1 - i).pow(i).value != PrimeField::<P>::ONE.value {
-        return P + 1 - i;
-      }
+  let mut g = 2;
+  while g < P {
+    if is_primitive_element::<P>(g) {
+      return g;
     }
-    i += 1;
+    g += 1;
   }
   panic!("generator not found");
 }
 
+/// Checks `g^((P-1)/q) != 1` for the prime factors `q` of `P-1`, found by trial division.
+const fn is_primitive_element<const P: usize>(g: usize) -> bool {
+  let mut n = P - 1;
+  let mut q = 2;
+  while n > 1 {
+    if q * q > n {
+      // what is left of `n` is prime
+      q = n;
+    }
+    if n % q == 0 {
+      if PrimeField::<P>::new(g).pow((P - 1) / q).value == PrimeField::<P>::ONE.value {
+        return false;
+      }
+      while n % q == 0 {
+        n /= q;
+      }
+    }
+    q += 1;
+  }
+  true
+}
+
 impl<const P: usize> fmt::Display for PrimeField<P> {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "{}", self.value) }
 }
@@ -213,13 +343,7 @@ impl<const P: usize> fmt::Display for PrimeField<P> {
 impl<const P: usize> Distribution<PrimeField<P>> for Standard {
   #[inline]
   fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PrimeField<P> {
-    loop {
-      let next_u31 = (rng.next_u32() >> 4) as usize;
-      let is_canonical = next_u31 < PrimeField::<P>::ORDER;
-      if is_canonical {
-        return PrimeField::<P> { value: next_u31 };
-      }
-    }
+    PrimeField::<P> { value: rng.gen_range(0..P) }
   }
 }
 
@@ -405,4 +529,105 @@ mod tests {
   fn square_root(#[case] a: PlutoBaseField, #[case] expected: (PlutoBaseField, PlutoBaseField)) {
     assert_eq!(a.sqrt().unwrap(), expected);
   }
+
+  #[test]
+  fn sliding_window_pow() {
+    for x in (0..101).map(PlutoBaseField::new) {
+      for power in 0..300 {
+        assert_eq!(x.pow_sliding_window(power), x.pow(power));
+      }
+      if x != PlutoBaseField::ZERO {
+        // x^100 = 1 by Fermat's little theorem
+        assert_eq!(x.pow_sliding_window(usize::MAX), x.pow(usize::MAX % 100));
+      }
+    }
+  }
+
+  #[test]
+  fn multi_pow() {
+    let bases = [2, 3, 5, 7].map(PlutoBaseField::new);
+    assert_eq!(PlutoBaseField::multi_pow(bases, [0; 4]), PlutoBaseField::ONE);
+    assert_eq!(PlutoBaseField::multi_pow(bases, [1, 0, 0, 0]), PlutoBaseField::new(2));
+    let exponents = [13, 200, 1, 77];
+    let expected = bases.iter().zip(exponents).map(|(b, e)| b.pow(e)).product::<PlutoBaseField>();
+    assert_eq!(PlutoBaseField::multi_pow(bases, exponents), expected);
+  }
+
+  #[test]
+  fn batch_inverse() {
+    let elements: [PlutoBaseField; 100] = std::array::from_fn(|i| PlutoBaseField::new(i + 1));
+    let inverses = PlutoBaseField::batch_inverse(elements);
+    for (x, inverse) in elements.iter().zip(inverses) {
+      assert_eq!(*x * inverse.unwrap(), PlutoBaseField::ONE);
+    }
+
+    let elements = [3, 0, 7].map(PlutoBaseField::new);
+    assert_eq!(PlutoBaseField::batch_inverse(elements), elements.map(|x| x.inverse()));
+    assert_eq!(PlutoBaseField::batch_inverse([]), []);
+  }
+
+  fn widened_arithmetic_check<const P: usize>() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..1000 {
+      let (x, y) = (rng.gen::<PrimeField<P>>(), rng.gen::<PrimeField<P>>());
+      let (a, b, p) = (x.value as u128, y.value as u128, P as u128);
+      assert_eq!((x * y).value as u128, a * b % p);
+      assert_eq!((x + y).value as u128, (a + b) % p);
+      assert_eq!((x - y).value as u128, (a + p - b) % p);
+    }
+    let max = PrimeField::<P>::new(P - 1);
+    assert_eq!(max * max, PrimeField::<P>::ONE);
+    assert_eq!(max + max, PrimeField::<P>::new(P - 2));
+    assert_eq!(max.inverse().unwrap(), max);
+  }
+
+  #[test]
+  fn widened_arithmetic() {
+    widened_arithmetic_check::<GOLDILOCKS>();
+    widened_arithmetic_check::<BABY_BEAR>();
+    widened_arithmetic_check::<MERSENNE_31>();
+    // a prime above `2^32` without special form takes the generic reduction
+    widened_arithmetic_check::<0xFFFF_FFFF_FFFF_FFC5>();
+  }
+
+  #[test]
+  fn is_prime_check() {
+    assert!(is_prime(2) && is_prime(101) && is_prime(GOLDILOCKS) && is_prime(MERSENNE_31));
+    assert!(!is_prime(1) && !is_prime(100) && !is_prime(561));
+    // the largest prime below `2^64`
+    assert!(is_prime(0xFFFF_FFFF_FFFF_FFC5));
+    // strong pseudoprimes to the bases up to 7 and up to 23 respectively
+    assert!(!is_prime(3_215_031_751));
+    assert!(!is_prime(3_825_123_056_546_413_051));
+  }
+
+  fn two_adic_root_check<const P: usize>(generator: usize, two_adicity: usize, root: usize) {
+    assert_eq!(PrimeField::<P>::PRIMITIVE_ELEMENT, PrimeField::<P>::new(generator));
+    assert_eq!(PrimeField::<P>::TWO_ADICITY, two_adicity);
+    let omega = PrimeField::<P>::TWO_ADIC_ROOT_OF_UNITY;
+    assert_eq!(omega, PrimeField::<P>::new(root));
+    assert_eq!(omega.pow(1 << two_adicity), PrimeField::<P>::ONE);
+    assert_eq!(omega.pow(1 << (two_adicity - 1)), -PrimeField::<P>::ONE);
+  }
+
+  #[test]
+  fn two_adic_roots_of_unity() {
+    two_adic_root_check::<GOLDILOCKS>(7, 32, 1_753_635_133_440_165_772);
+    two_adic_root_check::<BABY_BEAR>(31, 27, 440_564_289);
+    two_adic_root_check::<MERSENNE_31>(7, 1, MERSENNE_31 - 1);
+  }
+
+  #[test]
+  fn large_square_roots() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+      let x = rng.gen::<Goldilocks>();
+      let root = (x * x).square_root().unwrap();
+      assert!(root == x || root == -x);
+      let x = rng.gen::<BabyBear>();
+      assert_eq!((x * x).square_root().unwrap().pow(2), x * x);
+      let x = rng.gen::<Mersenne31>();
+      assert_eq!((x * x).square_root().unwrap().pow(2), x * x);
+    }
+  }
 }
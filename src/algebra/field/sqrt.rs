@@ -0,0 +1,307 @@
+//! Square roots and the quadratic character in finite fields.
+//!
+//! In a finite field $\mathbb{F}_q$ of odd order exactly half of the nonzero elements are squares:
+//! $\mathbb{F}_q^*$ is cyclic of even order $q - 1$, and the squares are the even powers of a
+//! generator. Euler's criterion tells them apart, $x^{(q-1)/2} = 1$ for squares and $-1$ otherwise.
+//! In characteristic two the Frobenius map $x \mapsto x^2$ is a bijection, so every element has
+//! exactly one square root.
+//!
+//! [`SquareRoot::square_root`] picks the cheapest algorithm for the shape of $q$:
+//! - $q$ even: $\sqrt{x} = x^{q/2}$, the inverse of the Frobenius map.
+//! - $q \equiv 3 \pmod 4$: $\sqrt{x} = x^{(q+1)/4}$.
+//! - $q \equiv 5 \pmod 8$: [Atkin's algorithm](atkin), a single exponentiation using that $2$ is a
+//!   non-residue.
+//! - $q \equiv 1 \pmod 8$: [Tonelli–Shanks](tonelli_shanks), or [Cipolla](cipolla) when $q - 1$ is
+//!   divisible by a large power of two.
+
+use super::*;
+use crate::algebra::limbs::{add_small, bit_length, shr, sub_small, trailing_zeros};
+
+/// The quadratic character of a field element, written $\left(\frac{x}{q}\right)$ after Legendre.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LegendreSymbol {
+  /// The element is zero.
+  Zero,
+  /// The element is a nonzero square.
+  QuadraticResidue,
+  /// The element is not a square.
+  QuadraticNonResidue,
+}
+
+/// Finite fields in which square roots can be computed.
+///
+/// Everything is provided from the order [`FiniteField::order`] of the field.
+pub trait SquareRoot: FiniteField {
+  /// Computes the quadratic character of the element with Euler's criterion
+  /// $x^{(q-1)/2} = \pm 1$.
+  fn legendre(&self) -> LegendreSymbol {
+    let q = Self::order();
+    if *self == Self::ZERO {
+      LegendreSymbol::Zero
+    } else if q[0] & 1 == 0 || pow_limbs(*self, &shr(&sub_small(&q, 1), 1)) == Self::ONE {
+      LegendreSymbol::QuadraticResidue
+    } else {
+      LegendreSymbol::QuadraticNonResidue
+    }
+  }
+
+  /// Returns true if the element has a square root in the field.
+  fn is_square(&self) -> bool { self.legendre() != LegendreSymbol::QuadraticNonResidue }
+
+  /// Computes a square root of the element, or `None` if it is not a square. The other root is the
+  /// negation of the returned one.
+  fn square_root(&self) -> Option<Self> {
+    if *self == Self::ZERO {
+      return Some(Self::ZERO);
+    }
+    let q = Self::order();
+    match q[0] & 7 {
+      0 | 2 | 4 | 6 => Some(pow_limbs(*self, &shr(&q, 1))),
+      3 | 7 => {
+        let root = pow_limbs(*self, &shr(&add_small(&q, 1), 2));
+        (root * root == *self).then_some(root)
+      },
+      5 => atkin(self),
+      _ => {
+        // Tonelli–Shanks needs about s^2/4 multiplications on top of an exponentiation, while
+        // Cipolla needs a constant number of base field multiplications per exponent bit.
+        let s = trailing_zeros(&sub_small(&q, 1));
+        if s * s > 8 * bit_length(&q) {
+          cipolla(self)
+        } else {
+          tonelli_shanks(self)
+        }
+      },
+    }
+  }
+}
+
+/// Computes a square root with the
+/// [Tonelli–Shanks algorithm](https://en.wikipedia.org/wiki/Tonelli–Shanks_algorithm), which works
+/// in any field of odd order.
+///
+/// Writing $q - 1 = Q \cdot 2^s$ with $Q$ odd, $r = x^{(Q+1)/2}$ is a root of $x$ up to the factor
+/// $t = x^Q$, which lies in the subgroup of order $2^s$. That subgroup is generated by $c = z^Q$
+/// for any non-residue $z$, e.g. the primitive element, so $t$ is cancelled one bit at a time by
+/// multiplying $r$ with powers of $c$.
+pub fn tonelli_shanks<F: SquareRoot>(x: &F) -> Option<F> {
+  if *x == F::ZERO {
+    return Some(F::ZERO);
+  }
+  let q_minus_one = sub_small(&F::order(), 1);
+  assert!(q_minus_one[0] & 1 == 0, "Tonelli-Shanks needs a field of odd order");
+  let s = trailing_zeros(&q_minus_one);
+  let q = shr(&q_minus_one, s);
+
+  let mut m = s;
+  let mut c = pow_limbs(F::PRIMITIVE_ELEMENT, &q);
+  let mut t = pow_limbs(*x, &q);
+  let mut r = pow_limbs(*x, &shr(&add_small(&q, 1), 1));
+  while t != F::ONE {
+    // Find the least `i` such that t^(2^i) = 1
+    let mut i = 0;
+    let mut t_pow = t;
+    while t_pow != F::ONE {
+      t_pow *= t_pow;
+      i += 1;
+    }
+    // `t` has order 2^m only if `x` is not a square
+    if i == m {
+      return None;
+    }
+    let mut b = c;
+    for _ in 0..m - i - 1 {
+      b *= b;
+    }
+    m = i;
+    c = b * b;
+    t *= c;
+    r *= b;
+  }
+  Some(r)
+}
+
+/// Computes a square root with [Cipolla's algorithm](https://en.wikipedia.org/wiki/Cipolla's_algorithm),
+/// which works in any field of odd order.
+///
+/// Find `a` such that $d = a^2 - x$ is not a square and adjoin $\omega = \sqrt{d}$. In
+/// $\mathbb{F}_{q^2} = \mathbb{F}_q(\omega)$ the Frobenius map sends $\omega$ to $-\omega$, so
+/// $(a + \omega)^{q+1} = (a + \omega)(a - \omega) = x$ and $(a + \omega)^{(q+1)/2}$ is a root of
+/// $x$. Unlike [`tonelli_shanks`] the cost does not depend on the power of two dividing $q - 1$.
+pub fn cipolla<F: SquareRoot>(x: &F) -> Option<F> {
+  if *x == F::ZERO {
+    return Some(F::ZERO);
+  }
+  if x.legendre() == LegendreSymbol::QuadraticNonResidue {
+    return None;
+  }
+  // Walk through the powers of the primitive element rather than `0, 1, 2, ...`: when `x` lies in
+  // a subfield, every `i^2 - x` with `i` in the prime field may be a square. About half of all
+  // candidates are non-residues, so this ends quickly.
+  let (a, d) =
+    std::iter::successors(Some(F::PRIMITIVE_ELEMENT), |a| Some(*a * F::PRIMITIVE_ELEMENT))
+      .map(|a| (a, a * a - *x))
+      .find(|(_, d)| d.legendre() == LegendreSymbol::QuadraticNonResidue)
+      .expect("half of the candidates are non-residues");
+
+  // Arithmetic in F[ω]/(ω^2 - d) on pairs `(u, v) = u + vω`
+  let mul = |(u0, v0): (F, F), (u1, v1): (F, F)| (u0 * u1 + d * v0 * v1, u0 * v1 + v0 * u1);
+  let exponent = shr(&add_small(&F::order(), 1), 1);
+  let (root, _) = exponent.iter().rev().fold((F::ONE, F::ZERO), |acc, &limb| {
+    (0..64).rev().fold(acc, |acc, bit| {
+      let acc = mul(acc, acc);
+      if (limb >> bit) & 1 == 1 {
+        mul(acc, (a, F::ONE))
+      } else {
+        acc
+      }
+    })
+  });
+  Some(root)
+}
+
+/// Computes a square root with Atkin's algorithm for fields of order $q \equiv 5 \pmod 8$, as used
+/// for Ed25519 point decompression.
+///
+/// For such `q` the element $2$ is not a square, so with $b = (2x)^{(q-5)/8}$ the element
+/// $i = 2xb^2$ satisfies $i^2 = (2x)^{(q-1)/2} = -1$ for a square $x$, and
+/// $(xb(i - 1))^2 = x^2 b^2 (-2i) = x$.
+pub fn atkin<F: SquareRoot>(x: &F) -> Option<F> {
+  let q = F::order();
+  assert_eq!(q[0] & 7, 5, "Atkin's algorithm needs q = 5 mod 8");
+  let two_x = *x + *x;
+  let b = pow_limbs(two_x, &shr(&sub_small(&q, 5), 3));
+  let i = two_x * b * b;
+  let root = *x * b * (i - F::ONE);
+  (root * root == *x).then_some(root)
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto_bigint::{impl_modulus, U64};
+  use rand::Rng;
+
+  use super::*;
+  use crate::algebra::field::{
+    big_prime::{BigPrimeField, BigPrimeParams, Bn254ScalarField},
+    extension::GaloisField,
+    prime::PrimeField,
+  };
+
+  /// Checks every element of a small field: exactly the squares have roots, and the roots square
+  /// back to the element.
+  fn check_exhaustive<F: SquareRoot>(
+    elements: impl Iterator<Item = F>,
+    algorithms: &[fn(&F) -> Option<F>],
+  ) {
+    let mut squares = 0;
+    for x in elements {
+      let expected = x.is_square();
+      squares += expected as usize;
+      for algorithm in algorithms.iter().chain([&(F::square_root as fn(&F) -> Option<F>)]) {
+        match algorithm(&x) {
+          Some(root) => assert_eq!(root * root, x),
+          None => assert!(!expected, "{x:?} is a square"),
+        }
+      }
+    }
+    let q = F::order()[0] as usize;
+    assert_eq!(squares, if q % 2 == 0 { q } else { (q + 1) / 2 });
+  }
+
+  fn prime_field<const P: usize>() -> impl Iterator<Item = PrimeField<P>> {
+    (0..P).map(PrimeField::new)
+  }
+
+  fn galois_field<const N: usize, const P: usize>() -> impl Iterator<Item = GaloisField<N, P>> {
+    (0..P.pow(N as u32)).map(|mut n| {
+      GaloisField::new(std::array::from_fn(|_| {
+        let coeff = PrimeField::new(n % P);
+        n /= P;
+        coeff
+      }))
+    })
+  }
+
+  #[test]
+  fn prime_fields() {
+    // q = 3 mod 4
+    check_exhaustive(prime_field::<11>(), &[tonelli_shanks, cipolla]);
+    check_exhaustive(prime_field::<59>(), &[tonelli_shanks, cipolla]);
+    // q = 5 mod 8
+    check_exhaustive(prime_field::<101>(), &[tonelli_shanks, cipolla, atkin]);
+    // q = 1 mod 8
+    check_exhaustive(prime_field::<17>(), &[tonelli_shanks, cipolla]);
+    check_exhaustive(prime_field::<97>(), &[tonelli_shanks, cipolla]);
+    check_exhaustive(prime_field::<2>(), &[]);
+  }
+
+  #[test]
+  fn large_two_adicity() {
+    // 2^64 - 2^32 + 1 has two-adicity 32, which is where Cipolla is chosen over Tonelli-Shanks
+    impl_modulus!(Goldilocks, U64, "ffffffff00000001");
+    impl BigPrimeParams<{ U64::LIMBS }> for Goldilocks {
+      const PRIMITIVE_ELEMENT: U64 = U64::from_u8(7);
+    }
+    type F = BigPrimeField<Goldilocks, { U64::LIMBS }>;
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+      let x = rng.gen::<F>();
+      let root = (x * x).square_root().unwrap();
+      assert!(root == x || root == -x);
+      assert_eq!(tonelli_shanks(&(x * x)).map(|r| r * r), Some(x * x));
+      assert_eq!((x * x * F::PRIMITIVE_ELEMENT).square_root(), None);
+    }
+  }
+
+  #[test]
+  fn big_prime_field() {
+    let mut rng = rand::thread_rng();
+    let g = Bn254ScalarField::PRIMITIVE_ELEMENT;
+    assert_eq!(g.legendre(), LegendreSymbol::QuadraticNonResidue);
+    for _ in 0..20 {
+      let x = rng.gen::<Bn254ScalarField>();
+      let root = (x * x).square_root().unwrap();
+      assert!(root == x || root == -x);
+      assert_eq!(cipolla(&(x * x)).map(|r| r * r), Some(x * x));
+      assert_eq!((x * x * g).square_root(), None);
+    }
+  }
+
+  #[test]
+  fn galois_fields() {
+    // q = 3 mod 4
+    check_exhaustive(galois_field::<3, 3>(), &[tonelli_shanks, cipolla]);
+    // q = 5 mod 8
+    check_exhaustive(galois_field::<3, 5>(), &[tonelli_shanks, cipolla, atkin]);
+    // q = 1 mod 8, like the Pluto extension field with 101^2 - 1 = 2^3 * 1275
+    check_exhaustive(galois_field::<2, 11>(), &[tonelli_shanks, cipolla]);
+    check_exhaustive(galois_field::<4, 3>(), &[tonelli_shanks, cipolla]);
+    for x in galois_field::<2, 101>().step_by(51) {
+      let root = (x * x).square_root().unwrap();
+      assert!(root == x || root == -x);
+      assert_eq!(cipolla(&(x * x)).map(|r| r * r), Some(x * x));
+    }
+    // q even
+    check_exhaustive(galois_field::<8, 2>(), &[]);
+  }
+
+  #[test]
+  fn legendre_is_multiplicative() {
+    type F = GaloisField<4, 7>;
+    let g = F::PRIMITIVE_ELEMENT;
+    assert_eq!(g.legendre(), LegendreSymbol::QuadraticNonResidue);
+    assert_eq!(F::ZERO.legendre(), LegendreSymbol::Zero);
+    for (i, x) in (0..20).map(|i| (i, g.pow(i))) {
+      let expected = if i % 2 == 0 {
+        LegendreSymbol::QuadraticResidue
+      } else {
+        LegendreSymbol::QuadraticNonResidue
+      };
+      assert_eq!(x.legendre(), expected);
+      // the character of an element of F_7 inside F_7^4 is always trivial
+      assert!(F::from(i).is_square());
+    }
+  }
+}
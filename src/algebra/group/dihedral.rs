@@ -0,0 +1,225 @@
+//! The [dihedral group](https://en.wikipedia.org/wiki/Dihedral_group) $D_N$ of the `2N` symmetries
+//! of a regular `N`-gon with vertices `0..N`.
+//!
+//! Every element is $r^k s^f$: the reflection `s` fixing vertex `0` if $f = 1$, followed by the
+//! rotation `r` by `k` vertices. It maps vertex `i` to $k + (-1)^f i \bmod N$. The group is
+//! generated by `r` and `s` with $r^N = s^2 = 1$ and $s r s = r^{-1}$, so composing
+//! $$
+//! (r^a s^f)(r^b s^g) = r^{a + (-1)^f b} s^{f + g}.
+//! $$
+//! Like [`SymmetricGroup`], `a·b` applies `b` first, so [`DihedralGroup::to_permutation`] is an
+//! injective homomorphism into $S_N$ for `N >= 3`, and elements are parsed from and displayed in
+//! its cycle notation.
+use std::{fmt::Display, str::FromStr};
+
+use super::{
+  symmetric::{PermutationError, SymmetricGroup},
+  *,
+};
+use crate::algebra::number_theory::gcd;
+
+/// An element $r^k s^f$ of the dihedral group $D_N$.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DihedralGroup<const N: usize> {
+  rotation:   usize,
+  reflection: bool,
+}
+
+impl<const N: usize> DihedralGroup<N> {
+  #[allow(dead_code)]
+  const IS_POLYGON: () = assert!(N >= 3, "a polygon has at least 3 vertices");
+  /// The reflection `s` fixing vertex `0`.
+  pub const REFLECTION: Self = Self { rotation: 0, reflection: true };
+  /// The rotation `r` by one vertex, of order `N`.
+  pub const ROTATION: Self = Self { rotation: 1 % N, reflection: false };
+
+  /// Creates $r^k s^f$ with `k = rotation` and `f = reflection`.
+  pub const fn new(rotation: usize, reflection: bool) -> Self {
+    #[allow(clippy::let_unit_value)]
+    let _ = Self::IS_POLYGON;
+    Self { rotation: rotation % N, reflection }
+  }
+
+  /// Returns `k` of $r^k s^f$.
+  pub const fn rotation(&self) -> usize { self.rotation }
+
+  /// Returns whether the element is a reflection, i.e. `f = 1`.
+  pub const fn is_reflection(&self) -> bool { self.reflection }
+
+  /// Returns the image of vertex `point`.
+  pub const fn apply(&self, point: usize) -> usize {
+    match self.reflection {
+      false => (self.rotation + point) % N,
+      true => (self.rotation + N - point % N) % N,
+    }
+  }
+
+  /// Returns the permutation of the vertices.
+  pub fn to_permutation(&self) -> SymmetricGroup<N> {
+    SymmetricGroup::new(std::array::from_fn(|i| self.apply(i)))
+  }
+
+  /// Returns the sign of the permutation of the vertices.
+  pub fn sign(&self) -> i8 { self.to_permutation().sign() }
+
+  /// Returns all `2N` elements, the rotations first.
+  pub fn elements() -> impl Iterator<Item = Self> {
+    [false, true].into_iter().flat_map(|f| (0..N).map(move |k| Self::new(k, f)))
+  }
+
+  /// Returns the conjugacy classes of $D_N$: the pairs of opposite rotations $\{r^k, r^{-k}\}$, and
+  /// either all reflections for odd `N` or the two classes of reflections through vertices and
+  /// through edges for even `N`.
+  pub fn conjugacy_classes() -> Vec<Vec<Self>> {
+    conjugacy_classes(&Self::elements().collect::<Vec<_>>())
+  }
+}
+
+impl<const N: usize> TryFrom<SymmetricGroup<N>> for DihedralGroup<N> {
+  type Error = PermutationError;
+
+  /// Recovers the symmetry from the images of vertices `0` and `1`, failing if the permutation is
+  /// not a symmetry of the polygon.
+  fn try_from(permutation: SymmetricGroup<N>) -> Result<Self, Self::Error> {
+    let k = permutation.apply(0);
+    let reflection = permutation.apply(1) != (k + 1) % N;
+    let element = Self::new(k, reflection);
+    match element.to_permutation() == permutation {
+      true => Ok(element),
+      false => Err(PermutationError::NotDihedral(permutation.to_string())),
+    }
+  }
+}
+
+impl<const N: usize> Finite for DihedralGroup<N> {
+  const ORDER: usize = 2 * N;
+}
+
+impl<const N: usize> Group for DihedralGroup<N> {
+  type Scalar = usize;
+
+  const IDENTITY: Self = Self { rotation: 0, reflection: false };
+
+  fn op(&self, rhs: &Self) -> Self {
+    let rotation = match self.reflection {
+      false => self.rotation + rhs.rotation,
+      true => self.rotation + N - rhs.rotation,
+    };
+    Self { rotation: rotation % N, reflection: self.reflection ^ rhs.reflection }
+  }
+
+  fn inverse(&self) -> Option<Self> {
+    match self.reflection {
+      // reflections are involutions
+      true => Some(*self),
+      false => Some(Self { rotation: (N - self.rotation) % N, reflection: false }),
+    }
+  }
+
+  fn scalar_mul(&self, scalar: Self::Scalar) -> Self {
+    match self.reflection {
+      true => [Self::IDENTITY, *self][scalar % 2],
+      false => Self { rotation: (self.rotation * (scalar % N)) % N, reflection: false },
+    }
+  }
+}
+
+impl<const N: usize> FiniteGroup for DihedralGroup<N> {
+  fn order(&self) -> usize {
+    match self.reflection {
+      true => 2,
+      false => N / gcd(self.rotation as u64, N as u64) as usize,
+    }
+  }
+}
+
+impl_group_ops!([const N: usize] DihedralGroup<N>);
+
+impl<const N: usize> Display for DihedralGroup<N> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.to_permutation())
+  }
+}
+
+impl<const N: usize> FromStr for DihedralGroup<N> {
+  type Err = PermutationError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::try_from(s.parse::<SymmetricGroup<N>>()?)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+
+  type D4 = DihedralGroup<4>;
+
+  #[test]
+  fn group_properties() {
+    let r = D4::ROTATION;
+    let s = D4::REFLECTION;
+
+    assert_eq!(r * 4, D4::IDENTITY);
+    assert_eq!(s * 2, D4::IDENTITY);
+    // s r s = r^-1
+    assert_eq!(s + r + s, -r);
+    assert_ne!(r + s, s + r);
+    assert_eq!(generate_subgroup(&[r, s]).len(), D4::ORDER);
+    assert_eq!(D4::elements().count(), D4::ORDER);
+
+    for a in D4::elements() {
+      assert_eq!(a + -a, D4::IDENTITY);
+      assert_eq!(a * a.order(), D4::IDENTITY);
+      for b in D4::elements() {
+        assert_eq!((a + b).to_permutation(), a.to_permutation() + b.to_permutation());
+      }
+    }
+  }
+
+  #[test]
+  fn cycle_notation() {
+    let r = D4::ROTATION;
+    let s = D4::REFLECTION;
+    assert_eq!(r.to_string(), "(0 1 2 3)");
+    assert_eq!(s.to_string(), "(1 3)");
+    assert_eq!((r + s).to_string(), "(0 1)(2 3)");
+    assert_eq!("(0 2)(1 3)".parse::<D4>(), Ok(r * 2));
+    assert_eq!("(0 2)".parse::<D4>(), Ok(r + r + s));
+    assert_eq!("(0 1)".parse::<D4>(), Err(PermutationError::NotDihedral("(0 1)".to_string())));
+    for a in D4::elements() {
+      assert_eq!(a.to_string().parse::<D4>(), Ok(a));
+    }
+  }
+
+  #[test]
+  fn sign_and_order() {
+    let r = DihedralGroup::<6>::ROTATION;
+    assert_eq!(r.order(), 6);
+    assert_eq!((r * 2).order(), 3);
+    assert_eq!((r * 3).order(), 2);
+    assert_eq!(DihedralGroup::<6>::REFLECTION.order(), 2);
+    // a rotation of an even polygon is an N-cycle
+    assert_eq!(r.sign(), -1);
+    assert_eq!(DihedralGroup::<5>::ROTATION.sign(), 1);
+  }
+
+  #[rstest]
+  #[case(DihedralGroup::<3>::conjugacy_classes().len(), 3)]
+  #[case(DihedralGroup::<4>::conjugacy_classes().len(), 5)]
+  #[case(DihedralGroup::<5>::conjugacy_classes().len(), 4)]
+  #[case(DihedralGroup::<6>::conjugacy_classes().len(), 6)]
+  fn conjugacy(#[case] classes: usize, #[case] expected: usize) {
+    assert_eq!(classes, expected);
+  }
+
+  #[test]
+  fn symmetric_group_of_triangle() {
+    // every permutation of the 3 vertices of a triangle is a symmetry
+    for permutation in SymmetricGroup::<3>::elements() {
+      assert_eq!(DihedralGroup::<3>::try_from(permutation).unwrap().to_permutation(), permutation);
+    }
+  }
+}
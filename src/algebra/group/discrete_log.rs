@@ -0,0 +1,326 @@
+//! Generic attacks on the discrete logarithm problem in a [`FiniteCyclicGroup`]: given `base` and
+//! `target = base^x`, find `x`.
+//!
+//! The group is written multiplicatively, `a·b` being [`Group::op`], so on an elliptic curve
+//! `base^x` is the scalar multiple `[x]base`. None of the algorithms look at the representation of
+//! the elements, which is why the best generic attacks need about $\sqrt{n}$ group operations in a
+//! group of order `n`, and why the order of a group used in cryptography must have a large prime
+//! factor:
+//! - [`baby_step_giant_step`]: deterministic, $O(\sqrt{n})$ time and memory.
+//! - [`pollard_rho`]: randomized, $O(\sqrt{n})$ expected time but only as much memory as there are
+//!   distinguished points.
+//! - [`pohlig_hellman`]: reduces the problem to the prime power subgroups, so its cost depends on
+//!   the largest prime factor of `n` instead of `n`.
+use std::{
+  collections::HashMap,
+  hash::{DefaultHasher, Hash, Hasher},
+};
+
+use rand::Rng;
+
+use super::*;
+use crate::algebra::number_theory::{crt, extended_gcd, factorize, isqrt};
+
+/// Counters collected while solving a discrete logarithm.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DiscreteLogStats {
+  /// Number of group operations, including those spent on inverses and exponentiations.
+  pub group_operations: usize,
+  /// Largest number of group elements stored at once, i.e. baby steps or distinguished points.
+  pub stored_elements:  usize,
+  /// Number of random walks started by [`pollard_rho`].
+  pub walks:            usize,
+}
+
+/// A solution `x` to `base^x = target`, along with the work it took to find it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiscreteLog {
+  /// The exponent `x`, in `0..G::ORDER`.
+  pub exponent: usize,
+  /// Counters collected while solving.
+  pub stats:    DiscreteLogStats,
+}
+
+/// Solves `base^x = target` with Shanks'
+/// [baby-step giant-step](https://en.wikipedia.org/wiki/Baby-step_giant-step) algorithm.
+///
+/// Writing `x = i·m + j` with $m = \lceil \sqrt{n} \rceil$, the baby steps `base^j` for `j < m` are
+/// stored in a table, and the giant steps `target·base^{-i·m}` are looked up in it until one
+/// matches. Returns the smallest such `x`, or `None` if `target` is not a power of `base`.
+pub fn baby_step_giant_step<G: FiniteCyclicGroup + Hash>(
+  base: G,
+  target: G,
+) -> Option<DiscreteLog> {
+  let mut stats = DiscreteLogStats::default();
+  let exponent = bsgs(base, target, G::ORDER, &mut stats)?;
+  Some(DiscreteLog { exponent, stats })
+}
+
+/// Solves `base^x = target` with [Pollard's rho](https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm_for_logarithms)
+/// using distinguished points, as in the parallel collision search of van Oorschot and Wiener.
+///
+/// Walks start from random `base^a·target^b` and take pseudo-random steps, multiplying by one of
+/// 16 fixed `base^{a_j}·target^{b_j}` picked from a hash of the current element. A walk stops at
+/// the first distinguished point, whose hash has its low bits zero, and only those are stored.
+/// Once two walks reach the same point with different exponents,
+/// `a + x·b = a' + x·b' (mod n)` is solved for `x`.
+///
+/// `base` should generate the group, e.g. any element but the identity in a group of prime order.
+/// Returns `None` if no solution is found after about $100\sqrt{n}$ steps of the walks.
+pub fn pollard_rho<G: FiniteCyclicGroup + Hash, R: Rng + ?Sized>(
+  base: G,
+  target: G,
+  rng: &mut R,
+) -> Option<DiscreteLog> {
+  let n = G::ORDER;
+  let mut stats = DiscreteLogStats::default();
+
+  // expected walks of 2^bits steps, so about n^(1/4) distinguished points are stored
+  let bits = (usize::BITS - n.leading_zeros()) / 4;
+  let max_walk_length = 20 << bits;
+  let max_steps = 100 * (isqrt(n as u64) as usize + 1) + (64 << bits);
+  let mut total_steps = 0;
+
+  let mut random_point = |stats: &mut DiscreteLogStats| {
+    let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+    let point = power(base, a, stats).op(&power(target, b, stats));
+    stats.group_operations += 1;
+    (point, a, b)
+  };
+  let mut steps: Vec<(G, usize, usize)> = (0..16).map(|_| random_point(&mut stats)).collect();
+
+  let mut distinguished: HashMap<G, (usize, usize)> = HashMap::new();
+  while total_steps < max_steps {
+    stats.walks += 1;
+    let (mut point, mut a, mut b) = random_point(&mut stats);
+    let mut length = 0;
+    loop {
+      if length == max_walk_length {
+        // stuck in a cycle without distinguished points, the stored points stay valid for new steps
+        steps = (0..16).map(|_| random_point(&mut stats)).collect();
+        break;
+      }
+      length += 1;
+      total_steps += 1;
+
+      let hash = hash(&point);
+      if hash >> 4 & ((1 << bits) - 1) == 0 {
+        match distinguished.get(&point) {
+          Some(&(a2, b2)) if b2 != b => {
+            // a + x b = a2 + x b2, so (b - b2) x = a2 - a
+            let lhs = (b + n - b2) % n;
+            let rhs = (a2 + n - a) % n;
+            if let Some(exponent) = solve_linear(base, target, lhs, rhs, n, &mut stats) {
+              return Some(DiscreteLog { exponent, stats });
+            }
+          },
+          Some(_) => {},
+          None => {
+            distinguished.insert(point, (a, b));
+            stats.stored_elements = distinguished.len();
+          },
+        }
+        break;
+      }
+
+      let (step, a_j, b_j) = steps[(hash & 15) as usize];
+      point = point.op(&step);
+      a = (a + a_j) % n;
+      b = (b + b_j) % n;
+      stats.group_operations += 1;
+    }
+  }
+  None
+}
+
+/// Solves `base^x = target` with the
+/// [Pohlig–Hellman](https://en.wikipedia.org/wiki/Pohlig%E2%80%93Hellman_algorithm) algorithm.
+///
+/// For each prime power $q^e$ dividing the group order `n`, raising both sides to $n / q^e$ moves
+/// the problem to the subgroup of order $q^e$, where `x mod q^e` is found one base `q` digit at a
+/// time with [`baby_step_giant_step`] in the subgroup of order `q`. The residues are combined with
+/// the Chinese remainder theorem.
+///
+/// `base` should generate the group. Returns `None` if `target` is not a power of `base`.
+pub fn pohlig_hellman<G: FiniteCyclicGroup + Hash>(base: G, target: G) -> Option<DiscreteLog> {
+  let n = G::ORDER;
+  let mut stats = DiscreteLogStats::default();
+
+  let mut congruences = Vec::new();
+  for (q, e) in factorize(n as u64) {
+    let (q, q_e) = (q as usize, q.pow(e) as usize);
+    let base_i = power(base, n / q_e, &mut stats);
+    let target_i = power(target, n / q_e, &mut stats);
+    // generates the subgroup of order q
+    let gamma = power(base_i, q_e / q, &mut stats);
+
+    // x_i = d_0 + d_1 q + ... + d_{e-1} q^{e-1}
+    let mut x_i = 0;
+    let mut q_k = 1;
+    for _ in 0..e {
+      // (base_i^{-x_i} target_i)^{q^{e-1-k}} = gamma^{d_k}
+      let shifted = inverse(power(base_i, x_i, &mut stats), &mut stats).op(&target_i);
+      let h_k = power(shifted, q_e / q_k / q, &mut stats);
+      stats.group_operations += 1;
+      x_i += bsgs(gamma, h_k, q, &mut stats)? * q_k;
+      q_k *= q;
+    }
+
+    congruences.push((x_i as u64, q_e as u64));
+  }
+  let (exponent, _) = crt(&congruences).expect("the prime power moduli are coprime");
+  let exponent = exponent as usize;
+
+  (power(base, exponent, &mut stats) == target).then_some(DiscreteLog { exponent, stats })
+}
+
+/// Baby-step giant-step for an exponent in `0..order`.
+fn bsgs<G: Group + Hash>(
+  base: G,
+  target: G,
+  order: usize,
+  stats: &mut DiscreteLogStats,
+) -> Option<usize> {
+  let m = isqrt(order as u64 - 1) as usize + 1;
+
+  let mut baby_steps = HashMap::with_capacity(m);
+  let mut element = G::IDENTITY;
+  for j in 0..m {
+    baby_steps.entry(element).or_insert(j);
+    element = element.op(&base);
+    stats.group_operations += 1;
+  }
+  stats.stored_elements = stats.stored_elements.max(baby_steps.len());
+
+  // base^{-m}
+  let giant_step = inverse(element, stats);
+  let mut gamma = target;
+  for i in 0..m {
+    if let Some(j) = baby_steps.get(&gamma) {
+      return Some(i * m + j).filter(|&x| x < order);
+    }
+    gamma = gamma.op(&giant_step);
+    stats.group_operations += 1;
+  }
+  None
+}
+
+/// Computes `base^exponent` by square-and-multiply over [`Group::op`], counting the operations.
+fn power<G: Group>(base: G, exponent: usize, stats: &mut DiscreteLogStats) -> G {
+  let mut result = G::IDENTITY;
+  let mut base = base;
+  let mut exponent = exponent;
+  while exponent > 0 {
+    if exponent & 1 == 1 {
+      result = result.op(&base);
+      stats.group_operations += 1;
+    }
+    base = base.op(&base);
+    stats.group_operations += 1;
+    exponent >>= 1;
+  }
+  result
+}
+
+/// Inverse of an element of a finite group, counted as a single operation.
+fn inverse<G: Group>(element: G, stats: &mut DiscreteLogStats) -> G {
+  stats.group_operations += 1;
+  element.inverse().expect("elements of a finite group are invertible")
+}
+
+/// Finds `x` with `lhs·x = rhs (mod n)` and `base^x = target` among the `gcd(lhs, n)` solutions of
+/// the congruence.
+fn solve_linear<G: Group>(
+  base: G,
+  target: G,
+  lhs: usize,
+  rhs: usize,
+  n: usize,
+  stats: &mut DiscreteLogStats,
+) -> Option<usize> {
+  // lhs·inverse = d (mod n)
+  let (d, inverse, _) = extended_gcd(lhs as u64, n as u64);
+  let d = d as usize;
+  if rhs % d != 0 {
+    return None;
+  }
+  let reduced = n / d;
+  let x_0 = ((rhs / d) as u128 * inverse as u128 % reduced as u128) as usize;
+  (0..d).map(|k| x_0 + k * reduced).find(|&x| power(base, x, stats) == target)
+}
+
+fn hash<G: Hash>(element: &G) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  element.hash(&mut hasher);
+  hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+  use rand::thread_rng;
+
+  use super::*;
+  use crate::{
+    algebra::group::prime::MultiplicativePrimeGroup, curve::AffinePoint, PlutoBaseCurve,
+  };
+
+  type PlutoGroup = AffinePoint<PlutoBaseCurve>;
+
+  #[test]
+  fn pluto_curve() {
+    let mut rng = thread_rng();
+    let generator = PlutoGroup::GENERATOR;
+    let mut target = PlutoGroup::IDENTITY;
+    for x in 0..PlutoGroup::ORDER {
+      assert_eq!(baby_step_giant_step(generator, target).unwrap().exponent, x);
+      assert_eq!(pollard_rho(generator, target, &mut rng).unwrap().exponent, x);
+      assert_eq!(pohlig_hellman(generator, target).unwrap().exponent, x);
+      target += generator;
+    }
+
+    // any point but infinity generates a group of prime order
+    let base = generator + generator + generator;
+    let target = base + base + base + base + base;
+    assert_eq!(pollard_rho(base, target, &mut rng).unwrap().exponent, 5);
+    assert_eq!(baby_step_giant_step(PlutoGroup::IDENTITY, generator), None);
+  }
+
+  #[test]
+  fn multiplicative_group() {
+    // the order 100 = 2^2 * 5^2 is smooth, so Pohlig-Hellman only works in tiny subgroups
+    type Z101 = MultiplicativePrimeGroup<101, 1>;
+    let mut rng = thread_rng();
+    let generator = Z101::GENERATOR;
+    for x in [0, 1, 37, 50, 99] {
+      let target = generator * x;
+      assert_eq!(baby_step_giant_step(generator, target).unwrap().exponent, x);
+      assert_eq!(pollard_rho(generator, target, &mut rng).unwrap().exponent, x);
+      assert_eq!(pohlig_hellman(generator, target).unwrap().exponent, x);
+    }
+
+    type Z81 = MultiplicativePrimeGroup<3, 4>;
+    let generator = Z81::GENERATOR;
+    for x in 0..Z81::ORDER {
+      assert_eq!(pohlig_hellman(generator, generator * x).unwrap().exponent, x);
+    }
+    // 4 = 1 + 3 generates the subgroup of order 27, which does not contain 2 of order 54
+    let base = Z81::new(4);
+    assert_eq!(base.order(), 27);
+    assert_eq!(baby_step_giant_step(base, Z81::new(2)), None);
+  }
+
+  #[test]
+  fn stats() {
+    let generator = PlutoGroup::GENERATOR;
+    let target = generator + generator;
+    let solution = baby_step_giant_step(generator, target).unwrap();
+    // ceil(sqrt(17)) = 5 baby steps are stored
+    assert_eq!(solution.stats.stored_elements, 5);
+    assert_eq!(solution.stats.walks, 0);
+    assert!(solution.stats.group_operations <= 2 * 5 + 1);
+
+    let solution = pollard_rho(generator, target, &mut thread_rng()).unwrap();
+    assert!(solution.stats.walks >= 2);
+    assert!(solution.stats.stored_elements >= 1);
+  }
+}
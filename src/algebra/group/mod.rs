@@ -1,7 +1,60 @@
 #![doc = include_str!("./README.md")]
+/// Implements the operator traits of a group written multiplicatively, with `+` as the group
+/// operation, `-` as the inverse and `*` as exponentiation.
+macro_rules! impl_group_ops {
+  ([$($generics:tt)*] $group:ty) => {
+    impl<$($generics)*> Default for $group {
+      fn default() -> Self { Self::IDENTITY }
+    }
+
+    impl<$($generics)*> Add for $group {
+      type Output = Self;
+
+      fn add(self, rhs: Self) -> Self::Output { Self::op(&self, &rhs) }
+    }
+
+    impl<$($generics)*> AddAssign for $group {
+      fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+    }
+
+    impl<$($generics)*> Neg for $group {
+      type Output = Self;
+
+      fn neg(self) -> Self::Output { Self::inverse(&self).expect("inverse does not exist") }
+    }
+
+    impl<$($generics)*> Sub for $group {
+      type Output = Self;
+
+      fn sub(self, rhs: Self) -> Self::Output { self + -rhs }
+    }
+
+    impl<$($generics)*> SubAssign for $group {
+      fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+    }
+
+    impl<$($generics)*> Mul<usize> for $group {
+      type Output = Self;
+
+      fn mul(self, rhs: usize) -> Self::Output { Self::scalar_mul(&self, rhs) }
+    }
+
+    impl<$($generics)*> MulAssign<usize> for $group {
+      fn mul_assign(&mut self, rhs: usize) { *self = *self * rhs; }
+    }
+  };
+}
+
+pub mod dihedral;
+pub mod discrete_log;
 pub mod prime;
+pub mod symmetric;
 
-use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::{
+  collections::HashSet,
+  hash::Hash,
+  ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 
 use super::Finite;
 
@@ -69,3 +122,45 @@ pub trait FiniteCyclicGroup: FiniteGroup + AbelianGroup {
   /// primitive element of group
   const GENERATOR: Self;
 }
+
+/// Returns the elements of the subgroup generated by `generators`, the identity first.
+///
+/// The subgroup is the closure of the identity under multiplication by the generators, which is
+/// enough in a finite group as every inverse is a positive power.
+pub fn generate_subgroup<G: FiniteGroup + Hash>(generators: &[G]) -> Vec<G> {
+  let mut elements = vec![G::IDENTITY];
+  let mut seen = HashSet::from([G::IDENTITY]);
+  let mut next = 0;
+  while next < elements.len() {
+    let element = elements[next];
+    for generator in generators {
+      let product = element.op(generator);
+      if seen.insert(product) {
+        elements.push(product);
+      }
+    }
+    next += 1;
+  }
+  elements
+}
+
+/// Partitions the elements of a group into conjugacy classes $\{g a g^{-1} : g \in G\}$, in the
+/// order of their first element in `elements`.
+pub fn conjugacy_classes<G: Group + Hash>(elements: &[G]) -> Vec<Vec<G>> {
+  let mut seen = HashSet::new();
+  let mut classes = Vec::new();
+  for a in elements {
+    if seen.contains(a) {
+      continue;
+    }
+    let mut class = Vec::new();
+    for g in elements {
+      let conjugate = g.op(a).op(&g.inverse().expect("group elements are invertible"));
+      if seen.insert(conjugate) {
+        class.push(conjugate);
+      }
+    }
+    classes.push(class);
+  }
+  classes
+}
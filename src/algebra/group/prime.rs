@@ -1,25 +1,47 @@
-//! Defines a multiplicative group under multiplication modulo prime `r`.
+//! Defines the multiplicative groups $(\mathbb{Z}/n\mathbb{Z})^*$ of integers coprime to `n` under
+//! multiplication modulo `n`.
+//!
+//! - [`MultiplicativePrimeGroup`] for prime powers $n = p^k$, which is cyclic for odd `p`.
+//! - [`MultiplicativeGroup`] for any modulus `n`, which is cyclic only for $n = 1, 2, 4, p^k,
+//!   2p^k$.
+//!
+//! The order of the group is Euler's totient $\varphi(n)$, while the
+//! [Carmichael function](https://en.wikipedia.org/wiki/Carmichael_function) $\lambda(n)$, the
+//! exponent of the group, is the smallest `m` with $a^m = 1$ for every element `a`. The order of
+//! any element divides $\lambda(n)$, which is how [`FiniteGroup::order`] is computed, and the group
+//! is cyclic exactly when $\lambda(n) = \varphi(n)$.
 use super::*;
-use crate::{
-  algebra::field::prime::find_primitive_element,
-  encryption::asymmetric::rsa::{gcd, is_prime},
-};
+use crate::algebra::number_theory::{self, is_prime, mod_inverse};
 
 /// [`FiniteGroup`] under multiplication implemented as integer, $(Z/nZ)*$ modulo any prime power
 /// number `n=p^k`.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct MultiplicativePrimeGroup<const P: usize, const K: usize>(usize);
+
 impl<const P: usize, const K: usize> MultiplicativePrimeGroup<P, K> {
-  #[allow(dead_code)]
-  const IS_PRIME: () = assert!(is_prime(P));
+  /// The exponent $\lambda(P^K)$ of the group.
+  pub const EXPONENT: usize = carmichael(Self::MODULUS);
+  /// The modulus `n = P^K`.
+  pub const MODULUS: usize = P.pow(K as u32);
 
   /// create new value in group `Z/nZ`
-  pub fn new(value: usize) -> Self { Self(value % (P ^ K)) }
+  ///
+  /// ## Panics
+  /// - If `P` is not a prime or `K` is zero.
+  /// - If `value` is a multiple of `P`, i.e. not invertible modulo `n`.
+  pub fn new(value: usize) -> Self {
+    assert!(is_prime(P as u64) && K > 0, "P should be a prime and K positive");
+    assert!(value % P != 0, "{value} is not coprime to {}", Self::MODULUS);
+    Self(value % Self::MODULUS)
+  }
+
+  /// Returns the representative of the element in `1..n`.
+  pub const fn value(&self) -> usize { self.0 }
 }
 
 impl<const P: usize, const K: usize> Finite for MultiplicativePrimeGroup<P, K> {
   /// P^K - P^{K-1}
-  const ORDER: usize = (P ^ K) - (P ^ (K - 1));
+  const ORDER: usize = Self::MODULUS - Self::MODULUS / P;
 }
 
 impl<const P: usize, const K: usize> Group for MultiplicativePrimeGroup<P, K> {
@@ -27,72 +49,225 @@ impl<const P: usize, const K: usize> Group for MultiplicativePrimeGroup<P, K> {
 
   const IDENTITY: Self = Self(1);
 
-  fn op(&self, rhs: &Self) -> Self { Self(self.0 * rhs.0 % (P ^ K)) }
+  fn op(&self, rhs: &Self) -> Self { Self(mul_mod(self.0, rhs.0, Self::MODULUS)) }
 
   fn inverse(&self) -> Option<Self> {
-    if gcd(self.0 as u64, P as u64) != 1 {
-      return None;
-    }
-    Some(self.scalar_mul(Self::ORDER - 1))
+    mod_inverse(self.0 as u64, Self::MODULUS as u64).map(|inv| Self(inv as usize))
   }
 
+  /// Computes `self^b` by square-and-multiply.
   fn scalar_mul(&self, b: Self::Scalar) -> Self {
-    let mut res = Self(1);
-    for _ in 0..b {
-      res = Self::op(&res, self);
-    }
-    res
+    Self(number_theory::pow_mod(self.0 as u64, b as u64, Self::MODULUS as u64) as usize)
   }
 }
 
 impl<const P: usize, const K: usize> FiniteGroup for MultiplicativePrimeGroup<P, K> {
-  fn order(&self) -> usize { Self::ORDER }
+  fn order(&self) -> usize { element_order(self.0, Self::MODULUS) }
 }
 
 impl<const P: usize, const K: usize> AbelianGroup for MultiplicativePrimeGroup<P, K> {}
 
 impl<const P: usize, const K: usize> FiniteCyclicGroup for MultiplicativePrimeGroup<P, K> {
-  const GENERATOR: Self = Self(find_primitive_element::<P>());
+  /// The smallest primitive root modulo `P^K`, which only exists for odd `P` or `P^K` = 2, 4.
+  const GENERATOR: Self = match find_generator(Self::MODULUS) {
+    Some(g) => Self(g),
+    None => panic!("the group is not cyclic"),
+  };
 }
 
-impl<const P: usize, const K: usize> Add for MultiplicativePrimeGroup<P, K> {
-  type Output = Self;
+/// [`FiniteGroup`] $(Z/nZ)*$ of the integers coprime to any modulus `N` under multiplication.
+///
+/// The group is only cyclic for `N = 1, 2, 4, p^k, 2p^k` with `p` an odd prime, so instead of a
+/// [`FiniteCyclicGroup::GENERATOR`] it has [`MultiplicativeGroup::generator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MultiplicativeGroup<const N: usize>(usize);
+
+impl<const N: usize> MultiplicativeGroup<N> {
+  /// The exponent $\lambda(N)$ of the group.
+  pub const EXPONENT: usize = carmichael(N);
+
+  /// create new value in group `Z/NZ`
+  ///
+  /// ## Panics
+  /// If `value` is not coprime to `N`.
+  pub fn new(value: usize) -> Self {
+    assert!(number_theory::gcd(value as u64, N as u64) == 1, "{value} is not coprime to {N}");
+    Self(value % N)
+  }
+
+  /// Returns the representative of the element in `0..N`.
+  pub const fn value(&self) -> usize { self.0 }
+
+  /// Returns whether the group is cyclic, i.e. $\lambda(N) = \varphi(N)$.
+  pub const fn is_cyclic() -> bool { Self::EXPONENT == Self::ORDER }
+
+  /// Returns the smallest generator of the group, or `None` if it is not cyclic.
+  pub const fn generator() -> Option<Self> {
+    match find_generator(N) {
+      Some(g) => Some(Self(g)),
+      None => None,
+    }
+  }
 
-  fn add(self, rhs: Self) -> Self::Output { Self::op(&self, &rhs) }
+  /// Returns all elements of the group in increasing order.
+  pub fn elements() -> impl Iterator<Item = Self> {
+    (1..N.max(2)).filter(|&a| number_theory::gcd(a as u64, N as u64) == 1).map(|a| Self(a % N))
+  }
 }
 
-impl<const P: usize, const K: usize> AddAssign for MultiplicativePrimeGroup<P, K> {
-  fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+impl<const N: usize> Finite for MultiplicativeGroup<N> {
+  /// Euler's totient $\varphi(N)$.
+  const ORDER: usize = totient(N);
 }
 
-impl<const P: usize, const K: usize> Neg for MultiplicativePrimeGroup<P, K> {
-  type Output = Self;
+impl<const N: usize> Group for MultiplicativeGroup<N> {
+  type Scalar = usize;
+
+  const IDENTITY: Self = Self(1 % N);
 
-  fn neg(self) -> Self::Output { Self::inverse(&self).expect("inverse does not exist") }
+  fn op(&self, rhs: &Self) -> Self { Self(mul_mod(self.0, rhs.0, N)) }
+
+  fn inverse(&self) -> Option<Self> {
+    mod_inverse(self.0 as u64, N as u64).map(|inv| Self(inv as usize))
+  }
+
+  /// Computes `self^b` by square-and-multiply.
+  fn scalar_mul(&self, b: Self::Scalar) -> Self {
+    Self(number_theory::pow_mod(self.0 as u64, b as u64, N as u64) as usize)
+  }
+}
+
+impl<const N: usize> FiniteGroup for MultiplicativeGroup<N> {
+  fn order(&self) -> usize { element_order(self.0, N) }
 }
 
-impl<const P: usize, const K: usize> Sub for MultiplicativePrimeGroup<P, K> {
-  type Output = Self;
+impl<const N: usize> AbelianGroup for MultiplicativeGroup<N> {}
 
-  fn sub(self, rhs: Self) -> Self::Output { self + -rhs }
+impl_group_ops!([const P: usize, const K: usize] MultiplicativePrimeGroup<P, K>);
+impl_group_ops!([const N: usize] MultiplicativeGroup<N>);
+
+/// Euler's totient $\varphi(n)$, the number of integers in `1..=n` coprime to `n`.
+pub const fn totient(n: usize) -> usize {
+  let mut result = n;
+  let mut m = n;
+  let mut q = 2;
+  while q * q <= m {
+    if m % q == 0 {
+      while m % q == 0 {
+        m /= q;
+      }
+      result -= result / q;
+    }
+    q += 1;
+  }
+  if m > 1 {
+    result -= result / m;
+  }
+  result
 }
 
-impl<const P: usize, const K: usize> SubAssign for MultiplicativePrimeGroup<P, K> {
-  fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+/// The Carmichael function $\lambda(n)$: the least common multiple of $\lambda(p^k)$ over the prime
+/// powers dividing `n`, where $\lambda(p^k) = p^{k-1}(p - 1)$ except for $\lambda(2^k) = 2^{k-2}$
+/// when $k \geq 3$.
+pub const fn carmichael(n: usize) -> usize {
+  let mut result = 1;
+  let mut m = n;
+  let mut q = 2;
+  while m > 1 {
+    if q * q > m {
+      // what is left of `m` is prime
+      q = m;
+    }
+    if m % q == 0 {
+      let mut q_k = 1;
+      while m % q == 0 {
+        m /= q;
+        q_k *= q;
+      }
+      let lambda = if q == 2 && q_k >= 8 { q_k / 4 } else { q_k / q * (q - 1) };
+      result = result / gcd(result, lambda) * lambda;
+    }
+    q += 1;
+  }
+  result
 }
 
-impl<const P: usize, const K: usize> Mul<usize> for MultiplicativePrimeGroup<P, K> {
-  type Output = Self;
+/// The multiplicative order of `a` modulo `n`: starting from $\lambda(n)$, every prime factor `q`
+/// is divided out as long as `a` raised to the quotient is still one.
+pub const fn element_order(a: usize, n: usize) -> usize {
+  let lambda = carmichael(n);
+  let mut order = lambda;
+  let mut m = lambda;
+  let mut q = 2;
+  while m > 1 {
+    if q * q > m {
+      q = m;
+    }
+    if m % q == 0 {
+      while m % q == 0 {
+        m /= q;
+      }
+      while order % q == 0 && pow_mod(a, order / q, n) == 1 % n {
+        order /= q;
+      }
+    }
+    q += 1;
+  }
+  order
+}
 
-  fn mul(self, rhs: usize) -> Self::Output { Self::scalar_mul(&self, rhs) }
+/// Finds the smallest generator of $(Z/nZ)^*$, an element of order $\varphi(n)$, if the group is
+/// cyclic.
+pub const fn find_generator(n: usize) -> Option<usize> {
+  let phi = totient(n);
+  if carmichael(n) != phi {
+    return None;
+  }
+  let mut g = 1;
+  while g < n {
+    if gcd(g, n) == 1 && element_order(g, n) == phi {
+      return Some(g);
+    }
+    g += 1;
+  }
+  // the trivial group of n = 1
+  Some(0)
 }
 
-impl<const P: usize, const K: usize> MulAssign<usize> for MultiplicativePrimeGroup<P, K> {
-  fn mul_assign(&mut self, rhs: usize) { *self = *self * rhs; }
+/// Computes the greatest common divisor in constant context; see [`number_theory::gcd`] for the
+/// runtime version.
+const fn gcd(a: usize, b: usize) -> usize {
+  let (mut a, mut b) = (a, b);
+  while b != 0 {
+    (a, b) = (b, a % b);
+  }
+  a
+}
+
+const fn mul_mod(a: usize, b: usize, n: usize) -> usize {
+  (a as u128 * b as u128 % n as u128) as usize
+}
+
+/// Computes `base^exponent mod n` by square-and-multiply in constant context; see
+/// [`number_theory::pow_mod`] for the runtime version.
+const fn pow_mod(base: usize, exponent: usize, n: usize) -> usize {
+  let mut result = 1 % n;
+  let mut base = base % n;
+  let mut exponent = exponent;
+  while exponent > 0 {
+    if exponent & 1 == 1 {
+      result = mul_mod(result, base, n);
+    }
+    base = mul_mod(base, base, n);
+    exponent >>= 1;
+  }
+  result
 }
 
 #[cfg(test)]
 mod tests {
+  use rstest::rstest;
+
   use super::*;
 
   #[test]
@@ -115,4 +290,72 @@ mod tests {
     // order
     assert_eq!(gen.order(), MulGroup::ORDER);
   }
+
+  #[test]
+  fn prime_powers() {
+    assert_eq!(MultiplicativePrimeGroup::<5, 2>::MODULUS, 25);
+    assert_eq!(MultiplicativePrimeGroup::<5, 2>::ORDER, 20);
+    assert_eq!(MultiplicativePrimeGroup::<3, 4>::ORDER, 54);
+    assert_eq!(MultiplicativePrimeGroup::<2, 1>::ORDER, 1);
+    assert_eq!(MultiplicativePrimeGroup::<2, 2>::GENERATOR.value(), 3);
+    // 2 is a primitive root modulo 5 and 25, but 7 is the smallest one modulo 49
+    assert_eq!(MultiplicativePrimeGroup::<5, 3>::GENERATOR.value(), 2);
+    assert_eq!(MultiplicativePrimeGroup::<7, 2>::GENERATOR.value(), 3);
+    assert_eq!(MultiplicativePrimeGroup::<7, 2>::GENERATOR.order(), 42);
+
+    let a = MultiplicativePrimeGroup::<101, 2>::new(1234);
+    let mut power = MultiplicativePrimeGroup::<101, 2>::IDENTITY;
+    for b in 0..300 {
+      assert_eq!(a * b, power);
+      power += a;
+    }
+    assert_eq!(a * MultiplicativePrimeGroup::<101, 2>::ORDER, MultiplicativePrimeGroup::IDENTITY);
+    assert_eq!(a - a, MultiplicativePrimeGroup::default());
+  }
+
+  #[test]
+  #[should_panic]
+  fn not_coprime() { MultiplicativePrimeGroup::<5, 2>::new(10); }
+
+  #[rstest]
+  #[case(1, 1, 1)]
+  #[case(2, 1, 1)]
+  #[case(8, 4, 2)]
+  #[case(15, 8, 4)]
+  #[case(18, 6, 6)]
+  #[case(32, 16, 8)]
+  #[case(561, 320, 80)]
+  #[case(1000, 400, 100)]
+  fn totient_and_carmichael(#[case] n: usize, #[case] phi: usize, #[case] lambda: usize) {
+    assert_eq!(totient(n), phi);
+    assert_eq!(carmichael(n), lambda);
+    assert_eq!(find_generator(n).is_some(), phi == lambda);
+  }
+
+  #[test]
+  fn composite_moduli() {
+    type Z15 = MultiplicativeGroup<15>;
+    assert_eq!(Z15::ORDER, 8);
+    assert!(!Z15::is_cyclic());
+    assert_eq!(Z15::generator(), None);
+    assert_eq!(Z15::elements().map(|a| a.value()).collect::<Vec<_>>(), [1, 2, 4, 7, 8, 11, 13, 14]);
+    assert_eq!(Z15::elements().map(|a| a.order()).max(), Some(Z15::EXPONENT));
+    for a in Z15::elements() {
+      assert_eq!(a + a.inverse().unwrap(), Z15::IDENTITY);
+      assert_eq!(a * a.order(), Z15::IDENTITY);
+      assert_eq!(Z15::ORDER % a.order(), 0);
+    }
+
+    type Z54 = MultiplicativeGroup<54>;
+    let g = Z54::generator().unwrap();
+    assert_eq!(g.value(), 5);
+    assert_eq!(g.order(), Z54::ORDER);
+    assert_eq!(Z54::elements().count(), Z54::ORDER);
+
+    // the element order is the brute force one
+    type Z1000 = MultiplicativeGroup<1000>;
+    let a = Z1000::new(3);
+    let brute_force = (1..).find(|&m| a * m == Z1000::IDENTITY).unwrap();
+    assert_eq!(a.order(), brute_force);
+  }
 }
@@ -0,0 +1,379 @@
+//! The [symmetric group](https://en.wikipedia.org/wiki/Symmetric_group) $S_N$ of all `N!`
+//! permutations of the points `0..N`.
+//!
+//! ## Operation
+//! The group operation is composition of functions: `a·b` applies `b` first, then `a`, so
+//! $(a \cdot b)(i) = a(b(i))$.
+//!
+//! ## Cycle notation
+//! A permutation is written as a product of cycles, `(0 1 2)` mapping `0 -> 1 -> 2 -> 0`, and the
+//! fixed points are left out. The identity is written `()`. Points are numbered from `0` like the
+//! indices of [`SymmetricGroup::mapping`]. Cycles don't have to be disjoint when parsing, in which
+//! case they are composed like any other product, the rightmost one applied first:
+//! `(0 1)(1 2)` is `(0 1 2)`.
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use super::*;
+use crate::algebra::number_theory::gcd;
+
+/// An element of the symmetric group $S_N$, stored as the images of `0..N`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SymmetricGroup<const N: usize> {
+  mapping: [usize; N],
+}
+
+/// Errors from parsing or building permutations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PermutationError {
+  /// A point is not in `0..N`.
+  PointOutOfRange(usize),
+  /// A point appears twice in a mapping or a cycle.
+  RepeatedPoint(usize),
+  /// The string is not a product of cycles.
+  InvalidCycleNotation(String),
+  /// The permutation is not a symmetry of the regular polygon.
+  NotDihedral(String),
+}
+
+impl Error for PermutationError {}
+
+impl Display for PermutationError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match *self {
+      PermutationError::PointOutOfRange(point) => write!(f, "point out of range: {}", point),
+      PermutationError::RepeatedPoint(point) => write!(f, "repeated point: {}", point),
+      PermutationError::InvalidCycleNotation(ref s) => write!(f, "invalid cycle notation: {}", s),
+      PermutationError::NotDihedral(ref s) => write!(f, "not a dihedral permutation: {}", s),
+    }
+  }
+}
+
+impl<const N: usize> SymmetricGroup<N> {
+  /// Creates the permutation sending `i` to `mapping[i]`.
+  ///
+  /// ## Panics
+  /// If `mapping` is not a permutation of `0..N`.
+  pub fn new(mapping: [usize; N]) -> Self {
+    Self::try_from(mapping).unwrap_or_else(|e| panic!("{e}"))
+  }
+
+  /// Returns the images of `0..N`.
+  pub const fn mapping(&self) -> [usize; N] { self.mapping }
+
+  /// Returns the image of `point`.
+  pub const fn apply(&self, point: usize) -> usize { self.mapping[point] }
+
+  /// Creates the product of `cycles`, the last one applied first.
+  pub fn from_cycles(cycles: &[&[usize]]) -> Result<Self, PermutationError> {
+    cycles.iter().try_fold(Self::IDENTITY, |acc, cycle| Ok(acc.op(&Self::cycle(cycle)?)))
+  }
+
+  /// Creates the cycle `(c_0 c_1 ... c_{k-1})`.
+  fn cycle(cycle: &[usize]) -> Result<Self, PermutationError> {
+    let mut mapping = Self::IDENTITY.mapping;
+    for (i, &point) in cycle.iter().enumerate() {
+      if point >= N {
+        return Err(PermutationError::PointOutOfRange(point));
+      }
+      if cycle[..i].contains(&point) {
+        return Err(PermutationError::RepeatedPoint(point));
+      }
+      mapping[point] = cycle[(i + 1) % cycle.len()];
+    }
+    Ok(Self { mapping })
+  }
+
+  /// Returns the disjoint cycles of length at least two, each starting from its smallest point.
+  pub fn cycles(&self) -> Vec<Vec<usize>> {
+    self.all_cycles().into_iter().filter(|cycle| cycle.len() > 1).collect()
+  }
+
+  /// Returns the disjoint cycles including the fixed points.
+  fn all_cycles(&self) -> Vec<Vec<usize>> {
+    let mut visited = [false; N];
+    let mut cycles = Vec::new();
+    for start in 0..N {
+      let mut cycle = Vec::new();
+      let mut point = start;
+      while !visited[point] {
+        visited[point] = true;
+        cycle.push(point);
+        point = self.mapping[point];
+      }
+      if !cycle.is_empty() {
+        cycles.push(cycle);
+      }
+    }
+    cycles
+  }
+
+  /// Returns the lengths of the disjoint cycles, fixed points included, in decreasing order. Two
+  /// permutations are conjugate if and only if they have the same cycle type.
+  pub fn cycle_type(&self) -> Vec<usize> {
+    let mut lengths: Vec<usize> = self.all_cycles().iter().map(Vec::len).collect();
+    lengths.sort_unstable_by(|a, b| b.cmp(a));
+    lengths
+  }
+
+  /// Returns whether the permutation is a product of an even number of transpositions.
+  pub fn is_even(&self) -> bool { (N - self.all_cycles().len()) % 2 == 0 }
+
+  /// Returns the sign `1` of an even permutation or `-1` of an odd one, a homomorphism to
+  /// $\{\pm 1\}$.
+  pub fn sign(&self) -> i8 {
+    match self.is_even() {
+      true => 1,
+      false => -1,
+    }
+  }
+
+  /// Returns the conjugate $g a g^{-1}$, which has the cycles of `a` with every point relabelled by
+  /// `g`.
+  pub fn conjugate(&self, g: &Self) -> Self { g.op(self).op(&-*g) }
+
+  /// Returns all `N!` permutations in lexicographic order of their mappings.
+  pub fn elements() -> impl Iterator<Item = Self> {
+    std::iter::successors(Some(Self::IDENTITY), Self::next_permutation)
+  }
+
+  /// Returns the next mapping in lexicographic order, or `None` after the last one.
+  fn next_permutation(&self) -> Option<Self> {
+    let mut mapping = self.mapping;
+    let pivot = (1..N).rev().find(|&i| mapping[i - 1] < mapping[i])? - 1;
+    let successor = (pivot + 1..N).rev().find(|&j| mapping[j] > mapping[pivot])?;
+    mapping.swap(pivot, successor);
+    mapping[pivot + 1..].reverse();
+    Some(Self { mapping })
+  }
+
+  /// Returns the conjugacy classes of $S_N$, one for each partition of `N`.
+  pub fn conjugacy_classes() -> Vec<Vec<Self>> {
+    conjugacy_classes(&Self::elements().collect::<Vec<_>>())
+  }
+}
+
+impl<const N: usize> TryFrom<[usize; N]> for SymmetricGroup<N> {
+  type Error = PermutationError;
+
+  fn try_from(mapping: [usize; N]) -> Result<Self, Self::Error> {
+    let mut seen = [false; N];
+    for &point in &mapping {
+      if point >= N {
+        return Err(PermutationError::PointOutOfRange(point));
+      }
+      if seen[point] {
+        return Err(PermutationError::RepeatedPoint(point));
+      }
+      seen[point] = true;
+    }
+    Ok(Self { mapping })
+  }
+}
+
+impl<const N: usize> Finite for SymmetricGroup<N> {
+  /// `N!`, saturated to `usize::MAX` from `N = 21` on.
+  const ORDER: usize = {
+    let mut order: usize = 1;
+    let mut i = 2;
+    while i <= N {
+      order = order.saturating_mul(i);
+      i += 1;
+    }
+    order
+  };
+}
+
+impl<const N: usize> Group for SymmetricGroup<N> {
+  type Scalar = usize;
+
+  const IDENTITY: Self = {
+    let mut mapping = [0; N];
+    let mut i = 0;
+    while i < N {
+      mapping[i] = i;
+      i += 1;
+    }
+    Self { mapping }
+  };
+
+  fn op(&self, rhs: &Self) -> Self { Self { mapping: rhs.mapping.map(|i| self.mapping[i]) } }
+
+  fn inverse(&self) -> Option<Self> {
+    let mut mapping = [0; N];
+    for (i, &j) in self.mapping.iter().enumerate() {
+      mapping[j] = i;
+    }
+    Some(Self { mapping })
+  }
+
+  /// Computes `self^scalar` by square-and-multiply.
+  fn scalar_mul(&self, scalar: Self::Scalar) -> Self {
+    let mut result = Self::IDENTITY;
+    let mut base = *self;
+    let mut exp = scalar;
+    while exp > 0 {
+      if exp % 2 == 1 {
+        result = result.op(&base);
+      }
+      base = base.op(&base);
+      exp /= 2;
+    }
+    result
+  }
+}
+
+impl<const N: usize> FiniteGroup for SymmetricGroup<N> {
+  /// The least common multiple of the cycle lengths.
+  fn order(&self) -> usize {
+    self
+      .all_cycles()
+      .iter()
+      .map(Vec::len)
+      .fold(1, |lcm, len| lcm / gcd(lcm as u64, len as u64) as usize * len)
+  }
+}
+
+impl_group_ops!([const N: usize] SymmetricGroup<N>);
+
+impl<const N: usize> Display for SymmetricGroup<N> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let cycles = self.cycles();
+    if cycles.is_empty() {
+      return write!(f, "()");
+    }
+    for cycle in cycles {
+      let points: Vec<String> = cycle.iter().map(usize::to_string).collect();
+      write!(f, "({})", points.join(" "))?;
+    }
+    Ok(())
+  }
+}
+
+impl<const N: usize> FromStr for SymmetricGroup<N> {
+  type Err = PermutationError;
+
+  /// Parses a product of cycles such as `(0 1 2)(3 4)`, the points being separated by spaces or
+  /// commas.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || PermutationError::InvalidCycleNotation(s.to_string());
+    let mut rest = s.trim();
+    let mut cycles = Vec::new();
+    while !rest.is_empty() {
+      let (cycle, tail) =
+        rest.strip_prefix('(').and_then(|r| r.split_once(')')).ok_or_else(invalid)?;
+      let points = cycle
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|point| !point.is_empty())
+        .map(|point| point.parse::<usize>().map_err(|_| invalid()))
+        .collect::<Result<Vec<_>, _>>()?;
+      cycles.push(points);
+      rest = tail.trim_start();
+    }
+    let cycles: Vec<&[usize]> = cycles.iter().map(Vec::as_slice).collect();
+    Self::from_cycles(&cycles)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rstest::rstest;
+
+  use super::*;
+
+  type S3 = SymmetricGroup<3>;
+  type S5 = SymmetricGroup<5>;
+
+  #[test]
+  fn group_properties() {
+    let a = S3::new([1, 0, 2]);
+    let b = S3::new([0, 2, 1]);
+    let ab = a + b;
+    let ba = b + a;
+
+    // composition applies the right element first
+    assert_eq!(ab.apply(0), a.apply(b.apply(0)));
+    assert_ne!(ab, ba);
+    assert_eq!(a + S3::IDENTITY, a);
+    assert_eq!(ab + -ab, S3::IDENTITY);
+    assert_eq!(-ab, ba);
+    assert_eq!(a.order(), 2);
+    assert_eq!(ab.order(), 3);
+    assert_eq!(ab * 4, ab);
+    assert_eq!(S3::ORDER, 6);
+    assert_eq!(S3::elements().count(), 6);
+    assert_eq!(SymmetricGroup::<20>::ORDER, 2_432_902_008_176_640_000);
+  }
+
+  #[rstest]
+  #[case("()", "()")]
+  #[case("(0 1 2)(3 4)", "(0 1 2)(3 4)")]
+  #[case("(1, 2, 0)", "(0 1 2)")]
+  #[case("(4 3)(2)", "(3 4)")]
+  #[case("(0 1)(1 2)", "(0 1 2)")]
+  #[case("(0 1)(0 1)", "()")]
+  fn cycle_notation(#[case] s: &str, #[case] expected: &str) {
+    let permutation: S5 = s.parse().unwrap();
+    assert_eq!(permutation.to_string(), expected);
+    assert_eq!(expected.parse::<S5>().unwrap(), permutation);
+  }
+
+  #[rstest]
+  #[case("(0 5)", PermutationError::PointOutOfRange(5))]
+  #[case("(0 1 0)", PermutationError::RepeatedPoint(0))]
+  #[case("(0 1", PermutationError::InvalidCycleNotation("(0 1".to_string()))]
+  #[case("0 1", PermutationError::InvalidCycleNotation("0 1".to_string()))]
+  #[case("(a b)", PermutationError::InvalidCycleNotation("(a b)".to_string()))]
+  fn invalid_cycle_notation(#[case] s: &str, #[case] error: PermutationError) {
+    assert_eq!(s.parse::<S5>(), Err(error));
+  }
+
+  #[test]
+  fn sign_and_order() {
+    let permutation: S5 = "(0 1 2)(3 4)".parse().unwrap();
+    assert_eq!(permutation.order(), 6);
+    assert_eq!(permutation.sign(), -1);
+    assert_eq!(permutation.cycle_type(), vec![3, 2]);
+    assert!((permutation * 2).is_even());
+
+    // the sign is a homomorphism, and the even permutations form the alternating group
+    for a in S3::elements() {
+      for b in S3::elements() {
+        assert_eq!((a + b).sign(), a.sign() * b.sign());
+      }
+    }
+    assert_eq!(S5::elements().filter(S5::is_even).count(), S5::ORDER / 2);
+  }
+
+  #[test]
+  fn conjugacy() {
+    // one class for each of the 7 partitions of 5, of size 5! / (the centralizer order)
+    let classes = S5::conjugacy_classes();
+    assert_eq!(classes.len(), 7);
+    let mut sizes: Vec<usize> = classes.iter().map(Vec::len).collect();
+    sizes.sort_unstable();
+    assert_eq!(sizes, vec![1, 10, 15, 20, 20, 24, 30]);
+    for class in &classes {
+      assert!(class.iter().all(|a| a.cycle_type() == class[0].cycle_type()));
+    }
+
+    let a: S5 = "(0 1 2)".parse().unwrap();
+    let g: S5 = "(2 3)".parse().unwrap();
+    assert_eq!(a.conjugate(&g).to_string(), "(0 1 3)");
+  }
+
+  #[test]
+  fn subgroups() {
+    // a transposition and an N-cycle generate S_N
+    let transposition: S5 = "(0 1)".parse().unwrap();
+    let cycle: S5 = "(0 1 2 3 4)".parse().unwrap();
+    assert_eq!(generate_subgroup(&[transposition, cycle]).len(), S5::ORDER);
+    assert_eq!(generate_subgroup(&[cycle]).len(), 5);
+
+    // the 3-cycles generate the alternating group
+    let three_cycles: Vec<S5> = S5::elements().filter(|a| a.cycle_type() == [3, 1, 1]).collect();
+    let alternating = generate_subgroup(&three_cycles);
+    assert_eq!(alternating.len(), 60);
+    assert!(alternating.iter().all(S5::is_even));
+    assert_eq!(generate_subgroup::<S5>(&[]), vec![S5::IDENTITY]);
+  }
+}
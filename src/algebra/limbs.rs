@@ -0,0 +1,112 @@
+//! Arithmetic on unsigned integers given as little-endian 64-bit limbs, the form in which
+//! [`FiniteField::order`](crate::algebra::field::FiniteField::order) and
+//! [`LimbRepr::modulus`](crate::algebra::field::LimbRepr::modulus) pass around integers too large
+//! for a `usize`. Only the few operations the exponents of field and curve arithmetic need are
+//! provided, on integers of any length.
+
+/// Multiplies two integers, trimming the leading zero limbs of the product.
+pub(crate) fn mul_limbs(lhs: &[u64], rhs: &[u64]) -> Vec<u64> {
+  let mut product = vec![0u64; lhs.len() + rhs.len()];
+  for (i, &l) in lhs.iter().enumerate() {
+    let mut carry = 0u128;
+    for (j, &r) in rhs.iter().enumerate() {
+      let current = product[i + j] as u128 + l as u128 * r as u128 + carry;
+      product[i + j] = current as u64;
+      carry = current >> 64;
+    }
+    product[i + rhs.len()] = carry as u64;
+  }
+  while product.len() > 1 && product.last() == Some(&0) {
+    product.pop();
+  }
+  product
+}
+
+/// Adds a single-limb `value`, growing the result by a limb on overflow.
+pub(crate) fn add_small(limbs: &[u64], value: u64) -> Vec<u64> {
+  let mut sum = limbs.to_vec();
+  let mut carry = value;
+  for limb in sum.iter_mut() {
+    let (next, overflow) = limb.overflowing_add(carry);
+    *limb = next;
+    carry = overflow as u64;
+  }
+  if carry != 0 {
+    sum.push(carry);
+  }
+  sum
+}
+
+/// Subtracts a single-limb `value`.
+///
+/// ## Panics
+/// If `value` is larger than the integer.
+pub(crate) fn sub_small(limbs: &[u64], value: u64) -> Vec<u64> {
+  let mut difference = limbs.to_vec();
+  let mut borrow = value;
+  for limb in difference.iter_mut() {
+    let (next, underflow) = limb.overflowing_sub(borrow);
+    *limb = next;
+    borrow = underflow as u64;
+  }
+  assert_eq!(borrow, 0, "subtraction underflow");
+  difference
+}
+
+/// Shifts right by `bits`, dropping the limbs shifted out entirely.
+pub(crate) fn shr(limbs: &[u64], bits: usize) -> Vec<u64> {
+  let (words, bits) = (bits / 64, bits % 64);
+  (words..limbs.len())
+    .map(|i| {
+      let high =
+        limbs.get(i + 1).map_or(0, |&next| if bits == 0 { 0 } else { next << (64 - bits) });
+      (limbs[i] >> bits) | high
+    })
+    .collect()
+}
+
+/// Divides by a single-limb divisor, returning the quotient and the remainder.
+pub(crate) fn div_rem_small(limbs: &[u64], divisor: u64) -> (Vec<u64>, u64) {
+  let mut quotient = vec![0; limbs.len()];
+  let mut rem = 0u128;
+  for (q, &limb) in quotient.iter_mut().zip(limbs).rev() {
+    let current = (rem << 64) | limb as u128;
+    *q = (current / divisor as u128) as u64;
+    rem = current % divisor as u128;
+  }
+  (quotient, rem as u64)
+}
+
+/// Returns the number of trailing zero bits, `64 * limbs.len()` for zero.
+pub(crate) fn trailing_zeros(limbs: &[u64]) -> usize {
+  let words = limbs.iter().take_while(|&&limb| limb == 0).count();
+  words * 64 + limbs.get(words).map_or(0, |limb| limb.trailing_zeros() as usize)
+}
+
+/// Returns the number of significant bits, 0 for zero.
+pub(crate) fn bit_length(limbs: &[u64]) -> usize {
+  limbs
+    .iter()
+    .rposition(|&limb| limb != 0)
+    .map_or(0, |i| i * 64 + 64 - limbs[i].leading_zeros() as usize)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn limbs() {
+    let q = [u64::MAX, 1];
+    assert_eq!(add_small(&q, 1), vec![0, 2]);
+    assert_eq!(add_small(&[u64::MAX], 1), vec![0, 1]);
+    assert_eq!(sub_small(&[0, 1], 1), vec![u64::MAX, 0]);
+    assert_eq!(div_rem_small(&[0, 1], 3), (vec![0x5555_5555_5555_5555, 0], 1));
+    assert_eq!(shr(&q, 1), vec![u64::MAX, 0]);
+    assert_eq!(shr(&q, 64), vec![1]);
+    assert_eq!(trailing_zeros(&[0, 8]), 67);
+    assert_eq!(bit_length(&[0, 8, 0]), 68);
+    assert_eq!(mul_limbs(&[u64::MAX], &[u64::MAX]), vec![1, u64::MAX - 1]);
+    assert_eq!(mul_limbs(&[3], &[5]), vec![15]);
+  }
+}
@@ -0,0 +1,393 @@
+//! Dense matrices over any [`Field`] and the linear algebra built on
+//! [Gaussian elimination](https://en.wikipedia.org/wiki/Gaussian_elimination).
+//!
+//! Every algorithm reduces the matrix to its reduced row echelon form, where each nonzero row
+//! starts with a one, the pivot, in a column that is zero everywhere else:
+//! - the rank is the number of pivots,
+//! - the determinant is the product of the pivots before they were scaled to one, with a sign flip
+//!   for every row swap,
+//! - the inverse of `A` is the right half of the reduced `[A | I]`,
+//! - the solutions of `Ax = b` are read off the reduced `[A | b]`, and the kernel has one basis
+//!   vector for each column without a pivot.
+//!
+//! ## MDS matrices
+//! A `k x n` matrix is *maximum distance separable* when every square submatrix is invertible. The
+//! linear layer of [Poseidon](crate::hashes::poseidon) uses one so that changing `d` elements of
+//! the state changes at least `n + 1 - d` elements of its product, which is checked by
+//! [`Matrix::is_mds`].
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+use itertools::Itertools;
+
+use super::field::Field;
+
+/// A `rows x cols` matrix over `F`, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<F: Field> {
+  rows:    usize,
+  cols:    usize,
+  entries: Vec<F>,
+}
+
+/// The reduced row echelon form of a matrix, along with what the reduction found out.
+struct Reduction<F: Field> {
+  /// The reduced matrix.
+  matrix:      Matrix<F>,
+  /// The column of the pivot of each nonzero row.
+  pivots:      Vec<usize>,
+  /// The product of the pivots before scaling, negated for every row swap.
+  determinant: F,
+}
+
+impl<F: Field> Matrix<F> {
+  /// Creates a matrix from its rows.
+  ///
+  /// ## Panics
+  /// If the rows don't all have the same length.
+  pub fn new(rows: Vec<Vec<F>>) -> Self {
+    let cols = rows.first().map_or(0, Vec::len);
+    assert!(rows.iter().all(|row| row.len() == cols), "rows should all have the same length");
+    Self { rows: rows.len(), cols, entries: rows.into_iter().flatten().collect() }
+  }
+
+  /// Creates the matrix with entry `f(i, j)` in row `i` and column `j`.
+  pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> F) -> Self {
+    let mut entries = Vec::with_capacity(rows * cols);
+    for i in 0..rows {
+      for j in 0..cols {
+        entries.push(f(i, j));
+      }
+    }
+    Self { rows, cols, entries }
+  }
+
+  /// Creates the `rows x cols` zero matrix.
+  pub fn zero(rows: usize, cols: usize) -> Self { Self::from_fn(rows, cols, |_, _| F::ZERO) }
+
+  /// Creates the `n x n` identity matrix.
+  pub fn identity(n: usize) -> Self {
+    Self::from_fn(n, n, |i, j| if i == j { F::ONE } else { F::ZERO })
+  }
+
+  /// Creates the `rows x cols` Vandermonde matrix of `points`, with entry $x_i^j$.
+  pub fn vandermonde(points: &[F], cols: usize) -> Self {
+    Self::from_fn(points.len(), cols, |i, j| points[i].pow(j))
+  }
+
+  /// Returns the number of rows.
+  pub const fn rows(&self) -> usize { self.rows }
+
+  /// Returns the number of columns.
+  pub const fn cols(&self) -> usize { self.cols }
+
+  /// Returns whether the matrix has as many rows as columns.
+  pub const fn is_square(&self) -> bool { self.rows == self.cols }
+
+  /// Returns row `i`.
+  pub fn row(&self, i: usize) -> &[F] { &self.entries[i * self.cols..(i + 1) * self.cols] }
+
+  /// Returns the rows of the matrix.
+  pub fn to_rows(&self) -> Vec<Vec<F>> { (0..self.rows).map(|i| self.row(i).to_vec()).collect() }
+
+  /// Returns the transpose, with entry `(j, i)` in row `i` and column `j`.
+  pub fn transpose(&self) -> Self { Self::from_fn(self.cols, self.rows, |i, j| self[(j, i)]) }
+
+  /// Returns the submatrix keeping the rows `rows` and the columns `cols`, in that order.
+  pub fn submatrix(&self, rows: &[usize], cols: &[usize]) -> Self {
+    Self::from_fn(rows.len(), cols.len(), |i, j| self[(rows[i], cols[j])])
+  }
+
+  /// Returns `[self | other]`, the columns of `other` appended to those of `self`.
+  ///
+  /// ## Panics
+  /// If the matrices don't have the same number of rows.
+  pub fn augment(&self, other: &Self) -> Self {
+    assert_eq!(self.rows, other.rows, "matrices should have the same number of rows");
+    Self::from_fn(self.rows, self.cols + other.cols, |i, j| match j < self.cols {
+      true => self[(i, j)],
+      false => other[(i, j - self.cols)],
+    })
+  }
+
+  /// Multiplies the matrix by the column vector `vector`.
+  ///
+  /// ## Panics
+  /// If the length of `vector` is not the number of columns.
+  pub fn mul_vec(&self, vector: &[F]) -> Vec<F> {
+    assert_eq!(self.cols, vector.len(), "vector length should be the number of columns");
+    (0..self.rows).map(|i| self.row(i).iter().zip(vector).map(|(&a, &x)| a * x).sum()).collect()
+  }
+
+  /// Returns the reduced row echelon form of the matrix.
+  pub fn row_echelon(&self) -> Self { self.reduce().matrix }
+
+  /// Returns the number of linearly independent rows, which is also the number of linearly
+  /// independent columns.
+  pub fn rank(&self) -> usize { self.reduce().pivots.len() }
+
+  /// Returns the determinant of the matrix.
+  ///
+  /// ## Panics
+  /// If the matrix is not square.
+  pub fn determinant(&self) -> F {
+    assert!(self.is_square(), "determinant is only defined for square matrices");
+    let reduction = self.reduce();
+    match reduction.pivots.len() == self.rows {
+      true => reduction.determinant,
+      false => F::ZERO,
+    }
+  }
+
+  /// Returns the inverse of the matrix, or `None` if it is not square or singular.
+  pub fn inverse(&self) -> Option<Self> {
+    if !self.is_square() {
+      return None;
+    }
+    let n = self.rows;
+    let reduction = self.augment(&Self::identity(n)).reduce();
+    // the matrix is invertible iff the pivots are the diagonal of the left half
+    if reduction.pivots.len() < n || reduction.pivots[n - 1] >= n {
+      return None;
+    }
+    let inverse: Vec<usize> = (n..2 * n).collect();
+    Some(reduction.matrix.submatrix(&(0..n).collect::<Vec<_>>(), &inverse))
+  }
+
+  /// Returns a basis of the kernel $\{x : Ax = 0\}$, one vector for each column without a pivot,
+  /// which has a one at that column and zero at the other free columns.
+  pub fn kernel(&self) -> Vec<Vec<F>> {
+    let Reduction { matrix, pivots, .. } = self.reduce();
+    (0..self.cols)
+      .filter(|column| !pivots.contains(column))
+      .map(|free| {
+        let mut vector = vec![F::ZERO; self.cols];
+        vector[free] = F::ONE;
+        for (i, &pivot) in pivots.iter().enumerate() {
+          vector[pivot] = -matrix[(i, free)];
+        }
+        vector
+      })
+      .collect()
+  }
+
+  /// Returns a solution `x` of `Ax = b`, with the free variables set to zero, or `None` if there is
+  /// none. All solutions are `x` plus the [`Matrix::kernel`].
+  ///
+  /// ## Panics
+  /// If the length of `b` is not the number of rows.
+  pub fn solve(&self, b: &[F]) -> Option<Vec<F>> {
+    assert_eq!(self.rows, b.len(), "right hand side length should be the number of rows");
+    let column = Self::from_fn(self.rows, 1, |i, _| b[i]);
+    let Reduction { matrix, pivots, .. } = self.augment(&column).reduce();
+    // a pivot in the last column is the equation 0 = 1
+    if pivots.last() == Some(&self.cols) {
+      return None;
+    }
+    let mut x = vec![F::ZERO; self.cols];
+    for (i, &pivot) in pivots.iter().enumerate() {
+      x[pivot] = matrix[(i, self.cols)];
+    }
+    Some(x)
+  }
+
+  /// Returns whether every square submatrix is invertible, i.e. the matrix is maximum distance
+  /// separable. This computes the determinant of all $\sum_k \binom{r}{k}\binom{c}{k}$ square
+  /// submatrices, so it is only practical for small matrices.
+  pub fn is_mds(&self) -> bool {
+    (1..=self.rows.min(self.cols)).all(|k| {
+      (0..self.rows).combinations(k).all(|rows| {
+        (0..self.cols).combinations(k).all(|cols| self.submatrix(&rows, &cols).rank() == k)
+      })
+    })
+  }
+
+  /// Gauss-Jordan elimination to the reduced row echelon form.
+  fn reduce(&self) -> Reduction<F> {
+    let mut matrix = self.clone();
+    let mut pivots = Vec::new();
+    let mut determinant = F::ONE;
+
+    for column in 0..self.cols {
+      let row = pivots.len();
+      let Some(pivot_row) = (row..self.rows).find(|&i| matrix[(i, column)] != F::ZERO) else {
+        continue;
+      };
+      if pivot_row != row {
+        matrix.swap_rows(row, pivot_row);
+        determinant = -determinant;
+      }
+
+      let pivot = matrix[(row, column)];
+      determinant *= pivot;
+      let inverse = pivot.inverse().expect("pivot is nonzero");
+      for j in column..self.cols {
+        matrix[(row, j)] *= inverse;
+      }
+
+      for i in (0..self.rows).filter(|&i| i != row) {
+        let factor = matrix[(i, column)];
+        if factor != F::ZERO {
+          for j in column..self.cols {
+            let value = matrix[(row, j)];
+            matrix[(i, j)] -= factor * value;
+          }
+        }
+      }
+
+      pivots.push(column);
+      if pivots.len() == self.rows {
+        break;
+      }
+    }
+
+    Reduction { matrix, pivots, determinant }
+  }
+
+  fn swap_rows(&mut self, a: usize, b: usize) {
+    for j in 0..self.cols {
+      self.entries.swap(a * self.cols + j, b * self.cols + j);
+    }
+  }
+}
+
+impl<F: Field> From<Vec<Vec<F>>> for Matrix<F> {
+  fn from(rows: Vec<Vec<F>>) -> Self { Self::new(rows) }
+}
+
+impl<F: Field> Index<(usize, usize)> for Matrix<F> {
+  type Output = F;
+
+  fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+    assert!(i < self.rows && j < self.cols, "index out of bounds");
+    &self.entries[i * self.cols + j]
+  }
+}
+
+impl<F: Field> IndexMut<(usize, usize)> for Matrix<F> {
+  fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+    assert!(i < self.rows && j < self.cols, "index out of bounds");
+    &mut self.entries[i * self.cols + j]
+  }
+}
+
+impl<F: Field> Add for Matrix<F> {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self::Output {
+    assert_eq!((self.rows, self.cols), (rhs.rows, rhs.cols), "matrices should have the same shape");
+    Self::from_fn(self.rows, self.cols, |i, j| self[(i, j)] + rhs[(i, j)])
+  }
+}
+
+impl<F: Field> Sub for Matrix<F> {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self::Output { self + -rhs }
+}
+
+impl<F: Field> Neg for Matrix<F> {
+  type Output = Self;
+
+  fn neg(self) -> Self::Output {
+    Self { entries: self.entries.into_iter().map(|a| -a).collect(), ..self }
+  }
+}
+
+impl<F: Field> Mul for Matrix<F> {
+  type Output = Self;
+
+  fn mul(self, rhs: Self) -> Self::Output {
+    assert_eq!(self.cols, rhs.rows, "columns of the left matrix should match rows of the right");
+    Self::from_fn(self.rows, rhs.cols, |i, j| {
+      (0..self.cols).map(|k| self[(i, k)] * rhs[(k, j)]).sum()
+    })
+  }
+}
+
+impl<F: Field> Mul<F> for Matrix<F> {
+  type Output = Self;
+
+  fn mul(self, rhs: F) -> Self::Output {
+    Self { entries: self.entries.into_iter().map(|a| a * rhs).collect(), ..self }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rand::{thread_rng, Rng};
+
+  use super::*;
+  use crate::PlutoBaseField;
+
+  type F = PlutoBaseField;
+
+  fn matrix(rows: &[&[usize]]) -> Matrix<F> {
+    Matrix::new(rows.iter().map(|row| row.iter().map(|&a| F::from(a)).collect()).collect())
+  }
+
+  #[test]
+  fn arithmetic() {
+    let a = matrix(&[&[1, 2, 3], &[4, 5, 6]]);
+    let b = matrix(&[&[7, 8], &[9, 10], &[11, 12]]);
+    assert_eq!(a.clone() * b.clone(), matrix(&[&[58, 64], &[139, 154]]));
+    assert_eq!(a.transpose(), matrix(&[&[1, 4], &[2, 5], &[3, 6]]));
+    assert_eq!((a.clone() * b.clone()).transpose(), b.transpose() * a.transpose());
+    assert_eq!(a.clone() - a.clone(), Matrix::zero(2, 3));
+    assert_eq!(a.clone() * F::from(2), a.clone() + a.clone());
+    assert_eq!(a.mul_vec(&[F::ONE, F::ZERO, F::ONE]), vec![F::from(4), F::from(10)]);
+    assert_eq!(Matrix::identity(2) * a.clone(), a);
+  }
+
+  #[test]
+  fn elimination() {
+    let a = matrix(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]]);
+    assert_eq!(a.rank(), 2);
+    assert_eq!(a.determinant(), F::ZERO);
+    assert_eq!(a.inverse(), None);
+    assert_eq!(a.row_echelon(), matrix(&[&[1, 0, 100], &[0, 1, 2], &[0, 0, 0]]));
+
+    // x - 2y + z = 0
+    let kernel = a.kernel();
+    assert_eq!(kernel, vec![vec![F::ONE, -F::from(2), F::ONE]]);
+    assert_eq!(a.mul_vec(&kernel[0]), vec![F::ZERO; 3]);
+
+    let b = [F::from(6), F::from(15), F::from(24)];
+    let x = a.solve(&b).unwrap();
+    assert_eq!(a.mul_vec(&x), b);
+    assert_eq!(a.solve(&[F::ONE, F::ZERO, F::ZERO]), None);
+
+    let b = matrix(&[&[0, 2, 1], &[1, 1, 0], &[3, 0, 1]]);
+    assert_eq!(b.determinant(), -F::from(5));
+    assert_eq!(b.inverse().unwrap() * b.clone(), Matrix::identity(3));
+    assert_eq!(Matrix::<F>::zero(2, 3).rank(), 0);
+    assert_eq!(Matrix::<F>::zero(2, 3).kernel().len(), 3);
+  }
+
+  #[test]
+  fn random_matrices() {
+    let mut rng = thread_rng();
+    for _ in 0..20 {
+      let a = Matrix::from_fn(4, 4, |_, _| rng.gen::<F>());
+      let b = Matrix::from_fn(4, 4, |_, _| rng.gen::<F>());
+      assert_eq!((a.clone() * b.clone()).determinant(), a.determinant() * b.determinant());
+      assert_eq!(a.transpose().determinant(), a.determinant());
+      match a.inverse() {
+        Some(inverse) => assert_eq!(a.clone() * inverse, Matrix::identity(4)),
+        None => assert_eq!(a.determinant(), F::ZERO),
+      }
+      // rank-nullity
+      let c = Matrix::from_fn(3, 5, |_, _| rng.gen::<F>());
+      assert_eq!(c.rank() + c.kernel().len(), 5);
+    }
+  }
+
+  #[test]
+  fn mds() {
+    // a Cauchy matrix 1 / (x_i - y_j) with distinct x_i, y_j is MDS
+    let cauchy =
+      Matrix::<F>::from_fn(3, 3, |i, j| (F::from(i) - F::from(j + 3)).inverse().unwrap());
+    assert!(cauchy.is_mds());
+    assert!(!Matrix::<F>::identity(3).is_mds());
+    assert!(!matrix(&[&[1, 1], &[1, 1]]).is_mds());
+  }
+}
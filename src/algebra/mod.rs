@@ -2,8 +2,13 @@
 //! Defines algebraic types:
 //! - [`group::Group`]: Group
 //! - [`field::Field`]: Field
+//! - [`matrix::Matrix`]: Matrix over a field
+//! - [`number_theory`]: GCDs, modular inverses, CRT and primality tests on integers
 pub mod field;
 pub mod group;
+pub(crate) mod limbs;
+pub mod matrix;
+pub mod number_theory;
 
 #[const_trait]
 /// Trait defining order of algebraic structure
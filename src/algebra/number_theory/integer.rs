@@ -0,0 +1,148 @@
+//! The [`Integer`] trait abstracting the unsigned integers the number theoretic algorithms run on,
+//! implemented for `u64` and [`crypto_bigint::Uint`].
+use std::fmt::Debug;
+
+use crypto_bigint::{NonZero, Uint, Word};
+use rand::Rng;
+
+/// Fixed width unsigned integers. Only the handful of operations the algorithms of
+/// [`number_theory`](super) need are required, everything else is built on top of them.
+pub trait Integer: Copy + Debug + Eq + Ord {
+  /// Zero.
+  const ZERO: Self;
+  /// One.
+  const ONE: Self;
+  /// Width of the integer in bits.
+  const BITS: u32;
+
+  /// Converts a `u64`.
+  fn from_u64(n: u64) -> Self;
+
+  /// Returns the number of significant bits, zero for zero.
+  fn bits(&self) -> u32;
+
+  /// Returns bit `index`, zero being the least significant bit.
+  fn bit(&self, index: u32) -> bool;
+
+  /// Returns the number of trailing zero bits, [`Integer::BITS`] for zero.
+  fn trailing_zeros(&self) -> u32;
+
+  /// Shifts right by `shift < BITS` bits.
+  fn shr(&self, shift: u32) -> Self;
+
+  /// Shifts left by `shift < BITS` bits, dropping the bits shifted out.
+  fn shl(&self, shift: u32) -> Self;
+
+  /// Adds modulo `2^BITS`.
+  fn wrapping_add(&self, rhs: &Self) -> Self;
+
+  /// Subtracts modulo `2^BITS`.
+  fn wrapping_sub(&self, rhs: &Self) -> Self;
+
+  /// Multiplies modulo `2^BITS`.
+  fn wrapping_mul(&self, rhs: &Self) -> Self;
+
+  /// Returns the quotient and the remainder of the division by `rhs`.
+  ///
+  /// ## Panics
+  /// If `rhs` is zero.
+  fn div_rem(&self, rhs: &Self) -> (Self, Self);
+
+  /// Computes `self * rhs mod modulus` without overflowing.
+  ///
+  /// ## Panics
+  /// If `modulus` is zero.
+  fn mul_mod(&self, rhs: &Self, modulus: &Self) -> Self;
+
+  /// Samples an integer of at most `bits` bits uniformly.
+  fn random_bits<R: Rng + ?Sized>(rng: &mut R, bits: u32) -> Self;
+
+  /// Returns whether the integer is zero.
+  fn is_zero(&self) -> bool { *self == Self::ZERO }
+
+  /// Returns whether the integer is odd.
+  fn is_odd(&self) -> bool { self.bit(0) }
+
+  /// Returns the remainder of the division by `rhs`.
+  fn rem(&self, rhs: &Self) -> Self { self.div_rem(rhs).1 }
+
+  /// Returns the quotient of the division by `rhs`.
+  fn div(&self, rhs: &Self) -> Self { self.div_rem(rhs).0 }
+}
+
+impl Integer for u64 {
+  const BITS: u32 = u64::BITS;
+  const ONE: Self = 1;
+  const ZERO: Self = 0;
+
+  fn from_u64(n: u64) -> Self { n }
+
+  fn bits(&self) -> u32 { u64::BITS - self.leading_zeros() }
+
+  fn bit(&self, index: u32) -> bool { (self >> index) & 1 == 1 }
+
+  fn trailing_zeros(&self) -> u32 { u64::trailing_zeros(*self) }
+
+  fn shr(&self, shift: u32) -> Self { self >> shift }
+
+  fn shl(&self, shift: u32) -> Self { self << shift }
+
+  fn wrapping_add(&self, rhs: &Self) -> Self { u64::wrapping_add(*self, *rhs) }
+
+  fn wrapping_sub(&self, rhs: &Self) -> Self { u64::wrapping_sub(*self, *rhs) }
+
+  fn wrapping_mul(&self, rhs: &Self) -> Self { u64::wrapping_mul(*self, *rhs) }
+
+  fn div_rem(&self, rhs: &Self) -> (Self, Self) { (self / rhs, self % rhs) }
+
+  fn mul_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+    (*self as u128 * *rhs as u128 % *modulus as u128) as u64
+  }
+
+  fn random_bits<R: Rng + ?Sized>(rng: &mut R, bits: u32) -> Self {
+    match bits {
+      0 => 0,
+      _ => rng.gen::<u64>() >> (u64::BITS - bits.min(u64::BITS)),
+    }
+  }
+}
+
+impl<const LIMBS: usize> Integer for Uint<LIMBS> {
+  const BITS: u32 = Uint::<LIMBS>::BITS;
+  const ONE: Self = Uint::ONE;
+  const ZERO: Self = Uint::ZERO;
+
+  fn from_u64(n: u64) -> Self { Uint::from_u64(n) }
+
+  fn bits(&self) -> u32 { self.bits_vartime() }
+
+  fn bit(&self, index: u32) -> bool { self.bit_vartime(index) }
+
+  fn trailing_zeros(&self) -> u32 { self.trailing_zeros_vartime() }
+
+  fn shr(&self, shift: u32) -> Self { self.shr_vartime(shift) }
+
+  fn shl(&self, shift: u32) -> Self { self.shl_vartime(shift) }
+
+  fn wrapping_add(&self, rhs: &Self) -> Self { Uint::wrapping_add(self, rhs) }
+
+  fn wrapping_sub(&self, rhs: &Self) -> Self { Uint::wrapping_sub(self, rhs) }
+
+  fn wrapping_mul(&self, rhs: &Self) -> Self { Uint::wrapping_mul(self, rhs) }
+
+  fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+    Uint::div_rem(self, &NonZero::new(*rhs).expect("division by zero"))
+  }
+
+  fn mul_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+    self.mul_mod_vartime(rhs, &NonZero::new(*modulus).expect("modulus should be nonzero"))
+  }
+
+  fn random_bits<R: Rng + ?Sized>(rng: &mut R, bits: u32) -> Self {
+    let words: [Word; LIMBS] = std::array::from_fn(|_| rng.gen());
+    match bits {
+      0 => Self::ZERO,
+      _ => Uint::from_words(words).shr_vartime(Self::BITS - bits.min(Self::BITS)),
+    }
+  }
+}
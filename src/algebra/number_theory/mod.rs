@@ -0,0 +1,633 @@
+//! Elementary number theory on unsigned integers: greatest common divisors, modular inverses, the
+//! Chinese remainder theorem, the Jacobi symbol, modular square roots, factorization by trial
+//! division, primality testing and prime generation.
+//!
+//! Every algorithm is generic over [`Integer`], which is implemented for `u64` and for the
+//! [`crypto_bigint::Uint`]s backing [`BigPrimeField`](crate::algebra::field::big_prime), so the
+//! same code checks toy RSA primes and 256-bit field moduli.
+//!
+//! ## Primality
+//! [`is_prime`] is the [Baillie–PSW](https://en.wikipedia.org/wiki/Baillie%E2%80%93PSW_primality_test)
+//! test: trial division by small primes, a [Miller–Rabin](https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test)
+//! test to base 2 and a strong [Lucas](https://en.wikipedia.org/wiki/Lucas_pseudoprime) test. The
+//! pseudoprimes of the two tests are believed to be disjoint, no composite passing both is known,
+//! and none exists below `2^64`, so the test is deterministic for `u64`.
+//!
+//! [`is_probable_prime`] instead runs Miller–Rabin with random bases, each round letting a
+//! composite through with probability at most `1/4`.
+use rand::Rng;
+
+pub mod integer;
+
+pub use integer::Integer;
+
+/// The primes below 100, used for trial division before the probabilistic tests.
+const SMALL_PRIMES: [u64; 25] =
+  [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
+
+/// Greatest common divisor of `a` and `b` by Euclid's algorithm.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+  let (mut a, mut b) = (a, b);
+  while !b.is_zero() {
+    (a, b) = (b, a.rem(&b));
+  }
+  a
+}
+
+/// Least common multiple of `a` and `b`.
+///
+/// ## Panics
+/// If the result does not fit in `T`.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+  if a.is_zero() || b.is_zero() {
+    return T::ZERO;
+  }
+  checked_mul(a.div(&gcd(a, b)), b).expect("lcm overflows")
+}
+
+/// Extended Euclidean algorithm: returns `(g, x, y)` with `g = gcd(a, b)` and
+///
+/// $$ a x - b y = g, \quad 0 \leq x \leq b / g, \quad 0 \leq y \leq a / g. $$
+///
+/// The Bézout coefficients of the usual $a s + b t = g$ have opposite signs, which unsigned
+/// integers can't hold, so their magnitudes are tracked instead. The signs alternate at every
+/// step, and a negative `s` is shifted by $(b / g, a / g)$ to make both coefficients nonnegative.
+///
+/// ## Panics
+/// If `a` is zero and `b` is not, as `-b y = b` has no solution with `y >= 0`.
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> (T, T, T) {
+  assert!(!a.is_zero() || b.is_zero(), "a should be nonzero");
+  let (mut r0, mut r1) = (a, b);
+  // |s_i| and |t_i| of r_i = s_i a + t_i b, where s_i has the sign of (-1)^i and t_i the opposite
+  let (mut s0, mut s1) = (T::ONE, T::ZERO);
+  let (mut t0, mut t1) = (T::ZERO, T::ONE);
+  let mut odd = false;
+  while !r1.is_zero() {
+    let (q, r) = r0.div_rem(&r1);
+    (r0, r1) = (r1, r);
+    (s0, s1) = (s1, s0.wrapping_add(&q.wrapping_mul(&s1)));
+    (t0, t1) = (t1, t0.wrapping_add(&q.wrapping_mul(&t1)));
+    odd = !odd;
+  }
+  match odd {
+    // g = s a - t b
+    false => (r0, s0, t0),
+    // g = -s a + t b = (b/g - s) a - (a/g - t) b
+    true => (r0, b.div(&r0).wrapping_sub(&s0), a.div(&r0).wrapping_sub(&t0)),
+  }
+}
+
+/// Returns the inverse of `a` modulo `m`, or `None` if they are not coprime.
+///
+/// ## Panics
+/// If `m` is zero.
+pub fn mod_inverse<T: Integer>(a: T, m: T) -> Option<T> {
+  let a = a.rem(&m);
+  if m == T::ONE {
+    return Some(T::ZERO);
+  }
+  if a.is_zero() {
+    return None;
+  }
+  let (g, x, _) = extended_gcd(a, m);
+  (g == T::ONE).then(|| x.rem(&m))
+}
+
+/// Computes `base^exponent mod m` by square-and-multiply.
+///
+/// ## Panics
+/// If `m` is zero.
+pub fn pow_mod<T: Integer>(base: T, exponent: T, m: T) -> T {
+  let base = base.rem(&m);
+  let mut result = T::ONE.rem(&m);
+  for i in (0..exponent.bits()).rev() {
+    result = result.mul_mod(&result, &m);
+    if exponent.bit(i) {
+      result = result.mul_mod(&base, &m);
+    }
+  }
+  result
+}
+
+/// Chinese remainder theorem: returns `(x, m)` such that the solutions of all the congruences
+/// `x = a_i (mod m_i)` given as `(a_i, m_i)` are exactly `x (mod m)`, with `m` the lcm of the
+/// moduli. The moduli don't have to be coprime, and `None` is returned if the congruences are
+/// inconsistent.
+///
+/// ## Panics
+/// - If a modulus is zero.
+/// - If the lcm of the moduli does not fit in `T`.
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Option<(T, T)> {
+  congruences.iter().try_fold((T::ZERO, T::ONE), |(a, m), &(b, n)| {
+    // x = a + m k with m k = b - a (mod n), solvable iff g = gcd(m, n) divides b - a
+    let g = gcd(m, n);
+    let difference = sub_mod(b.rem(&n), a.rem(&n), &n);
+    let (difference, remainder) = difference.div_rem(&g);
+    if !remainder.is_zero() {
+      return None;
+    }
+    let reduced = n.div(&g);
+    let inverse = mod_inverse(m.div(&g), reduced).expect("m / g and n / g are coprime");
+    let k = difference.mul_mod(&inverse, &reduced);
+    let modulus = checked_mul(m.div(&g), n).expect("lcm of the moduli overflows");
+    Some((a.wrapping_add(&m.wrapping_mul(&k)), modulus))
+  })
+}
+
+/// The [Jacobi symbol](https://en.wikipedia.org/wiki/Jacobi_symbol) `(a / n)` for odd `n`, which is
+/// the Legendre symbol when `n` is prime: `1` for a nonzero square, `-1` for a non-square and `0`
+/// when `a` is a multiple of `n`. For composite `n`, `-1` still proves that `a` is not a square
+/// modulo `n`, but `1` does not prove that it is.
+///
+/// Computed by quadratic reciprocity, like the Euclidean algorithm.
+///
+/// ## Panics
+/// If `n` is even.
+pub fn jacobi<T: Integer>(a: T, n: T) -> i8 {
+  assert!(n.is_odd(), "the Jacobi symbol is only defined for odd n");
+  let (mut a, mut n) = (a.rem(&n), n);
+  let mut result = 1;
+  while !a.is_zero() {
+    // (2 / n) = -1 iff n = 3, 5 (mod 8)
+    let twos = a.trailing_zeros();
+    a = a.shr(twos);
+    if twos % 2 == 1 && n.bit(1) != n.bit(2) {
+      result = -result;
+    }
+    // (a / n) = -(n / a) iff a = n = 3 (mod 4)
+    if a.bit(1) && n.bit(1) {
+      result = -result;
+    }
+    (a, n) = (n.rem(&a), a);
+  }
+  match n == T::ONE {
+    true => result,
+    false => 0,
+  }
+}
+
+/// A square root of `a` modulo the odd prime `p` by the
+/// [Tonelli–Shanks algorithm](https://en.wikipedia.org/wiki/Tonelli%E2%80%93Shanks_algorithm), or
+/// `None` if `a` is not a square. Which of the two roots is returned is unspecified.
+///
+/// ## Panics
+/// If `p` is even. The result is meaningless if `p` is not a prime.
+pub fn sqrt_mod<T: Integer>(a: T, p: T) -> Option<T> {
+  let a = a.rem(&p);
+  match jacobi(a, p) {
+    0 => return Some(T::ZERO),
+    -1 => return None,
+    _ => {},
+  }
+  // p - 1 = q 2^s with q odd
+  let p_minus_one = p.wrapping_sub(&T::ONE);
+  let s = p_minus_one.trailing_zeros();
+  let q = p_minus_one.shr(s);
+  let non_residue = (2..).map(T::from_u64).find(|&z| jacobi(z, p) == -1).unwrap();
+
+  // invariant: root^2 = a t, with t of order dividing 2^m and c of order 2^m
+  let mut c = pow_mod(non_residue, q, p);
+  let mut t = pow_mod(a, q, p);
+  let mut root = pow_mod(a, q.wrapping_add(&T::ONE).shr(1), p);
+  let mut m = s;
+  while t != T::ONE {
+    // the order 2^i of t, with i < m
+    let i = std::iter::successors(Some(t), |&x| Some(x.mul_mod(&x, &p)))
+      .position(|x| x == T::ONE)
+      .unwrap() as u32;
+    let b = (0..m - i - 1).fold(c, |b, _| b.mul_mod(&b, &p));
+    root = root.mul_mod(&b, &p);
+    c = b.mul_mod(&b, &p);
+    t = t.mul_mod(&c, &p);
+    m = i;
+  }
+  Some(root)
+}
+
+/// Integer square root `floor(sqrt(n))` by Newton's method.
+pub fn isqrt<T: Integer>(n: T) -> T {
+  if n <= T::ONE {
+    return n;
+  }
+  // start above the root, after which Newton's iterates decrease until they reach it
+  let mut x = T::ONE.shl(n.bits().div_ceil(2));
+  loop {
+    let y = x.wrapping_add(&n.div(&x)).shr(1);
+    if y >= x {
+      return x;
+    }
+    x = y;
+  }
+}
+
+/// Prime factorization of `n` by trial division, as `(prime, exponent)` pairs in increasing order,
+/// empty for zero and one.
+///
+/// Trial division stops at the square root of the unfactored part, so this takes time about the
+/// square root of the second largest prime factor of `n`. It is only meant for the orders of toy
+/// groups.
+pub fn factorize<T: Integer>(n: T) -> Vec<(T, u32)> {
+  let mut n = n;
+  let mut factors = Vec::new();
+  let mut q = T::from_u64(2);
+  while checked_mul(q, q).is_some_and(|square| square <= n) {
+    let mut e = 0;
+    loop {
+      let (quotient, remainder) = n.div_rem(&q);
+      if !remainder.is_zero() {
+        break;
+      }
+      n = quotient;
+      e += 1;
+    }
+    if e > 0 {
+      factors.push((q, e));
+    }
+    q = q.wrapping_add(&T::ONE);
+  }
+  if n > T::ONE {
+    factors.push((n, 1));
+  }
+  factors
+}
+
+/// Strong probable prime test to base `base`: writing `n - 1 = d 2^s` with `d` odd, a prime `n`
+/// has either `base^d = 1` or `base^{d 2^r} = -1` for some `r < s`. A composite passes for at most
+/// a quarter of the bases.
+///
+/// ## Panics
+/// If `n` is even or less than three.
+pub fn miller_rabin<T: Integer>(n: T, base: T) -> bool {
+  assert!(n.is_odd() && n > T::ONE, "n should be an odd integer greater than one");
+  let n_minus_one = n.wrapping_sub(&T::ONE);
+  let base = base.rem(&n);
+  if base <= T::ONE || base == n_minus_one {
+    return true;
+  }
+
+  let s = n_minus_one.trailing_zeros();
+  let mut x = pow_mod(base, n_minus_one.shr(s), n);
+  if x == T::ONE || x == n_minus_one {
+    return true;
+  }
+  for _ in 1..s {
+    x = x.mul_mod(&x, &n);
+    if x == n_minus_one {
+      return true;
+    }
+  }
+  false
+}
+
+/// Strong Lucas probable prime test with the parameters of Selfridge's method A: `D` is the first
+/// of `5, -7, 9, -11, ...` with Jacobi symbol `(D / n) = -1`, `P = 1` and `Q = (1 - D) / 4`.
+/// Writing `n + 1 = d 2^s` with `d` odd, a prime `n` has either $U_d = 0$ or $V_{d 2^r} = 0$ for
+/// some `r < s`.
+///
+/// ## Panics
+/// If `n` is even or a perfect square, for which no such `D` exists.
+pub fn strong_lucas<T: Integer>(n: T) -> bool {
+  assert!(n.is_odd(), "n should be odd");
+  assert!(isqrt(n).wrapping_mul(&isqrt(n)) != n, "n should not be a perfect square");
+
+  // D = ±magnitude and Q = (1 - D) / 4, both modulo n
+  let mut magnitude = 5;
+  let mut negative = false;
+  let (d, q) = loop {
+    let m = T::from_u64(magnitude).rem(&n);
+    let d = if negative { sub_mod(T::ZERO, m, &n) } else { m };
+    match jacobi(d, n) {
+      -1 => {
+        let quarter = if negative { (magnitude + 1) / 4 } else { (magnitude - 1) / 4 };
+        let quarter = T::from_u64(quarter).rem(&n);
+        let q = if negative { quarter } else { sub_mod(T::ZERO, quarter, &n) };
+        break (d, q);
+      },
+      // a factor of n, unless it is n itself
+      0 if T::from_u64(magnitude) != n => return false,
+      _ => {},
+    }
+    magnitude += 2;
+    negative = !negative;
+  };
+
+  let n_plus_one = n.wrapping_add(&T::ONE);
+  let s = n_plus_one.trailing_zeros();
+  let exponent = n_plus_one.shr(s);
+
+  // U_1 = 1, V_1 = P = 1, then double the index and add one for every bit of the exponent
+  let (mut u, mut v, mut q_k) = (T::ONE, T::ONE, q);
+  for i in (0..exponent.bits() - 1).rev() {
+    // U_2k = U_k V_k, V_2k = V_k^2 - 2 Q^k
+    u = u.mul_mod(&v, &n);
+    v = sub_mod(v.mul_mod(&v, &n), add_mod(q_k, q_k, &n), &n);
+    q_k = q_k.mul_mod(&q_k, &n);
+    if exponent.bit(i) {
+      // U_{k+1} = (P U_k + V_k) / 2, V_{k+1} = (D U_k + P V_k) / 2
+      (u, v) = (half_mod(add_mod(u, v, &n), &n), half_mod(add_mod(d.mul_mod(&u, &n), v, &n), &n));
+      q_k = q_k.mul_mod(&q, &n);
+    }
+  }
+
+  if u.is_zero() || v.is_zero() {
+    return true;
+  }
+  for _ in 1..s {
+    v = sub_mod(v.mul_mod(&v, &n), add_mod(q_k, q_k, &n), &n);
+    if v.is_zero() {
+      return true;
+    }
+    q_k = q_k.mul_mod(&q_k, &n);
+  }
+  false
+}
+
+/// The Baillie–PSW primality test, deterministic for `u64`.
+pub fn is_prime<T: Integer>(n: T) -> bool {
+  if let Some(result) = trial_division(n) {
+    return result;
+  }
+  if !miller_rabin(n, T::from_u64(2)) {
+    return false;
+  }
+  let root = isqrt(n);
+  root.wrapping_mul(&root) != n && strong_lucas(n)
+}
+
+/// Miller–Rabin primality test with `rounds` random bases: a prime is always accepted, while a
+/// composite is accepted with probability at most $4^{-\text{rounds}}$.
+pub fn is_probable_prime<T: Integer, R: Rng + ?Sized>(n: T, rounds: usize, rng: &mut R) -> bool {
+  if let Some(result) = trial_division(n) {
+    return result;
+  }
+  // bases in 2..n-1
+  let bound = n.wrapping_sub(&T::from_u64(3));
+  (0..rounds).all(|_| miller_rabin(n, random_below(bound, rng).wrapping_add(&T::from_u64(2))))
+}
+
+/// Samples an integer in `0..bound` uniformly by rejection.
+///
+/// ## Panics
+/// If `bound` is zero.
+pub fn random_below<T: Integer, R: Rng + ?Sized>(bound: T, rng: &mut R) -> T {
+  assert!(!bound.is_zero(), "bound should be nonzero");
+  loop {
+    let candidate = T::random_bits(rng, bound.bits());
+    if candidate < bound {
+      return candidate;
+    }
+  }
+}
+
+/// Samples a random prime of exactly `bits` bits, i.e. in `2^{bits-1}..2^bits`.
+///
+/// ## Panics
+/// If `bits` is less than two or larger than the width of `T`.
+pub fn random_prime<T: Integer, R: Rng + ?Sized>(bits: u32, rng: &mut R) -> T {
+  assert!((2..=T::BITS).contains(&bits), "bits should be in 2..={}", T::BITS);
+  let top = T::ONE.shl(bits - 1);
+  loop {
+    // set the top bit for the size and the bottom bit as even numbers are not prime
+    let mut candidate = T::random_bits(rng, bits);
+    if !candidate.bit(bits - 1) {
+      candidate = candidate.wrapping_add(&top);
+    }
+    if !candidate.is_odd() {
+      candidate = candidate.wrapping_add(&T::ONE);
+    }
+    if is_prime(candidate) {
+      return candidate;
+    }
+  }
+}
+
+/// Samples a random safe prime `p = 2q + 1` of exactly `bits` bits, where `q` is also prime, so
+/// that the multiplicative group modulo `p` has a subgroup of large prime order `q`.
+///
+/// ## Panics
+/// If `bits` is less than three or larger than the width of `T`.
+pub fn random_safe_prime<T: Integer, R: Rng + ?Sized>(bits: u32, rng: &mut R) -> T {
+  assert!((3..=T::BITS).contains(&bits), "bits should be in 3..={}", T::BITS);
+  loop {
+    let q: T = random_prime(bits - 1, rng);
+    let p = q.shl(1).wrapping_add(&T::ONE);
+    if is_prime(p) {
+      return p;
+    }
+  }
+}
+
+/// Decides the primality of `n` by trial division by the [`SMALL_PRIMES`], or returns `None` if it
+/// has no small factor and is too large to be decided that way.
+fn trial_division<T: Integer>(n: T) -> Option<bool> {
+  if n < T::from_u64(2) {
+    return Some(false);
+  }
+  for p in SMALL_PRIMES.map(T::from_u64) {
+    if n == p {
+      return Some(true);
+    }
+    if n.rem(&p).is_zero() {
+      return Some(false);
+    }
+  }
+  // no factor below 100
+  (n < T::from_u64(100 * 100)).then_some(true)
+}
+
+/// Returns `a * b`, or `None` if it overflows.
+fn checked_mul<T: Integer>(a: T, b: T) -> Option<T> {
+  let product = a.wrapping_mul(&b);
+  (a.is_zero() || product.div(&a) == b).then_some(product)
+}
+
+/// Computes `a + b mod n` for `a, b < n`.
+fn add_mod<T: Integer>(a: T, b: T, n: &T) -> T {
+  let sum = a.wrapping_add(&b);
+  // the sum went past n if it is at least n or overflowed
+  match sum >= *n || sum < a {
+    true => sum.wrapping_sub(n),
+    false => sum,
+  }
+}
+
+/// Computes `a - b mod n` for `a, b < n`.
+fn sub_mod<T: Integer>(a: T, b: T, n: &T) -> T {
+  match a >= b {
+    true => a.wrapping_sub(&b),
+    false => a.wrapping_sub(&b).wrapping_add(n),
+  }
+}
+
+/// Computes `a / 2 mod n` for `a < n` and odd `n`.
+fn half_mod<T: Integer>(a: T, n: &T) -> T {
+  match a.is_odd() {
+    // (a + n) / 2 without overflowing
+    true => a.shr(1).wrapping_add(&n.shr(1)).wrapping_add(&T::ONE),
+    false => a.shr(1),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto_bigint::{U128, U256};
+  use rand::thread_rng;
+  use rstest::rstest;
+
+  use super::*;
+
+  fn is_prime_naive(n: u64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+  }
+
+  #[test]
+  fn euclid() {
+    assert_eq!(gcd(84_u64, 36), 12);
+    assert_eq!(gcd(0_u64, 5), 5);
+    assert_eq!(lcm(4_u64, 6), 12);
+    for (a, b) in [(240_u64, 46), (46, 240), (17, 5), (5, 5), (7, 0), (1, u64::MAX), (u64::MAX, 3)]
+    {
+      let (g, x, y) = extended_gcd(a, b);
+      assert_eq!(g, gcd(a, b));
+      assert_eq!(a as u128 * x as u128 - b as u128 * y as u128, g as u128);
+    }
+
+    assert_eq!(mod_inverse(3_u64, 8), Some(3));
+    assert_eq!(mod_inverse(5_u64, 24), Some(5));
+    assert_eq!(mod_inverse(6_u64, 9), None);
+    assert_eq!(mod_inverse(0_u64, 1), Some(0));
+    let p = U256::from_be_hex("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+    let a = U256::from_u64(123_456_789);
+    let inverse = mod_inverse(a, p).unwrap();
+    assert_eq!(Integer::mul_mod(&a, &inverse, &p), U256::ONE);
+    assert_eq!(pow_mod(a, p.wrapping_sub(&U256::from_u64(2)), p), inverse);
+  }
+
+  #[rstest]
+  #[case(&[(2, 3), (3, 5), (2, 7)], Some((23, 105)))]
+  #[case(&[(1, 4), (3, 6)], Some((9, 12)))]
+  #[case(&[(1, 4), (2, 6)], None)]
+  #[case(&[], Some((0, 1)))]
+  fn chinese_remainder(#[case] congruences: &[(u64, u64)], #[case] expected: Option<(u64, u64)>) {
+    assert_eq!(crt(congruences), expected);
+  }
+
+  #[test]
+  fn jacobi_symbol() {
+    // the Legendre symbol by Euler's criterion
+    for p in [3_u64, 5, 7, 11, 101] {
+      for a in 0..p {
+        let euler = match pow_mod(a, (p - 1) / 2, p) {
+          0 => 0,
+          1 => 1,
+          _ => -1,
+        };
+        assert_eq!(jacobi(a, p), euler);
+      }
+    }
+    // multiplicative in n
+    for a in 0..45_u64 {
+      assert_eq!(jacobi(a, 45), jacobi(a, 5) * jacobi(a, 9));
+    }
+    // 2 is a square modulo 15 for the Jacobi symbol, but not actually a square
+    assert_eq!(jacobi(2_u64, 15), 1);
+  }
+
+  #[test]
+  fn modular_square_root() {
+    // 3 (mod 4), 5 (mod 8) and 1 (mod 16) exercise 1, 2 and 4 rounds of halving the order
+    for p in [3_u64, 5, 7, 13, 17, 41, 97, 101, 257, 65537] {
+      for a in 0..p {
+        match sqrt_mod(a, p) {
+          Some(root) => assert_eq!(root * root % p, a),
+          None => assert_eq!(jacobi(a, p), -1),
+        }
+      }
+    }
+    let p = (1_u64 << 61) - 1;
+    let square = 123_456_789_u64.pow(2);
+    let root = sqrt_mod(square, p).unwrap();
+    assert_eq!(Integer::mul_mod(&root, &root, &p), square);
+    // the BLS12-381 scalar field modulus, 1 (mod 2^32)
+    let p = U256::from_be_hex("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");
+    let square = Integer::mul_mod(&U256::from_u64(u64::MAX), &U256::from_u64(u64::MAX), &p);
+    let root = sqrt_mod(square, p).unwrap();
+    assert_eq!(Integer::mul_mod(&root, &root, &p), square);
+  }
+
+  #[test]
+  fn square_root() {
+    for n in 0..10_000_u64 {
+      let root = isqrt(n);
+      assert!(root * root <= n && (root + 1) * (root + 1) > n);
+    }
+    assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    assert_eq!(isqrt(U128::MAX), U128::from_u64(u64::MAX));
+  }
+
+  #[test]
+  fn factorization() {
+    assert_eq!(factorize(360_u64), vec![(2, 3), (3, 2), (5, 1)]);
+    assert_eq!(factorize(17_u64), vec![(17, 1)]);
+    assert_eq!(factorize(1_u64), vec![]);
+    for n in 1..2_000_u64 {
+      let factors = factorize(n);
+      assert!(factors.iter().all(|&(q, _)| is_prime(q)));
+      assert_eq!(factors.iter().map(|&(q, e)| q.pow(e)).product::<u64>(), n);
+    }
+    // 2^64 - 1 = 3 * 5 * 17 * 257 * 641 * 65537 * 6700417
+    assert_eq!(factorize(u64::MAX).last(), Some(&(6_700_417, 1)));
+    assert_eq!(factorize(U128::from_u64(360)).len(), 3);
+  }
+
+  #[test]
+  fn primality() {
+    for n in 0..20_000_u64 {
+      assert_eq!(is_prime(n), is_prime_naive(n), "{n}");
+    }
+
+    // Carmichael numbers, strong pseudoprimes to base 2 and strong Lucas pseudoprimes
+    for n in
+      [561_u64, 41_041, 2_047, 3_215_031_751, 5_459, 5_777, 10_877, 3_825_123_056_546_413_051]
+    {
+      assert!(!is_prime(n), "{n}");
+    }
+    assert!(!miller_rabin(561_u64, 2));
+    assert!(miller_rabin(2_047_u64, 2));
+    assert!(strong_lucas(5_459_u64));
+
+    for p in [(1_u64 << 61) - 1, u64::MAX - 58, 1_000_003] {
+      assert!(is_prime(p));
+      assert!(is_probable_prime(p, 20, &mut thread_rng()));
+    }
+    assert!(!is_prime(u64::MAX));
+
+    let bn254 =
+      U256::from_be_hex("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+    assert!(is_prime(bn254));
+    assert!(!is_prime(bn254.wrapping_add(&U256::from_u64(2))));
+    let mersenne = U128::MAX.shr_vartime(1);
+    assert!(is_prime(mersenne));
+  }
+
+  #[test]
+  fn prime_generation() {
+    let mut rng = thread_rng();
+    for bits in [2, 8, 32, 64] {
+      let p: u64 = random_prime(bits, &mut rng);
+      assert_eq!(p.bits(), bits);
+      assert!(bits > 32 || is_prime_naive(p));
+      assert!(is_prime(p));
+    }
+    let p: U128 = random_prime(128, &mut rng);
+    assert_eq!(p.bits(), 128);
+    assert!(is_probable_prime(p, 20, &mut rng));
+
+    let p: u64 = random_safe_prime(40, &mut rng);
+    assert_eq!(p.bits(), 40);
+    assert!(is_prime(p) && is_prime(p / 2));
+    for _ in 0..100 {
+      assert!(random_below(10_u64, &mut rng) < 10);
+    }
+  }
+}
@@ -2,10 +2,8 @@
 
 use std::array;
 
-use itertools::Itertools;
-
 use super::*;
-use crate::algebra::field::FiniteField;
+use crate::algebra::{field::FiniteField, matrix::Matrix};
 
 // TODO: We should allow for arbitrary data in the message so long as it can be
 // converted into an element of a prime field and decoded the same way.
@@ -54,56 +52,14 @@ impl<const K: usize, const P: usize> Message<K, P> {
   /// Decodes the message from a [`Codeword`].
   pub fn decode<const M: usize>(codeword: Codeword<M, K, P>) -> Self {
     assert_ge::<M, K>();
-    let x_values: [PrimeField<P>; K] = {
-      let mut array = [PrimeField::<P>::ZERO; K];
-      for (i, x) in codeword.data.iter().map(|c| c.x).take(K).enumerate() {
-        array[i] = x;
-      }
-      array
-    };
-
-    let y_values: [PrimeField<P>; K] = {
-      let mut array = [PrimeField::<P>::ZERO; K];
-      for (i, y) in codeword.data.iter().map(|c| c.y).take(K).enumerate() {
-        array[i] = y;
-      }
-      array
-    };
-
-    let mut data = [PrimeField::<P>::ZERO; K];
-
-    #[allow(clippy::needless_range_loop)]
-    // i is the degree of the monomial.
-    for i in 0..K {
-      for j in 0..K {
-        let x_combinations: PrimeField<P> = if i % 2 == 1 {
-          PrimeField::<P>::ZERO - PrimeField::<P>::ONE
-        } else {
-          PrimeField::<P>::ONE
-        } * x_values
-          .iter()
-          .enumerate()
-          .filter(|&(index, _)| index != j)
-          .map(|(_, x)| x)
-          .combinations(K - 1 - i)
-          .map(|comb| comb.into_iter().copied().product::<PrimeField<P>>())
-          .sum::<PrimeField<P>>();
-        let y_combinations = y_values[j];
-        let numerator = x_combinations * y_combinations;
-
-        // this could be put into the x_combinations iter above.
-        let mut denominator = PrimeField::ONE; // x_values[i];
-        for k in 0..K {
-          if k == j {
-            continue;
-          }
-          denominator *= x_values[k] - x_values[j];
-        }
-
-        data[i] += numerator / denominator;
-      }
-    }
-    Message { data }
+    let (x_values, y_values): (Vec<_>, Vec<_>) =
+      codeword.data.iter().take(K).map(|c| (c.x, c.y)).unzip();
+
+    // the coefficients c solve V c = y for the Vandermonde matrix V of the evaluation points
+    let coefficients = Matrix::vandermonde(&x_values, K)
+      .solve(&y_values)
+      .expect("evaluation points should be distinct");
+    Message { data: array::from_fn(|i| coefficients[i]) }
   }
 }
 
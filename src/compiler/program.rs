@@ -14,7 +14,7 @@ use super::{
   utils::get_product_key,
 };
 use crate::{
-  algebra::field::FiniteField,
+  algebra::field::{BatchArithmetic, FiniteField},
   compiler::parser::{parse_constraints, WireCoeffs},
   polynomial::{Lagrange, Polynomial},
   Field, PlutoScalarField,
@@ -55,11 +55,12 @@ pub struct Cell {
 
 impl Cell {
   /// Assign a domain value to a cell where `row` represents power of primitive root of unity and
-  /// `column` represents coset value: $k*\omega^(row)$
+  /// `column` represents coset value: $k*\omega^(row)$. The labels are only multiplied, never
+  /// inverted, so [`BatchArithmetic::pow_sliding_window`] is the only batch helper that applies.
   fn label(&self, group_order: usize) -> PlutoScalarField {
     let col: u32 = self.column as u32;
     PlutoScalarField::from(col)
-      * PlutoScalarField::primitive_root_of_unity(group_order).pow(self.row as usize)
+      * PlutoScalarField::primitive_root_of_unity(group_order).pow_sliding_window(self.row as usize)
   }
 }
 
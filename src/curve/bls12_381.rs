@@ -0,0 +1,154 @@
+//! The BLS12-381 curve $y^2 = x^3 + 4$, used for BLS signatures in Ethereum consensus and by
+//! Zcash.
+//!
+//! It is the Barreto-Lynn-Scott curve of embedding degree 12 and parameter
+//! $x = -\texttt{0xd201000000010000}$, over the 381-bit [`Bls12381BaseField`]. Its subgroup of
+//! prime order $r$, of cofactor $(x - 1)^2 / 3$, forms $\mathbb{G}_1$, while $\mathbb{G}_2$ is
+//! represented on the M-type sextic twist $y^2 = x^3 + 4 (1 + u)$ over [`Bls12381Fp2`], whose
+//! 508-bit cofactor is given in [`Bls12381G2Curve::COFACTOR`].
+
+use crypto_bigint::U384;
+
+use super::{
+  pairing::ate::{CurveFamily, PairingConfig, TwistType},
+  *,
+};
+use crate::algebra::field::{
+  big_prime::{Bls12381BaseField, Bls12381ScalarField},
+  extension::bls12_381::{Bls12381Fp12Config, Bls12381Fp2, Bls12381Fp2Config, Bls12381Fp6Config},
+};
+
+/// The BLS12-381 curve `y^2 = x^3 + 4` over the [`Bls12381BaseField`], whose subgroup of prime
+/// order is the group $\mathbb{G}_1$ of the pairing.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Bls12381G1Curve;
+
+/// The sextic twist `y^2 = x^3 + 4 (1 + u)` of BLS12-381 over [`Bls12381Fp2`], whose subgroup of
+/// prime order represents the group $\mathbb{G}_2$ of the pairing.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Bls12381G2Curve;
+
+/// The parameters of the optimal ate pairing on BLS12-381.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bls12381;
+
+impl EllipticCurve for Bls12381G1Curve {
+  type BaseField = Bls12381BaseField;
+  type Coefficient = Bls12381BaseField;
+  type ScalarField = Bls12381ScalarField;
+
+  const COFACTOR: &'static [u64] = &[0x8c00aaab0000aaab, 0x396c8c005555e156];
+  const EQUATION_A: Self::Coefficient = Bls12381BaseField::ZERO;
+  const EQUATION_B: Self::Coefficient = Bls12381BaseField::new(U384::from_u8(4));
+  const GENERATOR: (Self::BaseField, Self::BaseField) = (
+    Bls12381BaseField::new(U384::from_be_hex(
+      "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+    )),
+    Bls12381BaseField::new(U384::from_be_hex(
+      "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1",
+    )),
+  );
+}
+
+impl EllipticCurve for Bls12381G2Curve {
+  type BaseField = Bls12381Fp2;
+  type Coefficient = Bls12381Fp2;
+  type ScalarField = Bls12381ScalarField;
+
+  const COFACTOR: &'static [u64] = &[
+    0xcf1c38e31c7238e5,
+    0x1616ec6e786f0c70,
+    0x21537e293a6691ae,
+    0xa628f1cb4d9e82ef,
+    0xa68a205b2e5a7ddf,
+    0xcd91de4547085aba,
+    0x091d50792876a202,
+    0x05d543a95414e7f1,
+  ];
+  const EQUATION_A: Self::Coefficient = Bls12381Fp2::ZERO;
+  const EQUATION_B: Self::Coefficient = Bls12381Fp2::new([
+    Bls12381BaseField::new(U384::from_u8(4)),
+    Bls12381BaseField::new(U384::from_u8(4)),
+  ]);
+  const GENERATOR: (Self::BaseField, Self::BaseField) = (
+    Bls12381Fp2::new([
+      Bls12381BaseField::new(U384::from_be_hex(
+        "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+      )),
+      Bls12381BaseField::new(U384::from_be_hex(
+        "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e",
+      )),
+    ]),
+    Bls12381Fp2::new([
+      Bls12381BaseField::new(U384::from_be_hex(
+        "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+      )),
+      Bls12381BaseField::new(U384::from_be_hex(
+        "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be",
+      )),
+    ]),
+  );
+}
+
+impl PairingConfig for Bls12381 {
+  type Fp12Config = Bls12381Fp12Config;
+  type Fp2Config = Bls12381Fp2Config;
+  type Fp6Config = Bls12381Fp6Config;
+  type G1 = Bls12381G1Curve;
+  type G2 = Bls12381G2Curve;
+
+  const FAMILY: CurveFamily = CurveFamily::Bls12;
+  const TWIST: TwistType = TwistType::M;
+  const X: u64 = 0xd201000000010000;
+  const X_IS_NEGATIVE: bool = true;
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto_bigint::U256;
+
+  use super::*;
+  use crate::{algebra::field::SquareRoot, curve::subgroup::PrimeOrderPoint};
+
+  #[test]
+  fn generators() {
+    let g_1 = AffinePoint::<Bls12381G1Curve>::GENERATOR;
+    let g_2 = AffinePoint::<Bls12381G2Curve>::GENERATOR;
+    assert!(PrimeOrderPoint::new(g_1).is_ok());
+    assert!(PrimeOrderPoint::new(g_2).is_ok());
+    // the scalar field has order r = x^4 - x^2 + 1
+    let x = -Bls12381ScalarField::from(Bls12381::X);
+    assert_eq!(x.pow(4) - x.pow(2) + Bls12381ScalarField::ONE, Bls12381ScalarField::ZERO);
+  }
+
+  #[test]
+  fn cofactor() {
+    // points with x = 0, 1, ... are not in G1, but multiplying them by h = (x - 1)^2 / 3 is
+    let h = U256::from_be_hex("00000000000000000000000000000000396c8c005555e1568c00aaab0000aaab");
+    let point = (0..)
+      .find_map(|x| {
+        let x = Bls12381BaseField::from(x);
+        let y = (x * x * x + Bls12381G1Curve::EQUATION_B).square_root()?;
+        AffinePoint::<Bls12381G1Curve>::new(x, y).ok()
+      })
+      .unwrap();
+    assert_eq!(PrimeOrderPoint::new(point), Err(PointError::NotInSubgroup));
+    let cleared = point * Bls12381ScalarField::new(h);
+    assert!(PrimeOrderPoint::new(cleared).is_ok());
+    assert_eq!(point.clear_cofactor(), cleared);
+    assert_eq!(h.as_words()[..2], *Bls12381G1Curve::COFACTOR);
+  }
+
+  #[test]
+  fn twist_cofactor() {
+    let point = (0..)
+      .find_map(|x| {
+        let x = Bls12381Fp2::from(x);
+        let y = (x * x * x + Bls12381G2Curve::EQUATION_B).square_root()?;
+        AffinePoint::<Bls12381G2Curve>::new(x, y).ok()
+      })
+      .unwrap();
+    assert_eq!(PrimeOrderPoint::new(point), Err(PointError::NotInSubgroup));
+    assert!(PrimeOrderPoint::new(point.clear_cofactor()).is_ok());
+  }
+}
@@ -0,0 +1,135 @@
+//! The BN254 curve $y^2 = x^3 + 3$, also known as alt_bn128, whose pairing is available on
+//! Ethereum through the precompiles of [EIP-197](https://eips.ethereum.org/EIPS/eip-197).
+//!
+//! It is the Barreto-Naehrig curve of parameter $x = 4965661367192848881$, with a prime number $r$
+//! of points over the 254-bit [`Bn254BaseField`]. Its points form $\mathbb{G}_1$, while
+//! $\mathbb{G}_2$ is represented on the D-type sextic twist $y^2 = x^3 + 3 / (9 + u)$ over
+//! [`Bn254Fp2`], of cofactor $2p - r$. The
+//! [optimal ate pairing](super::pairing::ate) maps them to
+//! [`Bn254Fp12`](crate::algebra::field::extension::bn254::Bn254Fp12).
+
+use crypto_bigint::U256;
+
+use super::{
+  pairing::ate::{CurveFamily, PairingConfig, TwistType},
+  *,
+};
+use crate::algebra::field::{
+  big_prime::{Bn254BaseField, Bn254ScalarField},
+  extension::bn254::{Bn254Fp12Config, Bn254Fp2, Bn254Fp2Config, Bn254Fp6Config},
+};
+
+/// The BN254 curve `y^2 = x^3 + 3` over the [`Bn254BaseField`], the group $\mathbb{G}_1$ of the
+/// pairing.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Bn254G1Curve;
+
+/// The sextic twist `y^2 = x^3 + 3 / (9 + u)` of BN254 over [`Bn254Fp2`], whose subgroup of prime
+/// order represents the group $\mathbb{G}_2$ of the pairing.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Bn254G2Curve;
+
+/// The parameters of the optimal ate pairing on BN254.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Bn254;
+
+impl EllipticCurve for Bn254G1Curve {
+  type BaseField = Bn254BaseField;
+  type Coefficient = Bn254BaseField;
+  type ScalarField = Bn254ScalarField;
+
+  const COFACTOR: &'static [u64] = &[1];
+  const EQUATION_A: Self::Coefficient = Bn254BaseField::ZERO;
+  const EQUATION_B: Self::Coefficient = Bn254BaseField::new(U256::from_u8(3));
+  const GENERATOR: (Self::BaseField, Self::BaseField) =
+    (Bn254BaseField::ONE, Bn254BaseField::new(U256::from_u8(2)));
+}
+
+impl EllipticCurve for Bn254G2Curve {
+  type BaseField = Bn254Fp2;
+  type Coefficient = Bn254Fp2;
+  type ScalarField = Bn254ScalarField;
+
+  const COFACTOR: &'static [u64] =
+    &[0x345f2299c0f9fa8d, 0x06ceecda572a2489, 0xb85045b68181585e, 0x30644e72e131a029];
+  const EQUATION_A: Self::Coefficient = Bn254Fp2::ZERO;
+  const EQUATION_B: Self::Coefficient = Bn254Fp2::new([
+    Bn254BaseField::new(U256::from_be_hex(
+      "2b149d40ceb8aaae81be18991be06ac3b5b4c5e559dbefa33267e6dc24a138e5",
+    )),
+    Bn254BaseField::new(U256::from_be_hex(
+      "009713b03af0fed4cd2cafadeed8fdf4a74fa084e52d1852e4a2bd0685c315d2",
+    )),
+  ]);
+  const GENERATOR: (Self::BaseField, Self::BaseField) = (
+    Bn254Fp2::new([
+      Bn254BaseField::new(U256::from_be_hex(
+        "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+      )),
+      Bn254BaseField::new(U256::from_be_hex(
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+      )),
+    ]),
+    Bn254Fp2::new([
+      Bn254BaseField::new(U256::from_be_hex(
+        "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+      )),
+      Bn254BaseField::new(U256::from_be_hex(
+        "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+      )),
+    ]),
+  );
+}
+
+impl PairingConfig for Bn254 {
+  type Fp12Config = Bn254Fp12Config;
+  type Fp2Config = Bn254Fp2Config;
+  type Fp6Config = Bn254Fp6Config;
+  type G1 = Bn254G1Curve;
+  type G2 = Bn254G2Curve;
+
+  const FAMILY: CurveFamily = CurveFamily::Bn;
+  const TWIST: TwistType = TwistType::D;
+  const X: u64 = 4965661367192848881;
+  const X_IS_NEGATIVE: bool = false;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{algebra::field::SquareRoot, curve::subgroup::PrimeOrderPoint};
+
+  #[test]
+  fn generators() {
+    let g_1 = AffinePoint::<Bn254G1Curve>::GENERATOR;
+    let g_2 = AffinePoint::<Bn254G2Curve>::GENERATOR;
+    assert!(PrimeOrderPoint::new(g_1).is_ok());
+    assert!(PrimeOrderPoint::new(g_2).is_ok());
+    // b' = b / ξ for a D-type twist
+    let xi = Bn254Fp2::new([Bn254BaseField::from(9u64), Bn254BaseField::ONE]);
+    assert_eq!(Bn254G2Curve::EQUATION_B * xi, Bn254Fp2::from(3));
+  }
+
+  #[test]
+  fn twist_is_not_prime_order() {
+    // the twist has (2p - r) r points, so most of them are not in G2
+    let point = (0..)
+      .find_map(|x| {
+        let x = Bn254Fp2::from(x);
+        let y = (x * x * x + Bn254G2Curve::EQUATION_B).square_root()?;
+        AffinePoint::<Bn254G2Curve>::new(x, y).ok()
+      })
+      .unwrap();
+    assert_eq!(PrimeOrderPoint::new(point), Err(PointError::NotInSubgroup));
+    assert!(PrimeOrderPoint::new(point.clear_cofactor()).is_ok());
+  }
+
+  #[test]
+  fn cofactor() {
+    // h = 2p - r
+    let (p, r) = (Bn254BaseField::MODULUS, Bn254ScalarField::MODULUS);
+    let h = p.wrapping_add(&p).wrapping_sub(&r);
+    assert_eq!(h.as_words(), Bn254G2Curve::COFACTOR);
+    assert_eq!(Bn254G1Curve::COFACTOR, [1]);
+  }
+}
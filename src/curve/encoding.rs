@@ -0,0 +1,203 @@
+//! [SEC1](https://www.secg.org/sec1-v2.pdf) encodings of [`AffinePoint`]s (section 2.3.3).
+//!
+//! With `L` the [`FieldEncoding::ENCODED_LEN`] of the base field, a point is encoded as
+//! - the single byte `0x00` for the point at infinity;
+//! - `0x04 || x || y` uncompressed, `2L + 1` bytes;
+//! - `0x02 || x` or `0x03 || x` compressed, `L + 1` bytes, where the tag carries the sign
+//!   [`FieldEncoding::sgn0`] of `y`.
+//!
+//! Compression works because a valid `x` leaves only the two choices $\pm y$ for the other
+//! coordinate, the square roots of $x^3 + a x + b$, and only one of them is odd. Decompressing
+//! therefore costs a [square root](SquareRoot::square_root).
+//!
+//! Decoding checks everything a point received from someone else could get wrong: the length and
+//! tag, that the coordinates are canonical, that the point is on the curve, and that it lies in the
+//! subgroup of prime order $r$ of the scalar field, i.e. $rP = \mathcal{O}$. Skipping the last
+//! check lets an attacker send points of small order, which leak the secret scalar modulo the
+//! cofactor in protocols like ECDH.
+use std::{error::Error, fmt::Display};
+
+use super::*;
+use crate::algebra::field::{FieldEncoding, SquareRoot};
+
+/// The tag of the encoding of the point at infinity.
+const INFINITY_TAG: u8 = 0x00;
+/// The tag of a compressed point with even `y`.
+const COMPRESSED_EVEN_TAG: u8 = 0x02;
+/// The tag of a compressed point with odd `y`.
+const COMPRESSED_ODD_TAG: u8 = 0x03;
+/// The tag of an uncompressed point.
+const UNCOMPRESSED_TAG: u8 = 0x04;
+
+/// Errors from decoding a point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointDecodingError {
+  /// The encoding has the wrong length for its tag.
+  InvalidLength(usize),
+  /// The first byte is not a known tag.
+  InvalidTag(u8),
+  /// A coordinate is not a canonical field element, or `y = 0` is given with an odd sign.
+  NonCanonical,
+  /// The coordinates don't satisfy the curve equation, or `x` has no matching `y`.
+  NotOnCurve,
+  /// The point is on the curve but not in the subgroup of prime order.
+  NotInSubgroup,
+}
+
+impl Error for PointDecodingError {}
+
+impl Display for PointDecodingError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match *self {
+      PointDecodingError::InvalidLength(len) => write!(f, "invalid encoding length: {}", len),
+      PointDecodingError::InvalidTag(tag) => write!(f, "invalid encoding tag: {:#04x}", tag),
+      PointDecodingError::NonCanonical => write!(f, "non-canonical encoding"),
+      PointDecodingError::NotOnCurve => write!(f, "point is not on curve"),
+      PointDecodingError::NotInSubgroup => write!(f, "point is not in the prime order subgroup"),
+    }
+  }
+}
+
+impl<C: EllipticCurve> AffinePoint<C>
+where C::BaseField: FieldEncoding
+{
+  /// Encodes the point as `0x02 || x` or `0x03 || x` depending on the sign of `y`, or `0x00` for
+  /// the point at infinity.
+  pub fn to_compressed(&self) -> Vec<u8> {
+    match self {
+      AffinePoint::Point(x, y) => {
+        let tag = match y.sgn0() {
+          true => COMPRESSED_ODD_TAG,
+          false => COMPRESSED_EVEN_TAG,
+        };
+        let mut bytes = vec![tag];
+        bytes.extend(x.to_be_bytes());
+        bytes
+      },
+      AffinePoint::Infinity => vec![INFINITY_TAG],
+    }
+  }
+
+  /// Encodes the point as `0x04 || x || y`, or `0x00` for the point at infinity.
+  pub fn to_uncompressed(&self) -> Vec<u8> {
+    match self {
+      AffinePoint::Point(x, y) => {
+        let mut bytes = vec![UNCOMPRESSED_TAG];
+        bytes.extend(x.to_be_bytes());
+        bytes.extend(y.to_be_bytes());
+        bytes
+      },
+      AffinePoint::Infinity => vec![INFINITY_TAG],
+    }
+  }
+}
+
+impl<C: EllipticCurve> AffinePoint<C>
+where C::BaseField: FieldEncoding + SquareRoot
+{
+  /// Decodes a compressed or uncompressed point, recovering `y` from `x` with a square root in the
+  /// first case.
+  ///
+  /// ## Errors
+  /// If the encoding is malformed, or the point is not on the curve or not in the subgroup of
+  /// prime order, see [`PointDecodingError`].
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self, PointDecodingError> {
+    let len = C::BaseField::ENCODED_LEN;
+    let coordinate =
+      |bytes: &[u8]| C::BaseField::from_be_bytes(bytes).ok_or(PointDecodingError::NonCanonical);
+    let (&tag, rest) = bytes.split_first().ok_or(PointDecodingError::InvalidLength(0))?;
+    let point = match (tag, rest.len()) {
+      (INFINITY_TAG, 0) => return Ok(AffinePoint::Infinity),
+      (COMPRESSED_EVEN_TAG | COMPRESSED_ODD_TAG, n) if n == len => {
+        let x = coordinate(rest)?;
+        let a: C::BaseField = C::EQUATION_A.into();
+        let b: C::BaseField = C::EQUATION_B.into();
+        let y = (x * x * x + a * x + b).square_root().ok_or(PointDecodingError::NotOnCurve)?;
+        match (y.sgn0() == (tag == COMPRESSED_ODD_TAG), y == C::BaseField::ZERO) {
+          (true, _) => AffinePoint::Point(x, y),
+          (false, false) => AffinePoint::Point(x, -y),
+          // zero is its own negation, so it has no odd encoding
+          (false, true) => return Err(PointDecodingError::NonCanonical),
+        }
+      },
+      (UNCOMPRESSED_TAG, n) if n == 2 * len => {
+        let point = AffinePoint::Point(coordinate(&rest[..len])?, coordinate(&rest[len..])?);
+        if !point.is_on_curve() {
+          return Err(PointDecodingError::NotOnCurve);
+        }
+        point
+      },
+      (INFINITY_TAG | COMPRESSED_EVEN_TAG | COMPRESSED_ODD_TAG | UNCOMPRESSED_TAG, _) =>
+        return Err(PointDecodingError::InvalidLength(bytes.len())),
+      _ => return Err(PointDecodingError::InvalidTag(tag)),
+    };
+    match point.is_in_prime_subgroup() {
+      true => Ok(point),
+      false => Err(PointDecodingError::NotInSubgroup),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::curve::pluto_curve::{PlutoBaseCurve, PlutoExtendedCurve};
+
+  #[test]
+  fn generator() {
+    let g = AffinePoint::<PlutoBaseCurve>::GENERATOR;
+    assert_eq!(g.to_compressed(), vec![0x02, 1]);
+    assert_eq!(g.to_uncompressed(), vec![0x04, 1, 2]);
+    assert_eq!((-g).to_compressed(), vec![0x03, 1]);
+    assert_eq!(AffinePoint::<PlutoBaseCurve>::Infinity.to_compressed(), vec![0x00]);
+    assert_eq!(AffinePoint::<PlutoBaseCurve>::Infinity.to_uncompressed(), vec![0x00]);
+  }
+
+  #[test]
+  fn round_trip() {
+    let g = AffinePoint::<PlutoBaseCurve>::GENERATOR;
+    let h = AffinePoint::<PlutoExtendedCurve>::GENERATOR;
+    for k in 0..PlutoPrime::Scalar as usize {
+      let p = g * PlutoScalarField::new(k);
+      assert_eq!(AffinePoint::from_bytes(&p.to_compressed()), Ok(p));
+      assert_eq!(AffinePoint::from_bytes(&p.to_uncompressed()), Ok(p));
+      let q = h * PlutoScalarField::new(k);
+      assert_eq!(AffinePoint::from_bytes(&q.to_compressed()), Ok(q));
+      assert_eq!(AffinePoint::from_bytes(&q.to_uncompressed()), Ok(q));
+    }
+  }
+
+  #[test]
+  fn malformed() {
+    type Point = AffinePoint<PlutoBaseCurve>;
+    assert_eq!(Point::from_bytes(&[]), Err(PointDecodingError::InvalidLength(0)));
+    assert_eq!(Point::from_bytes(&[0x00, 0]), Err(PointDecodingError::InvalidLength(2)));
+    assert_eq!(Point::from_bytes(&[0x02, 1, 2]), Err(PointDecodingError::InvalidLength(3)));
+    assert_eq!(Point::from_bytes(&[0x04, 1]), Err(PointDecodingError::InvalidLength(2)));
+    assert_eq!(Point::from_bytes(&[0x05, 1]), Err(PointDecodingError::InvalidTag(0x05)));
+    assert_eq!(Point::from_bytes(&[0x02, 102]), Err(PointDecodingError::NonCanonical));
+    assert_eq!(Point::from_bytes(&[0x04, 1, 103]), Err(PointDecodingError::NonCanonical));
+    assert_eq!(Point::from_bytes(&[0x04, 1, 3]), Err(PointDecodingError::NotOnCurve));
+  }
+
+  #[test]
+  fn rejects_points_outside_subgroup() {
+    // the curve has 102 = 2 * 3 * 17 points, so every other point has a small order factor
+    let mut rejected = 0;
+    for x in 0..PlutoPrime::Base as usize {
+      let x = PlutoBaseField::new(x);
+      for tag in [0x02, 0x03] {
+        let bytes = [tag, x.value as u8];
+        match AffinePoint::<PlutoBaseCurve>::from_bytes(&bytes) {
+          Ok(point) => assert_eq!(point.to_compressed(), bytes),
+          Err(PointDecodingError::NotInSubgroup) => rejected += 1,
+          // x^3 + 3 is not a square, or y = 0 with the odd tag
+          Err(e) =>
+            assert!(matches!(e, PointDecodingError::NotOnCurve | PointDecodingError::NonCanonical)),
+        }
+      }
+    }
+    // the 101 affine points minus the 16 of the subgroup, each with a single encoding
+    assert_eq!(rejected, 102 - 17);
+  }
+}
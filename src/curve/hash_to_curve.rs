@@ -0,0 +1,388 @@
+//! Hashing to elliptic curves following the shape of
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380).
+//!
+//! A message is mapped to a point in three steps:
+//! 1. [`expand_message_xmd`] stretches the message into uniformly random bytes with a hash
+//!    function, separated from other uses of the hash by a domain separation tag `dst`;
+//! 2. [`hash_to_field`] reduces those bytes to field elements, taking $\lceil \log_2 p \rceil +
+//!    128$ bits per element so that the result is statistically close to uniform;
+//! 3. a [`MapToCurve`] sends each field element to a curve point, and the cofactor is cleared to
+//!    land in the subgroup of prime order.
+//!
+//! The maps onto curves are not surjective nor uniform on their own, which is why
+//! [`hash_to_curve()`] adds the images of two field elements: the sum is indistinguishable from a
+//! random oracle. [`encode_to_curve`] only maps one element and is cheaper, but its output is
+//! recognisably non-uniform.
+//!
+//! Maps for Weierstrass curves:
+//! - [`map_to_curve_simple_swu`], the simplified Shallue–van de Woestijne–Ulas map, for curves with
+//!   $ab \neq 0$. Curves with $a = 0$ like the Pluto curves or BN254 need an isogeny first.
+//! - [`try_and_increment`], which increments `x` until $x^3 + ax + b$ is a square. It works on any
+//!   curve, but its running time depends on the input, so it leaks timing information.
+//!
+//! The Ed25519 curve has its own map, Elligator 2, see
+//! [`Coordinate::hash_to_curve`](crate::dsa::eddsa::curve::Coordinate::hash_to_curve).
+use super::*;
+use crate::{
+  algebra::field::{
+    big_prime::{BigPrimeField, BigPrimeParams},
+    FieldEncoding, SquareRoot,
+  },
+  hashes::sha::{Sha256, Sha512},
+};
+
+/// The security level `k` in bits of [`hash_to_field`].
+const SECURITY_BITS: usize = 128;
+
+/// Hash functions that [`expand_message_xmd`] can be instantiated with.
+pub trait XmdHash {
+  /// The output size `b_in_bytes` of the hash function.
+  const OUTPUT_LEN: usize;
+  /// The input block size `s_in_bytes` of the hash function.
+  const BLOCK_LEN: usize;
+
+  /// Hashes the input.
+  fn hash(input: &[u8]) -> Vec<u8>;
+}
+
+impl XmdHash for Sha256 {
+  const BLOCK_LEN: usize = 64;
+  const OUTPUT_LEN: usize = 32;
+
+  fn hash(input: &[u8]) -> Vec<u8> { Sha256::new().digest(input) }
+}
+
+impl XmdHash for Sha512 {
+  const BLOCK_LEN: usize = 128;
+  const OUTPUT_LEN: usize = 64;
+
+  fn hash(input: &[u8]) -> Vec<u8> { Sha512::new().digest(input) }
+}
+
+/// Expands a message into `len` pseudorandom bytes with the Merkle–Damgård hash function `H`, as
+/// in section 5.3.1 of RFC 9380.
+///
+/// The blocks are $b_0 = H(0^s \| msg \| len \| 0 \| dst')$, $b_1 = H(b_0 \| 1 \| dst')$ and
+/// $b_i = H((b_0 \oplus b_{i-1}) \| i \| dst')$, where $dst'$ is the tag followed by its length.
+/// Tags longer than 255 bytes are replaced by their hash, as in section 5.3.3.
+///
+/// ## Panics
+/// If `len` is more than 255 blocks of output, or more than 65535 bytes.
+pub fn expand_message_xmd<H: XmdHash>(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+  let ell = len.div_ceil(H::OUTPUT_LEN);
+  assert!(ell <= 255 && len <= 65535, "requested too many bytes");
+  let oversize;
+  let dst = match dst.len() {
+    0..=255 => dst,
+    _ => {
+      oversize = H::hash(&[b"H2C-OVERSIZE-DST-", dst].concat());
+      &oversize
+    },
+  };
+  let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+  let msg_prime =
+    [&vec![0; H::BLOCK_LEN], msg, &(len as u16).to_be_bytes(), &[0], &dst_prime].concat();
+  let b_0 = H::hash(&msg_prime);
+  let mut b_i = H::hash(&[&b_0, &[1][..], &dst_prime].concat());
+  let mut uniform_bytes = b_i.clone();
+  for i in 2..=ell {
+    let xored: Vec<u8> = b_0.iter().zip(&b_i).map(|(a, b)| a ^ b).collect();
+    b_i = H::hash(&[&xored, &[i as u8][..], &dst_prime].concat());
+    uniform_bytes.extend(&b_i);
+  }
+  uniform_bytes.truncate(len);
+  uniform_bytes
+}
+
+/// Fields that uniformly random bytes can be reduced into, see [`hash_to_field`].
+pub trait HashToField: Field {
+  /// The number of bytes `L * m` reduced into one element: `L` bytes for each of the `m`
+  /// coordinates over the prime field, where $L = \lceil (\lceil \log_2 p \rceil + k) / 8 \rceil$.
+  const HASH_LEN: usize;
+
+  /// Reduces [`HashToField::HASH_LEN`] big-endian bytes into an element.
+  fn from_uniform_bytes(bytes: &[u8]) -> Self;
+}
+
+impl<const P: usize> HashToField for PrimeField<P> {
+  const HASH_LEN: usize = ((usize::BITS - P.leading_zeros()) as usize + SECURITY_BITS).div_ceil(8);
+
+  fn from_uniform_bytes(bytes: &[u8]) -> Self {
+    let value = bytes.iter().fold(0, |acc, &byte| (acc << 8 | byte as u128) % P as u128);
+    Self::new(value as usize)
+  }
+}
+
+impl<const N: usize, const P: usize> HashToField for GaloisField<N, P> {
+  const HASH_LEN: usize = N * PrimeField::<P>::HASH_LEN;
+
+  /// Reduces each coefficient from its own `L` bytes, starting from the constant term.
+  fn from_uniform_bytes(bytes: &[u8]) -> Self {
+    let mut coeffs = bytes.chunks(PrimeField::<P>::HASH_LEN).map(PrimeField::from_uniform_bytes);
+    Self::new(std::array::from_fn(|_| coeffs.next().unwrap()))
+  }
+}
+
+impl<M: BigPrimeParams<LIMBS>, const LIMBS: usize> HashToField for BigPrimeField<M, LIMBS> {
+  const HASH_LEN: usize = (M::MODULUS.as_ref().bits_vartime() as usize + SECURITY_BITS).div_ceil(8);
+
+  /// Reduces the bytes 64 bits at a time with Horner's rule, in the field.
+  fn from_uniform_bytes(bytes: &[u8]) -> Self {
+    let shift = Self::from(1u64 << 32) * Self::from(1u64 << 32);
+    bytes.rchunks(8).rev().fold(Self::ZERO, |acc, limb| {
+      let limb = limb.iter().fold(0, |acc, &byte| acc << 8 | byte as u64);
+      acc * shift + Self::from(limb)
+    })
+  }
+}
+
+/// Hashes a message to `count` field elements, as in section 5.2 of RFC 9380: every element is
+/// reduced from [`HashToField::HASH_LEN`] bytes of [`expand_message_xmd`].
+pub fn hash_to_field<F: HashToField, H: XmdHash>(msg: &[u8], dst: &[u8], count: usize) -> Vec<F> {
+  let uniform_bytes = expand_message_xmd::<H>(msg, dst, count * F::HASH_LEN);
+  uniform_bytes.chunks(F::HASH_LEN).map(F::from_uniform_bytes).collect()
+}
+
+/// Curves with a deterministic map from field elements to points, from which [`hash_to_curve()`]
+/// and [`encode_to_curve`] are built.
+pub trait MapToCurve: EllipticCurve<BaseField: FieldEncoding + HashToField + SquareRoot> {
+  /// Maps a field element to a point on the curve, not necessarily in the subgroup of prime order.
+  fn map_to_curve(u: Self::BaseField) -> AffinePoint<Self>;
+}
+
+/// The right-hand side $x^3 + ax + b$ of the curve equation.
+fn curve_rhs<C: EllipticCurve>(x: C::BaseField) -> C::BaseField {
+  let a: C::BaseField = C::EQUATION_A.into();
+  let b: C::BaseField = C::EQUATION_B.into();
+  x * x * x + a * x + b
+}
+
+/// The simplified SWU map of section 6.6.2 of RFC 9380, for curves with $ab \neq 0$.
+///
+/// With $x_1 = \frac{-b}{a} \left(1 + \frac{1}{Z^2 u^4 + Z u^2}\right)$ and $x_2 = Z u^2 x_1$, one
+/// checks that $g(x_2) = Z^3 u^6 g(x_1)$ for $g(x) = x^3 + ax + b$. Since $Z$ is not a square,
+/// one of $g(x_1)$ and $g(x_2)$ is always a square, and gives a point. The sign of `y` is then
+/// matched to the sign of `u`.
+///
+/// `z` must be a non-square such that $g(x) - Z$ is irreducible and $g(b / (Z a))$ is a square,
+/// the criteria of appendix H.2 of RFC 9380.
+///
+/// ## Panics
+/// If `a` or `b` is zero, or `z` is a square.
+pub fn map_to_curve_simple_swu<C: EllipticCurve>(
+  u: C::BaseField,
+  z: C::BaseField,
+) -> AffinePoint<C>
+where
+  C::BaseField: FieldEncoding + SquareRoot,
+{
+  let a: C::BaseField = C::EQUATION_A.into();
+  let b: C::BaseField = C::EQUATION_B.into();
+  assert!(a != C::BaseField::ZERO && b != C::BaseField::ZERO, "SWU needs ab != 0");
+  assert!(!z.is_square(), "Z must not be a square");
+
+  let z_u2 = z * u * u;
+  let x1 = match (z_u2 * z_u2 + z_u2).inverse() {
+    Some(tv1) => -b / a * (C::BaseField::ONE + tv1),
+    // exceptional case for u = 0, and Z u^2 = -1 which only happens when -1 is not a square
+    None => b / (z * a),
+  };
+  let gx1 = curve_rhs::<C>(x1);
+  let (x, y) = match gx1.square_root() {
+    Some(y1) => (x1, y1),
+    None => {
+      let x2 = z_u2 * x1;
+      (x2, curve_rhs::<C>(x2).square_root().expect("g(x2) is a square when g(x1) is not"))
+    },
+  };
+  match y.sgn0() == u.sgn0() {
+    true => AffinePoint::Point(x, y),
+    false => AffinePoint::Point(x, -y),
+  }
+}
+
+/// Maps `u` to the point with the smallest `x = u, u + 1, u + 2, ...` for which $x^3 + ax + b$ is a
+/// square, with the sign of `y` matched to the sign of `u`.
+///
+/// About half of the field elements are valid `x` coordinates, so this takes two attempts on
+/// average. It is simple and works on any curve, but the number of attempts depends on `u`, so the
+/// running time leaks information about the input.
+pub fn try_and_increment<C: EllipticCurve>(u: C::BaseField) -> AffinePoint<C>
+where C::BaseField: FieldEncoding + SquareRoot {
+  let mut x = u;
+  loop {
+    if let Some(y) = curve_rhs::<C>(x).square_root() {
+      return match y.sgn0() == u.sgn0() {
+        true => AffinePoint::Point(x, y),
+        false => AffinePoint::Point(x, -y),
+      };
+    }
+    x += C::BaseField::ONE;
+  }
+}
+
+/// Hashes a message to a point of the subgroup of prime order, indistinguishably from a random
+/// oracle: the two field elements from [`hash_to_field`] with SHA-256 are mapped to the curve, and
+/// the cofactor of their sum is cleared.
+pub fn hash_to_curve<C: MapToCurve>(msg: &[u8], dst: &[u8]) -> AffinePoint<C> {
+  let u = hash_to_field::<C::BaseField, Sha256>(msg, dst, 2);
+  (C::map_to_curve(u[0]) + C::map_to_curve(u[1])).clear_cofactor()
+}
+
+/// Encodes a message as a point of the subgroup of prime order, mapping a single field element
+/// from [`hash_to_field`] with SHA-256. The output is not uniformly distributed, see
+/// [`hash_to_curve()`] for a random oracle.
+pub fn encode_to_curve<C: MapToCurve>(msg: &[u8], dst: &[u8]) -> AffinePoint<C> {
+  let u = hash_to_field::<C::BaseField, Sha256>(msg, dst, 1);
+  C::map_to_curve(u[0]).clear_cofactor()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    algebra::field::big_prime::Bn254ScalarField,
+    curve::pluto_curve::{PlutoBaseCurve, PlutoExtendedCurve},
+  };
+
+  /// The curve `y^2 = x^3 + 5x + 5` over `GF(101)`, with `119 = 7 * 17` points.
+  #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+  struct SwuCurve;
+
+  impl EllipticCurve for SwuCurve {
+    type BaseField = PlutoBaseField;
+    type Coefficient = PlutoBaseField;
+    type ScalarField = PlutoScalarField;
+
+    const COFACTOR: &'static [u64] = &[7];
+    const EQUATION_A: Self::Coefficient = PlutoBaseField::new(5);
+    const EQUATION_B: Self::Coefficient = PlutoBaseField::new(5);
+    const GENERATOR: (Self::BaseField, Self::BaseField) =
+      (PlutoBaseField::new(11), PlutoBaseField::new(49));
+    const ORDER: usize = PlutoPrime::Scalar as usize;
+  }
+
+  impl MapToCurve for SwuCurve {
+    /// `Z = 2` is the first candidate satisfying the criteria of appendix H.2.
+    fn map_to_curve(u: PlutoBaseField) -> AffinePoint<Self> {
+      map_to_curve_simple_swu(u, PlutoBaseField::new(2))
+    }
+  }
+
+  /// Test vectors of appendix K.1 of RFC 9380.
+  #[test]
+  fn expand_message_xmd_sha256() {
+    let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+    let cases: [(&[u8], usize, &str); 3] = [
+      (b"", 0x20, "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+      (b"abc", 0x20, "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+      (
+        b"",
+        0x80,
+        "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+      ),
+    ];
+    for (msg, len, expected) in cases {
+      assert_eq!(hex::encode(expand_message_xmd::<Sha256>(msg, dst, len)), expected);
+    }
+  }
+
+  #[test]
+  fn expand_message_xmd_oversize_dst() {
+    let dst = [b'a'; 300];
+    let hashed = Sha256::hash(&[&b"H2C-OVERSIZE-DST-"[..], &dst].concat());
+    assert_eq!(
+      expand_message_xmd::<Sha256>(b"msg", &dst, 64),
+      expand_message_xmd::<Sha256>(b"msg", &hashed, 64)
+    );
+  }
+
+  #[test]
+  fn field_elements() {
+    assert_eq!(PlutoBaseField::HASH_LEN, 17);
+    assert_eq!(PlutoBaseFieldExtension::HASH_LEN, 34);
+    assert_eq!(Bn254ScalarField::HASH_LEN, 48);
+
+    // 2^128 = 80 and 2^8 = 54 mod 101
+    let mut bytes = [0; 17];
+    bytes[0] = 1;
+    assert_eq!(PlutoBaseField::from_uniform_bytes(&bytes), PlutoBaseField::new(80));
+    bytes[15] = 1;
+    assert_eq!(PlutoBaseField::from_uniform_bytes(&bytes), PlutoBaseField::new(80 + 54));
+
+    let mut bytes = [0; 48];
+    bytes[47] = 7;
+    bytes[39] = 1;
+    assert_eq!(
+      Bn254ScalarField::from_uniform_bytes(&bytes),
+      Bn254ScalarField::from(7u64)
+        + Bn254ScalarField::from(1u64 << 63) * Bn254ScalarField::from(2u64)
+    );
+    let minus_one = -Bn254ScalarField::ONE;
+    let mut bytes = vec![0; 16];
+    bytes.extend(minus_one.to_be_bytes());
+    assert_eq!(Bn254ScalarField::from_uniform_bytes(&bytes), minus_one);
+
+    let elements = hash_to_field::<PlutoBaseFieldExtension, Sha256>(b"abc", b"dst", 3);
+    assert_eq!(elements.len(), 3);
+    let bytes = expand_message_xmd::<Sha256>(b"abc", b"dst", 3 * 34);
+    let c_1 = PlutoBaseField::from_uniform_bytes(&bytes[34 + 17..2 * 34]);
+    assert_eq!(elements[1].coeffs[1], c_1);
+  }
+
+  #[test]
+  fn simple_swu() {
+    let mut images = std::collections::HashSet::new();
+    for u in 0..PlutoPrime::Base as usize {
+      let u = PlutoBaseField::new(u);
+      let point = SwuCurve::map_to_curve(u);
+      assert!(point.is_on_curve());
+      assert_eq!(point.xy().1.sgn0(), u.sgn0());
+      if u != PlutoBaseField::ZERO {
+        assert_eq!(SwuCurve::map_to_curve(-u), -point);
+      }
+      images.insert(point);
+    }
+    // each point has at most four preimages
+    assert!(images.len() >= 101 / 4);
+  }
+
+  #[test]
+  fn try_and_increment_pluto() {
+    for u in 0..PlutoPrime::Base as usize {
+      let u = PlutoBaseField::new(u);
+      let point = PlutoBaseCurve::map_to_curve(u);
+      assert!(point.is_on_curve());
+      let (x, y, _) = point.xy();
+      assert!(y == PlutoBaseField::ZERO || y.sgn0() == u.sgn0());
+      // no x between u and the image is valid
+      let skipped = (0..PlutoPrime::Base as usize).map(|i| u + PlutoBaseField::new(i));
+      for x in skipped.take_while(|&v| v != x) {
+        assert!(!curve_rhs::<PlutoBaseCurve>(x).is_square());
+      }
+    }
+  }
+
+  #[test]
+  fn hash_to_prime_order_subgroup() {
+    let dst = b"ronkathon-hash-to-curve-test";
+    for msg in [&b""[..], b"abc", b"a longer message to hash"] {
+      let p = hash_to_curve::<PlutoBaseCurve>(msg, dst);
+      assert!(p.is_in_prime_subgroup());
+      assert_eq!(p, hash_to_curve::<PlutoBaseCurve>(msg, dst));
+      assert!(encode_to_curve::<PlutoBaseCurve>(msg, dst).is_in_prime_subgroup());
+      assert!(hash_to_curve::<PlutoExtendedCurve>(msg, dst).is_in_prime_subgroup());
+      assert!(hash_to_curve::<SwuCurve>(msg, dst).is_in_prime_subgroup());
+    }
+  }
+
+  #[test]
+  fn cofactor_clearing() {
+    let points: Vec<_> = (0..PlutoPrime::Base as usize)
+      .map(|u| SwuCurve::map_to_curve(PlutoBaseField::new(u)))
+      .collect();
+    assert!(points.iter().any(|&p| !p.is_in_prime_subgroup()));
+    assert!(points.into_iter().all(|p| p.clear_cofactor().is_in_prime_subgroup()));
+  }
+}
@@ -0,0 +1,233 @@
+//! Points in [Jacobian coordinates](https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html).
+//!
+//! A point $(X : Y : Z)$ with $Z \neq 0$ stands for the affine point $(X / Z^2, Y / Z^3)$, and the
+//! point at infinity is any $(X : Y : 0)$. The curve equation becomes
+//! $$ Y^2 = X^3 + a X Z^4 + b Z^6. $$
+//!
+//! Adding and doubling then only take multiplications, the divisions of the affine formulas being
+//! absorbed into $Z$. The single inversion is deferred to the conversion back to [`AffinePoint`],
+//! which is why [`AffinePoint`]'s scalar multiplication runs in these coordinates.
+//!
+//! The formulas are exception-free: the identity, doubling and a point plus its negation are
+//! detected from the inputs and handled separately.
+use super::*;
+
+/// A point on the curve `C` in Jacobian coordinates `(X : Y : Z)`, representing the affine point
+/// `(X / Z^2, Y / Z^3)`, or the point at infinity if `Z = 0`.
+#[derive(Clone, Copy, Debug)]
+pub struct JacobianPoint<C: EllipticCurve> {
+  pub(super) x: C::BaseField,
+  pub(super) y: C::BaseField,
+  pub(super) z: C::BaseField,
+}
+
+impl<C: EllipticCurve> JacobianPoint<C> {
+  /// Creates a point from its Jacobian coordinates.
+  ///
+  /// ## Errors
+  /// [`PointError::NotOnCurve`] if the coordinates don't satisfy
+  /// $Y^2 = X^3 + a X Z^4 + b Z^6$, or are all zero.
+  pub fn new(x: C::BaseField, y: C::BaseField, z: C::BaseField) -> Result<Self, PointError> {
+    let point = Self { x, y, z };
+    match point.is_on_curve() {
+      true => Ok(point),
+      false => Err(PointError::NotOnCurve),
+    }
+  }
+
+  /// Returns the coordinates `(X, Y, Z)`.
+  pub fn coordinates(&self) -> (C::BaseField, C::BaseField, C::BaseField) {
+    (self.x, self.y, self.z)
+  }
+
+  /// Returns whether the point is the point at infinity.
+  pub fn is_identity(&self) -> bool { self.z == C::BaseField::ZERO }
+}
+
+impl<C: EllipticCurve> PartialEq for JacobianPoint<C> {
+  /// Compares the affine points $(X_1 Z_2^2 : Y_1 Z_2^3)$ and $(X_2 Z_1^2 : Y_2 Z_1^3)$ scaled by
+  /// $Z_1^2 Z_2^2$ and $Z_1^3 Z_2^3$.
+  fn eq(&self, other: &Self) -> bool {
+    let (z1z1, z2z2) = (self.z * self.z, other.z * other.z);
+    self.x * z2z2 == other.x * z1z1 && self.y * z2z2 * other.z == other.y * z1z1 * self.z
+  }
+}
+
+impl<C: EllipticCurve> Eq for JacobianPoint<C> {}
+
+impl<C: EllipticCurve> Group for JacobianPoint<C> {
+  type Scalar = C::ScalarField;
+
+  const IDENTITY: Self = Self { x: C::BaseField::ONE, y: C::BaseField::ONE, z: C::BaseField::ZERO };
+
+  /// Adds two points with the [`add-2007-bl`](https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html#addition-add-2007-bl)
+  /// formulas, in 11 multiplications and 5 squarings.
+  fn op(&self, rhs: &Self) -> Self {
+    if self.is_identity() {
+      return *rhs;
+    }
+    if rhs.is_identity() {
+      return *self;
+    }
+    let two = C::BaseField::ONE + C::BaseField::ONE;
+    let (z1z1, z2z2) = (self.z * self.z, rhs.z * rhs.z);
+    // both points scaled to the common denominator Z1^2 Z2^2, resp. Z1^3 Z2^3
+    let (u1, u2) = (self.x * z2z2, rhs.x * z1z1);
+    let (s1, s2) = (self.y * rhs.z * z2z2, rhs.y * self.z * z1z1);
+    if u1 == u2 {
+      return match s1 == s2 {
+        true => self.double(),
+        false => Self::IDENTITY,
+      };
+    }
+    let h = u2 - u1;
+    let i = (two * h) * (two * h);
+    let j = h * i;
+    let r = two * (s2 - s1);
+    let v = u1 * i;
+    let x = r * r - j - two * v;
+    let y = r * (v - x) - two * s1 * j;
+    let z = ((self.z + rhs.z) * (self.z + rhs.z) - z1z1 - z2z2) * h;
+    Self { x, y, z }
+  }
+
+  fn inverse(&self) -> Option<Self> { Some(-*self) }
+
+  fn scalar_mul(&self, scalar: Self::Scalar) -> Self { double_and_add(*self, &scalar.to_limbs()) }
+}
+
+impl<C: EllipticCurve> CurveGroup for JacobianPoint<C> {
+  type BaseField = C::BaseField;
+
+  /// Doubles with the [`dbl-2007-bl`](https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html#doubling-dbl-2007-bl)
+  /// formulas, in 1 multiplication and 8 squarings. Points of order two have $Y = 0$, so their
+  /// double gets $Z = 0$ and is the point at infinity.
+  fn double(self) -> Self {
+    if self.is_identity() {
+      return self;
+    }
+    let a: C::BaseField = C::EQUATION_A.into();
+    let two = C::BaseField::ONE + C::BaseField::ONE;
+    let xx = self.x * self.x;
+    let yy = self.y * self.y;
+    let yyyy = yy * yy;
+    let zz = self.z * self.z;
+    // S = 4 X Y^2 and M = 3 X^2 + a Z^4, the slope of the tangent scaled by 2 Y Z^3
+    let s = two * ((self.x + yy) * (self.x + yy) - xx - yyyy);
+    let m = (two + C::BaseField::ONE) * xx + a * zz * zz;
+    let x = m * m - two * s;
+    let y = m * (s - x) - two * two * two * yyyy;
+    let z = (self.y + self.z) * (self.y + self.z) - yy - zz;
+    Self { x, y, z }
+  }
+
+  fn is_on_curve(&self) -> bool {
+    if self.is_identity() {
+      return self.x != C::BaseField::ZERO || self.y != C::BaseField::ZERO;
+    }
+    let a: C::BaseField = C::EQUATION_A.into();
+    let b: C::BaseField = C::EQUATION_B.into();
+    let z2 = self.z * self.z;
+    let z4 = z2 * z2;
+    self.y * self.y == self.x * self.x * self.x + a * self.x * z4 + b * z4 * z2
+  }
+
+  fn xy(&self) -> (Self::BaseField, Self::BaseField, bool) { AffinePoint::from(*self).xy() }
+
+  fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+    Self {
+      x: select_coordinate(a.x, b.x, choice),
+      y: select_coordinate(a.y, b.y, choice),
+      z: select_coordinate(a.z, b.z, choice),
+    }
+  }
+}
+
+impl_curve_ops!(JacobianPoint);
+
+impl<C: EllipticCurve> From<AffinePoint<C>> for JacobianPoint<C> {
+  fn from(point: AffinePoint<C>) -> Self {
+    match point {
+      AffinePoint::Point(x, y) => Self { x, y, z: C::BaseField::ONE },
+      AffinePoint::Infinity => Self::IDENTITY,
+    }
+  }
+}
+
+impl<C: EllipticCurve> From<JacobianPoint<C>> for AffinePoint<C> {
+  /// Normalises the point to $Z = 1$, at the cost of one field inversion.
+  fn from(point: JacobianPoint<C>) -> Self {
+    match point.z.inverse() {
+      Some(z_inv) => {
+        let z_inv2 = z_inv * z_inv;
+        AffinePoint::Point(point.x * z_inv2, point.y * z_inv2 * z_inv)
+      },
+      None => AffinePoint::Infinity,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::curve::pluto_curve::PlutoBaseCurve;
+
+  /// All the points of the curve, including points of order two.
+  fn points() -> Vec<AffinePoint<PlutoBaseCurve>> {
+    let mut points = vec![AffinePoint::Infinity];
+    for x in 0..PlutoPrime::Base as usize {
+      for y in 0..PlutoPrime::Base as usize {
+        let point = AffinePoint::Point(PlutoBaseField::new(x), PlutoBaseField::new(y));
+        if point.is_on_curve() {
+          points.push(point);
+        }
+      }
+    }
+    points
+  }
+
+  #[test]
+  fn affine_conversion() {
+    let g = AffinePoint::<PlutoBaseCurve>::GENERATOR;
+    let jacobian = JacobianPoint::from(g);
+    assert_eq!(jacobian, JacobianPoint::GENERATOR);
+    assert_eq!(AffinePoint::from(jacobian), g);
+    assert_eq!(AffinePoint::<PlutoBaseCurve>::from(JacobianPoint::IDENTITY), AffinePoint::Infinity);
+
+    // (X : Y : Z) = (λ^2 X : λ^3 Y : λ Z)
+    let lambda = PlutoBaseField::new(7);
+    let scaled = JacobianPoint::<PlutoBaseCurve>::new(
+      lambda * lambda,
+      lambda * lambda * lambda * PlutoBaseField::new(2),
+      lambda,
+    )
+    .unwrap();
+    assert_eq!(scaled, jacobian);
+    assert_eq!(scaled.xy(), g.xy());
+  }
+
+  #[test]
+  fn group_law_matches_affine() {
+    let points = points();
+    assert_eq!(points.len(), 102);
+    for &p in &points {
+      for &q in &points {
+        let sum = JacobianPoint::from(p) + JacobianPoint::from(q);
+        assert!(sum.is_on_curve());
+        assert_eq!(AffinePoint::from(sum), p + q);
+      }
+    }
+  }
+
+  #[test]
+  fn scalar_multiplication() {
+    let g = JacobianPoint::<PlutoBaseCurve>::GENERATOR;
+    let mut multiple = JacobianPoint::IDENTITY;
+    for k in 0..PlutoPrime::Scalar as usize {
+      assert_eq!(g * PlutoScalarField::new(k), multiple);
+      multiple += g;
+    }
+    assert_eq!(multiple, JacobianPoint::IDENTITY);
+    assert_eq!(g.order(), 17);
+  }
+}
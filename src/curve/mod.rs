@@ -1,21 +1,89 @@
 //! Elliptic curve operations and types.
 #![doc = include_str!("./README.md")]
-use std::fmt::Debug;
+use std::{error::Error, fmt::Debug};
 
 use algebra::{
-  field::FiniteField,
+  field::{FiniteField, LimbRepr},
   group::{AbelianGroup, FiniteGroup},
   Finite,
 };
+use crypto_bigint::subtle::{Choice, ConditionallySelectable};
 
+use self::{jacobian::JacobianPoint, scalar_mul::double_and_add};
 use super::*;
 use crate::{
   algebra::group::{FiniteCyclicGroup, Group},
   Field, PlutoScalarField,
 };
 
+/// Implements the traits shared by the point representations of [`AffinePoint`]'s group in other
+/// coordinates, given their [`Group`] and [`CurveGroup`] implementations and conversions to and
+/// from [`AffinePoint`].
+macro_rules! impl_curve_ops {
+  ($point:ident) => {
+    impl<C: EllipticCurve> Finite for $point<C> {
+      const ORDER: usize = C::ORDER;
+    }
+    impl<C: EllipticCurve> FiniteGroup for $point<C> {}
+    impl<C: EllipticCurve> AbelianGroup for $point<C> {}
+    impl<C: EllipticCurve> FiniteCyclicGroup for $point<C> {
+      const GENERATOR: Self = Self { x: C::GENERATOR.0, y: C::GENERATOR.1, z: C::BaseField::ONE };
+    }
+    impl<C: EllipticCurve> Default for $point<C> {
+      fn default() -> Self { <Self as FiniteCyclicGroup>::GENERATOR }
+    }
+    impl<C: EllipticCurve> Hash for $point<C> {
+      fn hash<H: std::hash::Hasher>(&self, state: &mut H) { AffinePoint::from(*self).hash(state) }
+    }
+    impl<C: EllipticCurve> Add for $point<C> {
+      type Output = Self;
+
+      fn add(self, rhs: Self) -> Self::Output { self.op(&rhs) }
+    }
+    impl<C: EllipticCurve> AddAssign for $point<C> {
+      fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+    }
+    impl<C: EllipticCurve> Neg for $point<C> {
+      type Output = Self;
+
+      fn neg(self) -> Self::Output { Self { x: self.x, y: -self.y, z: self.z } }
+    }
+    impl<C: EllipticCurve> Sub for $point<C> {
+      type Output = Self;
+
+      fn sub(self, rhs: Self) -> Self::Output { self + -rhs }
+    }
+    impl<C: EllipticCurve> SubAssign for $point<C> {
+      fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+    }
+    impl<C: EllipticCurve> Mul<C::ScalarField> for $point<C> {
+      type Output = Self;
+
+      fn mul(self, rhs: C::ScalarField) -> Self::Output { self.scalar_mul(rhs) }
+    }
+    impl<C: EllipticCurve> MulAssign<C::ScalarField> for $point<C> {
+      fn mul_assign(&mut self, rhs: C::ScalarField) { *self = *self * rhs; }
+    }
+    impl<C: EllipticCurve> Sum for $point<C> {
+      fn sum<I: Iterator<Item = Self>>(iter: I) -> Self { iter.fold(Self::IDENTITY, |x, y| x + y) }
+    }
+  };
+}
+
+pub mod bls12_381;
+pub mod bn254;
+pub mod encoding;
+pub mod hash_to_curve;
+pub mod jacobian;
+pub mod msm;
+pub mod p256;
 pub mod pairing;
 pub mod pluto_curve;
+pub mod point_counting;
+pub mod projective;
+pub mod scalar_mul;
+pub mod secp256k1;
+pub mod subgroup;
 #[cfg(test)] mod tests;
 
 /// Elliptic curve parameters for a curve over a finite field in Weierstrass form
@@ -25,14 +93,23 @@ pub trait EllipticCurve: Copy + Debug + Eq {
   type Coefficient: Field + Into<Self::BaseField>;
 
   /// curve base field element type
-  /// TODO: need to be converted for big integers later
-  type BaseField: FiniteField + Into<usize>;
+  type BaseField: FiniteField;
+
+  /// Curve scalar field type, a prime field whose elements are converted to little-endian limbs
+  /// for scalar multiplication.
+  type ScalarField: LimbRepr;
 
-  /// Curve scalar field type
-  type ScalarField: FiniteField + Into<usize>;
+  /// Order of the subgroup of this elliptic curve generated by [`EllipticCurve::GENERATOR`], which
+  /// defaults to the number of elements in the scalar field. Like [`Finite::ORDER`] of the scalar
+  /// field, evaluating it is a compile-time error for curves over big fields, where the order is
+  /// given by [`LimbRepr::modulus`] of the scalar field instead.
+  const ORDER: usize = <Self::ScalarField as Finite>::ORDER;
 
-  /// Order of this elliptic curve, i.e. number of elements in the scalar field.
-  const ORDER: usize;
+  /// The cofactor `h` of the curve as little-endian 64-bit limbs, such that the curve has
+  /// `h * ORDER` points. Multiplying by `h` maps any point into the subgroup of order `ORDER`. Like
+  /// [`LimbRepr::modulus`], limbs let `h` exceed a `usize`, as for the curves of pairings over big
+  /// fields.
+  const COFACTOR: &'static [u64];
 
   /// Coefficient `a` in the Weierstrass equation of this elliptic curve.
   const EQUATION_A: Self::Coefficient;
@@ -47,7 +124,7 @@ pub trait EllipticCurve: Copy + Debug + Eq {
 /// Curve group representing curve element
 pub trait CurveGroup: FiniteCyclicGroup {
   /// Curve group's base field
-  type BaseField: Field + Into<usize>;
+  type BaseField: Field;
 
   /// Point doubling
   fn double(self) -> Self;
@@ -56,11 +133,45 @@ pub trait CurveGroup: FiniteCyclicGroup {
 
   /// Returns affine point `(x, y)`, returns `(_, _, true)` if point is `infinity`
   fn xy(&self) -> (Self::BaseField, Self::BaseField, bool);
+
+  /// Returns `b` if `choice` is set and `a` otherwise, with the same operations for either choice.
+  /// Unlike [`ConditionallySelectable::conditional_select`] this is not constant-time: the field
+  /// arithmetic it is built on takes time that depends on its operands.
+  fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self;
+}
+
+/// Returns `b` if `choice` is set and `a` otherwise, as $a + c(b - a)$ for the bit $c$ of `choice`,
+/// so that the same field operations run for either choice. Their timing still depends on the
+/// operands.
+pub(crate) fn select_coordinate<F: Field>(a: F, b: F, choice: Choice) -> F {
+  a + (b - a) * F::from(choice.unwrap_u8() as usize)
+}
+
+/// Errors from validating the points of a curve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointError {
+  /// The coordinates don't satisfy the curve equation.
+  NotOnCurve,
+  /// The point is on the curve but not in the subgroup of prime order.
+  NotInSubgroup,
+}
+
+impl Error for PointError {}
+
+impl Display for PointError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match *self {
+      PointError::NotOnCurve => write!(f, "point is not on curve"),
+      PointError::NotInSubgroup => write!(f, "point is not in the prime order subgroup"),
+    }
+  }
 }
 
-// TODO: A potential issue here is that you can have a point that is not on the curve created via
-// this enum. This is a potential issue that should be addressed.
 /// An Affine Coordinate Point on a Weierstrass elliptic curve
+///
+/// The variants can hold coordinates that are not on the curve, use [`AffinePoint::new`] to
+/// validate them, and [`PrimeOrderPoint`](subgroup::PrimeOrderPoint) to also make sure that the
+/// point is in the subgroup of prime order.
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
 pub enum AffinePoint<C: EllipticCurve> {
   /// A point on the curve.
@@ -72,10 +183,46 @@ pub enum AffinePoint<C: EllipticCurve> {
 
 impl<C: EllipticCurve> AffinePoint<C> {
   /// Create a new point on the curve so long as it satisfies the curve equation.
-  pub fn new(x: C::BaseField, y: C::BaseField) -> Self {
+  ///
+  /// ## Errors
+  /// [`PointError::NotOnCurve`] if $y^2 \neq x^3 + ax + b$.
+  pub fn new(x: C::BaseField, y: C::BaseField) -> Result<Self, PointError> {
     let point = Self::Point(x, y);
-    assert!(point.is_on_curve(), "Point is not on curve");
-    point
+    match point.is_on_curve() {
+      true => Ok(point),
+      false => Err(PointError::NotOnCurve),
+    }
+  }
+
+  /// Returns whether $nP = \mathcal{O}$ for `n` given as little-endian 64-bit limbs, computed with
+  /// [`double_and_add`] in $O(\log n)$ group operations.
+  pub fn is_torsion(&self, n: &[u64]) -> bool {
+    double_and_add(JacobianPoint::from(*self), n).is_identity()
+  }
+
+  /// Returns whether the point is in the subgroup of prime order $r$, the order of the scalar
+  /// field, i.e. whether $rP = \mathcal{O}$.
+  pub fn is_in_prime_subgroup(&self) -> bool {
+    double_and_add(JacobianPoint::from(*self), &C::ScalarField::modulus()).is_identity()
+  }
+
+  /// Multiplies the point by the [`EllipticCurve::COFACTOR`] `h`. As the curve has `h * ORDER`
+  /// points, the result is killed by `ORDER`, and is in the subgroup of prime order when `ORDER`
+  /// is prime.
+  pub fn clear_cofactor(&self) -> Self {
+    double_and_add(JacobianPoint::from(*self), C::COFACTOR).into()
+  }
+}
+
+impl<C: EllipticCurve> Hash for AffinePoint<C> {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+    match self {
+      AffinePoint::Point(x, y) => {
+        x.hash(state);
+        y.hash(state);
+      },
+      AffinePoint::Infinity => state.write_u8(0),
+    }
   }
 }
 
@@ -95,7 +242,7 @@ impl<C: EllipticCurve> Group for AffinePoint<C> {
       AffinePoint::Infinity => return Some(*self),
       AffinePoint::Point(x, y) => (*x, *y),
     };
-    Some(AffinePoint::Point(-x, y))
+    Some(AffinePoint::Point(x, -y))
   }
 
   fn scalar_mul(&self, b: Self::Scalar) -> Self { *self * b }
@@ -110,6 +257,8 @@ impl<C: EllipticCurve> CurveGroup for AffinePoint<C> {
   // NOTE: Apparently there is a faster way to do this with twisted curve methods
   fn double(self) -> Self {
     let (x, y) = match self {
+      // points of order two have a vertical tangent
+      AffinePoint::Point(_, y) if y == C::BaseField::ZERO => return AffinePoint::Infinity,
       AffinePoint::Point(x, y) => (x, y),
       AffinePoint::Infinity => return AffinePoint::Infinity,
     };
@@ -121,7 +270,7 @@ impl<C: EllipticCurve> CurveGroup for AffinePoint<C> {
     // 2P = (m^2 - 2x, m(3x - m^2)- y)
     let x_new = m * m - (C::BaseField::ONE + C::BaseField::ONE) * x;
     let y_new = m * ((C::BaseField::ONE + C::BaseField::ONE + C::BaseField::ONE) * x - m * m) - y;
-    AffinePoint::new(x_new, y_new)
+    AffinePoint::Point(x_new, y_new)
   }
 
   fn is_on_curve(&self) -> bool {
@@ -141,6 +290,18 @@ impl<C: EllipticCurve> CurveGroup for AffinePoint<C> {
       AffinePoint::Point(x, y) => (*x, *y, false),
     }
   }
+
+  /// The point at infinity has no coordinates, so the result is rebuilt from the selected
+  /// coordinates and flag of [`CurveGroup::xy`], which branches on whether the selected point is
+  /// at infinity.
+  fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+    let ((a_x, a_y, a_infinity), (b_x, b_y, b_infinity)) = (a.xy(), b.xy());
+    let (x, y) = (select_coordinate(a_x, b_x, choice), select_coordinate(a_y, b_y, choice));
+    match u8::conditional_select(&(a_infinity as u8), &(b_infinity as u8), choice) {
+      1 => AffinePoint::Infinity,
+      _ => AffinePoint::Point(x, y),
+    }
+  }
 }
 
 impl<C: EllipticCurve> FiniteCyclicGroup for AffinePoint<C> {
@@ -154,17 +315,10 @@ impl<C: EllipticCurve> Default for AffinePoint<C> {
 impl<C: EllipticCurve> Mul<C::ScalarField> for AffinePoint<C> {
   type Output = Self;
 
-  /// This is the naive implementation of scalar multiplication
-  /// There is a faster way to do this but this is simpler to reason about for now
+  /// Computes the scalar multiplication with [`double_and_add`] in [`JacobianPoint`] coordinates,
+  /// so that only the final conversion back needs a field inversion.
   fn mul(self, rhs: C::ScalarField) -> Self::Output {
-    if rhs == C::ScalarField::ZERO {
-      return AffinePoint::Infinity;
-    }
-    let mut val = self;
-    for _ in 1..rhs.into() {
-      val += self;
-    }
-    val
+    double_and_add(JacobianPoint::from(self), &rhs.to_limbs()).into()
   }
 }
 
@@ -205,7 +359,7 @@ impl<C: EllipticCurve> Add for AffinePoint<C> {
     let x = lambda * lambda - x1 - x2;
     let y = lambda * (x1 - x) - y1;
 
-    AffinePoint::new(x, y)
+    AffinePoint::Point(x, y)
   }
 }
 
@@ -227,7 +381,7 @@ impl<C: EllipticCurve> Neg for AffinePoint<C> {
       AffinePoint::Point(x, y) => (x, -y),
       AffinePoint::Infinity => return AffinePoint::Infinity,
     };
-    AffinePoint::new(x, y)
+    AffinePoint::Point(x, y)
   }
 }
 
@@ -248,13 +402,6 @@ impl<C: EllipticCurve> std::ops::Mul<AffinePoint<C>> for u32 {
   type Output = AffinePoint<C>;
 
   fn mul(self, val: AffinePoint<C>) -> Self::Output {
-    if self == 0 {
-      return AffinePoint::Infinity;
-    }
-    let mut out = val;
-    for _ in 1..self {
-      out += val;
-    }
-    out
+    double_and_add(JacobianPoint::from(val), &[self as u64]).into()
   }
 }
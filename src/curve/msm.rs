@@ -0,0 +1,181 @@
+//! Multi-scalar multiplication (MSM): computing $\sum_i k_i P_i$ for many points at once, the
+//! main cost of committing to a polynomial or a vector with an SRS or a list of generators.
+//!
+//! ## Pippenger's bucket method
+//! The scalars are cut into windows of `c` bits, and the sum is computed window by window from the
+//! most significant one, doubling `c` times in between as in [`fixed_window`]. Within a window,
+//! every point is added to the bucket $B_d$ of its digit $d$, and
+//! $$ \sum_{d = 1}^{2^c - 1} d B_d = \sum_{d = 1}^{2^c - 1} \sum_{e \geq d} B_e $$
+//! is evaluated with running sums from the largest bucket down, for a total of about
+//! $\frac{b}{c}(n + 2^{c+1})$ additions for `n` scalars of `b` bits, instead of $n b / 2$ for
+//! separate [`double_and_add`]s. The window is chosen around $\ln n$ to balance the two terms.
+//!
+//! ## Fixed bases
+//! When the points are known in advance, like the powers of $\tau$ in a KZG SRS, [`FixedBaseMsm`]
+//! precomputes $2^{cj} P_i$ for every window `j`. All windows of all scalars then share a single
+//! set of buckets, and no doublings are left.
+//!
+//! [`fixed_window`]: super::scalar_mul::fixed_window
+//! [`double_and_add`]: super::scalar_mul::double_and_add
+use super::{
+  scalar_mul::{bits, window},
+  *,
+};
+
+/// Returns the window width used for `n` points: about $\ln n$, computed as $0.69 \log_2 n$, plus
+/// two, with a minimum of three for small inputs.
+fn window_size(n: usize) -> usize {
+  match n {
+    0..32 => 3,
+    _ => n.ilog2() as usize * 69 / 100 + 2,
+  }
+}
+
+/// Returns $\sum_{d \geq 1} d B_d$ for the buckets $B_1, B_2, \ldots$ with running sums: after
+/// adding bucket $B_d$, the running sum is $\sum_{e \geq d} B_e$, and it is added to the total once
+/// for every $d$.
+fn sum_buckets<G: CurveGroup>(buckets: &[G]) -> G {
+  let mut running = G::IDENTITY;
+  let mut total = G::IDENTITY;
+  for bucket in buckets.iter().rev() {
+    running = running.op(bucket);
+    total = total.op(&running);
+  }
+  total
+}
+
+/// Computes $\sum_i k_i P_i$ with Pippenger's bucket method, with the scalars given as
+/// little-endian 64-bit limbs.
+///
+/// ## Panics
+/// If there are not as many scalars as points.
+pub fn msm<G: CurveGroup, S: AsRef<[u64]>>(points: &[G], scalars: &[S]) -> G {
+  assert_eq!(points.len(), scalars.len(), "there should be as many scalars as points");
+  let c = window_size(points.len());
+  let windows = scalars.iter().map(|s| bits(s.as_ref())).max().unwrap_or(0).div_ceil(c);
+  (0..windows).rev().fold(G::IDENTITY, |acc, j| {
+    let acc = (0..c).fold(acc, |acc, _| acc.double());
+    // bucket d - 1 holds the sum of the points whose digit in this window is d
+    let mut buckets = vec![G::IDENTITY; (1 << c) - 1];
+    for (point, scalar) in points.iter().zip(scalars) {
+      let digit = window(scalar.as_ref(), j * c, c);
+      if digit != 0 {
+        buckets[digit - 1] = buckets[digit - 1].op(point);
+      }
+    }
+    acc.op(&sum_buckets(&buckets))
+  })
+}
+
+/// Precomputed multiples of fixed base points $P_i$, to compute $\sum_i k_i P_i$ for many
+/// different scalars of at most `bits` bits without any doubling.
+#[derive(Clone, Debug)]
+pub struct FixedBaseMsm<G: CurveGroup> {
+  window:    usize,
+  bits:      usize,
+  /// `multiples[i][j]` is $2^{cj} P_i$.
+  multiples: Vec<Vec<G>>,
+}
+
+impl<G: CurveGroup> FixedBaseMsm<G> {
+  /// Precomputes the multiples $2^{cj} P_i$ of the `bases` for scalars of at most `bits` bits.
+  pub fn new(bases: &[G], bits: usize) -> Self {
+    let window = window_size(bases.len());
+    let windows = bits.div_ceil(window);
+    let multiples = bases
+      .iter()
+      .map(|&base| {
+        let mut multiple = base;
+        (0..windows)
+          .map(|_| {
+            let current = multiple;
+            multiple = (0..window).fold(multiple, |acc, _| acc.double());
+            current
+          })
+          .collect()
+      })
+      .collect();
+    Self { window, bits, multiples }
+  }
+
+  /// Returns the number of base points.
+  pub fn len(&self) -> usize { self.multiples.len() }
+
+  /// Returns whether there are no base points.
+  pub fn is_empty(&self) -> bool { self.multiples.is_empty() }
+
+  /// Computes $\sum_i k_i P_i$ for the first `scalars.len()` bases, with the scalars given as
+  /// little-endian 64-bit limbs: $k_i = \sum_j d_{ij} 2^{cj}$, so every digit $d_{ij}$ adds the
+  /// precomputed $2^{cj} P_i$ to the bucket $B_{d_{ij}}$.
+  ///
+  /// ## Panics
+  /// If there are more scalars than bases, or a scalar has more bits than given to
+  /// [`FixedBaseMsm::new`].
+  pub fn msm<S: AsRef<[u64]>>(&self, scalars: &[S]) -> G {
+    assert!(scalars.len() <= self.len(), "there should be at most as many scalars as bases");
+    let mut buckets = vec![G::IDENTITY; (1 << self.window) - 1];
+    for (multiples, scalar) in self.multiples.iter().zip(scalars) {
+      let scalar = scalar.as_ref();
+      assert!(bits(scalar) <= self.bits, "scalar is too large");
+      for (j, multiple) in multiples.iter().enumerate() {
+        let digit = window(scalar, j * self.window, self.window);
+        if digit != 0 {
+          buckets[digit - 1] = buckets[digit - 1].op(multiple);
+        }
+      }
+    }
+    sum_buckets(&buckets)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rand::{rngs::StdRng, Rng, SeedableRng};
+
+  use super::*;
+  use crate::curve::{
+    jacobian::JacobianPoint, pluto_curve::PlutoExtendedCurve, projective::ProjectivePoint,
+    scalar_mul::double_and_add,
+  };
+
+  /// Random multiples of the generator with random scalars of `limbs` limbs.
+  fn instance<G: CurveGroup>(n: usize, limbs: usize, rng: &mut StdRng) -> (Vec<G>, Vec<Vec<u64>>) {
+    let points = (0..n).map(|_| double_and_add(G::GENERATOR, &[rng.gen_range(0..17)])).collect();
+    let scalars = (0..n).map(|_| (0..limbs).map(|_| rng.gen()).collect()).collect();
+    (points, scalars)
+  }
+
+  fn naive<G: CurveGroup>(points: &[G], scalars: &[Vec<u64>]) -> G {
+    points.iter().zip(scalars).fold(G::IDENTITY, |acc, (&p, s)| acc.op(&double_and_add(p, s)))
+  }
+
+  #[test]
+  fn pippenger() {
+    let mut rng = StdRng::seed_from_u64(0);
+    for (n, limbs) in [(0, 1), (1, 1), (5, 1), (40, 1), (100, 2), (7, 0)] {
+      let (points, scalars) = instance::<JacobianPoint<PlutoExtendedCurve>>(n, limbs, &mut rng);
+      assert_eq!(msm(&points, &scalars), naive(&points, &scalars), "n = {n}");
+    }
+    let (points, scalars) = instance::<ProjectivePoint<PlutoExtendedCurve>>(50, 1, &mut rng);
+    assert_eq!(msm(&points, &scalars), naive(&points, &scalars));
+  }
+
+  #[test]
+  fn fixed_base() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let (points, _) = instance::<JacobianPoint<PlutoExtendedCurve>>(40, 1, &mut rng);
+    let table = FixedBaseMsm::new(&points, 128);
+    assert_eq!(table.len(), 40);
+    for n in [0, 1, 10, 40] {
+      let (_, scalars) = instance::<JacobianPoint<PlutoExtendedCurve>>(n, 2, &mut rng);
+      assert_eq!(table.msm(&scalars), naive(&points[..n], &scalars));
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "scalar is too large")]
+  fn fixed_base_scalar_too_large() {
+    let table = FixedBaseMsm::new(&[JacobianPoint::<PlutoExtendedCurve>::GENERATOR], 64);
+    table.msm(&[[0, 1]]);
+  }
+}
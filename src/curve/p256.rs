@@ -0,0 +1,77 @@
+//! The NIST P-256 curve $y^2 = x^3 - 3x + b$ of
+//! [FIPS 186-5](https://csrc.nist.gov/pubs/sp/800/186/final), also known as secp256r1, used in TLS
+//! and WebAuthn among others.
+//!
+//! Its base field is the 256-bit prime field [`P256BaseField`] of order
+//! $p = 2^{256} - 2^{224} + 2^{192} + 2^{96} - 1$, and the curve has a prime number $n$ of points,
+//! so every point other than the point at infinity generates the whole group and the cofactor is 1.
+//! The order $n$ does not fit in a `usize`, so [`EllipticCurve::ORDER`] cannot be evaluated and the
+//! order of the group is given by the modulus of the [`P256ScalarField`] instead.
+
+use crypto_bigint::U256;
+
+use super::*;
+use crate::algebra::field::big_prime::{P256BaseField, P256ScalarField};
+
+/// The [`P256`] curve, defined by the equation `y^2 = x^3 - 3x + b` over the [`P256BaseField`].
+/// The coefficient `a = -3` makes doubling in Jacobian coordinates a bit cheaper.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct P256;
+
+impl EllipticCurve for P256 {
+  type BaseField = P256BaseField;
+  type Coefficient = P256BaseField;
+  type ScalarField = P256ScalarField;
+
+  const COFACTOR: &'static [u64] = &[1];
+  const EQUATION_A: Self::Coefficient = P256BaseField::new(U256::from_be_hex(
+    "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+  ));
+  const EQUATION_B: Self::Coefficient = P256BaseField::new(U256::from_be_hex(
+    "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+  ));
+  const GENERATOR: (Self::BaseField, Self::BaseField) = (
+    P256BaseField::new(U256::from_be_hex(
+      "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+    )),
+    P256BaseField::new(U256::from_be_hex(
+      "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+    )),
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn generator() {
+    let g = AffinePoint::<P256>::GENERATOR;
+    assert!(g.is_on_curve());
+    assert!(g.is_in_prime_subgroup());
+    assert_eq!(g * -P256ScalarField::ONE, -g);
+    assert_eq!(g * P256ScalarField::ZERO, AffinePoint::Infinity);
+  }
+
+  #[test]
+  fn equation_a() {
+    assert_eq!(P256::EQUATION_A, -P256BaseField::from(3u64));
+  }
+
+  #[test]
+  fn scalar_mul() {
+    // 2G from the list of small multiples of the generator
+    let two_g = AffinePoint::<P256>::new(
+      P256BaseField::new(U256::from_be_hex(
+        "7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+      )),
+      P256BaseField::new(U256::from_be_hex(
+        "07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1",
+      )),
+    )
+    .unwrap();
+    let g = AffinePoint::<P256>::GENERATOR;
+    assert_eq!(g.double(), two_g);
+    assert_eq!(g * P256ScalarField::from(2u64), two_g);
+  }
+}
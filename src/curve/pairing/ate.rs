@@ -0,0 +1,413 @@
+//! The optimal ate pairing of BN and BLS12 curves.
+//!
+//! Both families have embedding degree 12: the $r$-torsion is defined over $\mathbb{F}_{p^{12}}$,
+//! where the pairing takes its values. Its two inputs come from
+//! - $\mathbb{G}_1 = E(\mathbb{F}_p)\[r\]$, points with coordinates in the base field;
+//! - $\mathbb{G}_2$, the $r$-torsion points on which the Frobenius map acts as multiplication by
+//!   $p$. They are represented on a sextic twist $E'$ over $\mathbb{F}_{p^2}$, which is much
+//!   cheaper, and mapped to $E(\mathbb{F}_{p^{12}})$ by the untwisting isomorphism $\psi$ only
+//!   where needed.
+//!
+//! ## Miller loop
+//! The Tate pairing runs Miller's algorithm over the bits of $r$. The ate pairing swaps the roles
+//! of the points and loops over a much smaller multiple of $Q$: the curve parameter $x$ for BLS12
+//! curves, and $6x + 2$ for BN curves, which need two extra lines through the Frobenius images
+//! $\pi(Q)$ and $\pi^2(Q)$. Vertical lines are skipped, as their values lie in a subfield that the
+//! final exponentiation sends to 1.
+//!
+//! ## Final exponentiation
+//! The output of the Miller loop is only defined up to $r$-th powers, and is raised to
+//! $(p^{12} - 1) / r$ to get a unique $r$-th root of unity. The exponent is split as
+//! $$ \frac{p^{12} - 1}{r} = (p^6 - 1)(p^2 + 1) \cdot \frac{p^4 - p^2 + 1}{r}. $$
+//! The easy part costs a conjugation, an inversion and a Frobenius map. Its output is in the
+//! cyclotomic subgroup, where inverting is conjugating. The hard part is written in base $p$ with
+//! digits that are polynomials in $x$, so that it only takes a few exponentiations by $x$ and
+//! Frobenius maps.
+use super::*;
+use crate::{
+  algebra::field::{
+    extension::tower::{CubicExtension, QuadraticExtension, TowerConfig},
+    FrobeniusMap,
+  },
+  curve::subgroup::PrimeOrderPoint,
+};
+
+/// The families of pairing-friendly curves supported by the optimal ate pairing, which fix the
+/// loop of the Miller loop and the hard part of the final exponentiation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurveFamily {
+  /// Barreto-Naehrig curves, with $p = 36x^4 + 36x^3 + 24x^2 + 6x + 1$ and
+  /// $r = 36x^4 + 36x^3 + 18x^2 + 6x + 1$.
+  Bn,
+  /// Barreto-Lynn-Scott curves of embedding degree 12, with
+  /// $p = (x - 1)^2 (x^4 - x^2 + 1) / 3 + x$ and $r = x^4 - x^2 + 1$.
+  Bls12,
+}
+
+/// How the sextic twist $E': y^2 = x^3 + b'$ relates to $E: y^2 = x^3 + b$, with $w^6 = \xi$.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TwistType {
+  /// $b' = b / \xi$, untwisted by $\psi(x, y) = (x w^2, y w^3)$.
+  D,
+  /// $b' = b \xi$, untwisted by $\psi(x, y) = (x / w^2, y / w^3)$.
+  M,
+}
+
+/// Parameters of a BN or BLS12 curve for the optimal ate pairing. The extension tower must be
+/// `Fp12 = Fp6[w]/(w^2 - v)` over `Fp6 = Fp2[v]/(v^3 - ξ)`, and both curves must have `a = 0`.
+pub trait PairingConfig {
+  /// The curve over the base field, whose points of prime order form $\mathbb{G}_1$.
+  type G1: EllipticCurve;
+
+  /// The sextic twist over `Fp2`, whose points of prime order represent $\mathbb{G}_2$.
+  type G2: EllipticCurve<
+    BaseField = QuadraticExtension<Self::Fp2Config>,
+    ScalarField = <Self::G1 as EllipticCurve>::ScalarField,
+  >;
+
+  /// The quadratic extension `Fp2` of the base field.
+  type Fp2Config: TowerConfig<2, BaseField = <Self::G1 as EllipticCurve>::BaseField>;
+
+  /// The cubic extension `Fp6` of `Fp2`.
+  type Fp6Config: TowerConfig<3, BaseField = QuadraticExtension<Self::Fp2Config>>;
+
+  /// The quadratic extension `Fp12` of `Fp6`, where the pairing takes its values.
+  type Fp12Config: TowerConfig<2, BaseField = CubicExtension<Self::Fp6Config>>;
+
+  /// The family of the curve.
+  const FAMILY: CurveFamily;
+
+  /// The type of the twist used for $\mathbb{G}_2$.
+  const TWIST: TwistType;
+
+  /// The absolute value of the curve parameter $x$.
+  const X: u64;
+
+  /// Whether the curve parameter $x$ is negative.
+  const X_IS_NEGATIVE: bool;
+}
+
+/// The field `Fp2` of the coordinates of $\mathbb{G}_2$.
+pub type Fp2<P> = QuadraticExtension<<P as PairingConfig>::Fp2Config>;
+
+/// The field `Fp6` between `Fp2` and `Fp12`.
+pub type Fp6<P> = CubicExtension<<P as PairingConfig>::Fp6Config>;
+
+/// The field `Fp12` where the pairing takes its values.
+pub type Fp12<P> = QuadraticExtension<<P as PairingConfig>::Fp12Config>;
+
+/// The points of $\mathbb{G}_1$.
+pub type G1<P> = PrimeOrderPoint<<P as PairingConfig>::G1>;
+
+/// The points of $\mathbb{G}_2$, on the twist.
+pub type G2<P> = PrimeOrderPoint<<P as PairingConfig>::G2>;
+
+/// Computes the optimal ate pairing $e(P, Q)$, a bilinear map
+/// $\mathbb{G}_1 \times \mathbb{G}_2 \to \mu_r \subset \mathbb{F}_{p^{12}}$.
+///
+/// The inputs are [`PrimeOrderPoint`]s, so that they are known to be $r$-torsion points.
+pub fn pairing<P: PairingConfig>(p: G1<P>, q: G2<P>) -> Fp12<P> {
+  final_exponentiation::<P>(miller_loop::<P>(p, q))
+}
+
+/// Computes the product of pairings $\prod_i e(P_i, Q_i)$ with a single final exponentiation, as
+/// used to check equations like $e(P_1, Q_1) = e(P_2, Q_2)$ in KZG or BLS signature verification.
+pub fn multi_pairing<P: PairingConfig>(pairs: &[(G1<P>, G2<P>)]) -> Fp12<P> {
+  let untwist = Untwist::<P>::new();
+  let f = pairs.iter().map(|&(p, q)| untwisted_miller_loop::<P>(p, q, &untwist)).product();
+  final_exponentiation::<P>(f)
+}
+
+/// Runs the optimal ate Miller loop, computing $f_{6x+2, Q}(P) \cdot l_{[6x+2]Q, \pi(Q)}(P) \cdot
+/// l_{[6x+2]Q + \pi(Q), -\pi^2(Q)}(P)$ for BN curves, and $f_{x, Q}(P)$ for BLS12 curves, up to
+/// factors removed by the [`final_exponentiation`].
+pub fn miller_loop<P: PairingConfig>(p: G1<P>, q: G2<P>) -> Fp12<P> {
+  untwisted_miller_loop::<P>(p, q, &Untwist::new())
+}
+
+/// The [`miller_loop`], with the constants of the untwisting isomorphism computed by the caller.
+fn untwisted_miller_loop<P: PairingConfig>(p: G1<P>, q: G2<P>, untwist: &Untwist<P>) -> Fp12<P> {
+  let (AffinePoint::Point(x_p, y_p), q @ AffinePoint::Point(..)) = (p.point(), q.point()) else {
+    return Fp12::<P>::ONE;
+  };
+  let p = (embed::<P>(Fp2::<P>::from_base(x_p)), embed::<P>(Fp2::<P>::from_base(y_p)));
+
+  let loop_count: u128 = match (P::FAMILY, P::X_IS_NEGATIVE) {
+    (CurveFamily::Bn, false) => 6 * P::X as u128 + 2,
+    (CurveFamily::Bn, true) => 6 * P::X as u128 - 2,
+    (CurveFamily::Bls12, _) => P::X as u128,
+  };
+
+  // f_{2m} = f_m^2 l_{[m]Q, [m]Q} and f_{m+1} = f_m l_{[m]Q, Q}, from the top bit down
+  let mut f = Fp12::<P>::ONE;
+  let mut t = q;
+  for i in (0..loop_count.ilog2()).rev() {
+    f = f * f * line::<P>(t, t, p, untwist);
+    t = t.double();
+    if (loop_count >> i) & 1 == 1 {
+      f *= line::<P>(t, q, p, untwist);
+      t += q;
+    }
+  }
+
+  // f_{-m} = 1 / (f_m v_{[m]Q}), and inverting is conjugating after the final exponentiation
+  if P::X_IS_NEGATIVE {
+    f = f.conjugate();
+    t = -t;
+  }
+
+  if P::FAMILY == CurveFamily::Bn {
+    let q_1 = frobenius::<P>(q, untwist);
+    let q_2 = -frobenius::<P>(q_1, untwist);
+    f *= line::<P>(t, q_1, p, untwist);
+    t += q_1;
+    f *= line::<P>(t, q_2, p, untwist);
+  }
+  f
+}
+
+/// Raises the output of the [`miller_loop`] to the power $(p^{12} - 1) / r$.
+///
+/// The hard part of BN curves computes exactly $(p^4 - p^2 + 1) / r$ with
+/// $\lambda_0 + \lambda_1 p + \lambda_2 p^2 + \lambda_3 p^3$, where
+/// $\lambda_3 = 1$, $\lambda_2 = 6x^2 + 1$, $\lambda_1 = -36x^3 - 18x^2 - 12x + 1$ and
+/// $\lambda_0 = -36x^3 - 30x^2 - 18x - 2$.
+///
+/// The hard part of BLS12 curves factors as
+/// $$ \frac{p^4 - p^2 + 1}{r} = \frac{x - 1}{3} (x - 1) (x + p) (x^2 + p^2 - 1) + 1, $$
+/// where $3$ divides $x - 1$. The exponentiation by $(x - 1) / 3$ is done directly, the others by
+/// exponentiations by $x$ and Frobenius maps. Faster chains like that of
+/// [Hayashida, Hayasaka and Teruya](https://eprint.iacr.org/2020/875) drop this step and compute
+/// the cube of the pairing instead.
+///
+/// ## Panics
+/// If `f` is zero, which the Miller loop never outputs.
+pub fn final_exponentiation<P: PairingConfig>(f: Fp12<P>) -> Fp12<P> {
+  // f^(p^6 - 1), as f^(p^6) is the conjugate of f
+  let f = f.conjugate() * f.inverse().expect("the output of the Miller loop is never zero");
+  // f^(p^2 + 1)
+  let f = f.frobenius_map(2) * f;
+
+  match P::FAMILY {
+    CurveFamily::Bn => {
+      let a = exp_by_x::<P>(f);
+      let b = exp_by_x::<P>(a);
+      let c = exp_by_x::<P>(b);
+      let l_0 = (c.pow(36) * b.pow(30) * a.pow(18) * f.pow(2)).conjugate();
+      let l_1 = (c.pow(36) * b.pow(18) * a.pow(12)).conjugate() * f;
+      let l_2 = b.pow(6) * f;
+      l_0 * l_1.frobenius_map(1) * l_2.frobenius_map(2) * f.frobenius_map(3)
+    },
+    CurveFamily::Bls12 => {
+      // f^((x - 1) / 3), where x - 1 = -(|x| + 1) for a negative x
+      let a = match P::X_IS_NEGATIVE {
+        true => f.pow((P::X as usize + 1) / 3).conjugate(),
+        false => f.pow((P::X as usize - 1) / 3),
+      };
+      let b = exp_by_x::<P>(a) * a.conjugate();
+      let c = exp_by_x::<P>(b) * b.frobenius_map(1);
+      let d = exp_by_x::<P>(exp_by_x::<P>(c)) * c.frobenius_map(2) * c.conjugate();
+      d * f
+    },
+  }
+}
+
+/// Raises an element of the cyclotomic subgroup to the power $x$, conjugating for negative $x$.
+fn exp_by_x<P: PairingConfig>(f: Fp12<P>) -> Fp12<P> {
+  let f_x = f.pow(P::X as usize);
+  match P::X_IS_NEGATIVE {
+    true => f_x.conjugate(),
+    false => f_x,
+  }
+}
+
+/// Embeds an element of `Fp2` into `Fp12`.
+fn embed<P: PairingConfig>(x: Fp2<P>) -> Fp12<P> { Fp12::<P>::from_base(Fp6::<P>::from_base(x)) }
+
+/// Returns the element of `Fp2` that `x` is the embedding of.
+fn project<P: PairingConfig>(x: Fp12<P>) -> Fp2<P> {
+  let x_0 = x.coeffs[0].coeffs[0];
+  debug_assert_eq!(embed::<P>(x_0), x, "the element is not in Fp2");
+  x_0
+}
+
+/// The coefficients of the untwisting isomorphism $\psi(x, y) = (c_x x, c_y y)$ and the constants
+/// derived from them, computed once per pairing rather than for every line.
+struct Untwist<P: PairingConfig> {
+  c_x:       Fp12<P>,
+  c_y:       Fp12<P>,
+  /// $c_y / c_x$, which maps the slope of a line on the twist to that of its image by $\psi$.
+  slope:     Fp12<P>,
+  /// $c_x^p / c_x$ and $c_y^p / c_y$, which lie in `Fp2`.
+  frobenius: (Fp2<P>, Fp2<P>),
+}
+
+impl<P: PairingConfig> Untwist<P> {
+  fn new() -> Self {
+    let w = Fp12::<P>::new([Fp6::<P>::ZERO, Fp6::<P>::ONE]);
+    let (w_2, w_3) = (w * w, w * w * w);
+    let (c_x, c_y) = match P::TWIST {
+      TwistType::D => (w_2, w_3),
+      TwistType::M => (w_2.inverse().unwrap(), w_3.inverse().unwrap()),
+    };
+    let gamma = |c: Fp12<P>| project::<P>(c.frobenius_map(1) / c);
+    Self { c_x, c_y, slope: c_y / c_x, frobenius: (gamma(c_x), gamma(c_y)) }
+  }
+}
+
+/// Evaluates at $P$ the line through $\psi(T)$ and $\psi(S)$, or the tangent at $\psi(T)$ when
+/// $S = T$. Its slope is $\lambda c_y / c_x$ for the slope $\lambda$ of the line through $T$ and
+/// $S$ on the twist.
+///
+/// ## Panics
+/// If `t` or `s` is the point at infinity.
+fn line<P: PairingConfig>(
+  t: AffinePoint<P::G2>,
+  s: AffinePoint<P::G2>,
+  (x_p, y_p): (Fp12<P>, Fp12<P>),
+  untwist: &Untwist<P>,
+) -> Fp12<P> {
+  let (c_x, c_y) = (untwist.c_x, untwist.c_y);
+  let (AffinePoint::Point(x_t, y_t), AffinePoint::Point(x_s, y_s)) = (t, s) else {
+    panic!("Cannot use point at infinity");
+  };
+  let slope = if x_t != x_s {
+    (y_s - y_t) / (x_s - x_t)
+  } else if y_t == y_s {
+    let a: Fp2<P> = <P::G2 as EllipticCurve>::EQUATION_A.into();
+    (Fp2::<P>::from(3) * x_t * x_t + a) / (Fp2::<P>::from(2) * y_t)
+  } else {
+    // the vertical line through T and -T
+    return x_p - c_x * embed::<P>(x_t);
+  };
+  y_p - c_y * embed::<P>(y_t) - embed::<P>(slope) * untwist.slope * (x_p - c_x * embed::<P>(x_t))
+}
+
+/// The Frobenius endomorphism $\psi^{-1} \circ \pi \circ \psi$ on the twist,
+/// $(x, y) \mapsto (x^p c_x^p / c_x, y^p c_y^p / c_y)$, where $c^p / c$ is in `Fp2`.
+fn frobenius<P: PairingConfig>(q: AffinePoint<P::G2>, untwist: &Untwist<P>) -> AffinePoint<P::G2> {
+  let (gamma_x, gamma_y) = untwist.frobenius;
+  match q {
+    AffinePoint::Point(x, y) =>
+      AffinePoint::Point(x.frobenius_map(1) * gamma_x, y.frobenius_map(1) * gamma_y),
+    AffinePoint::Infinity => AffinePoint::Infinity,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::array;
+
+  use rand::Rng;
+
+  use super::*;
+  use crate::{
+    algebra::field::{big_prime::Bls12381BaseField, pow_limbs, FieldEncoding, LimbRepr},
+    curve::{bls12_381::Bls12381, bn254::Bn254},
+  };
+
+  type Scalar<P> = <<P as PairingConfig>::G1 as EllipticCurve>::ScalarField;
+
+  /// Parses a big-endian hexadecimal integer into little-endian limbs.
+  fn limbs(hex: &str) -> Vec<u64> {
+    let bytes = hex::decode(format!("{:0>1$}", hex, hex.len().next_multiple_of(16))).unwrap();
+    bytes.rchunks(8).map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap())).collect()
+  }
+
+  fn bilinearity<P: PairingConfig>()
+  where rand::distributions::Standard: rand::distributions::Distribution<Scalar<P>> {
+    let mut rng = rand::thread_rng();
+    let (g_1, g_2) = (G1::<P>::GENERATOR, G2::<P>::GENERATOR);
+    let (a, b) = (rng.gen::<Scalar<P>>(), rng.gen::<Scalar<P>>());
+
+    let e = pairing::<P>(g_1, g_2);
+    assert_ne!(e, Fp12::<P>::ONE);
+    assert_eq!(pow_limbs(e, &Scalar::<P>::modulus()), Fp12::<P>::ONE);
+
+    let e_ab = pairing::<P>(g_1 * a, g_2 * b);
+    assert_eq!(e_ab, pairing::<P>(g_1 * (a * b), g_2));
+    assert_eq!(e_ab, pairing::<P>(g_1, g_2 * (a * b)));
+    assert_eq!(pairing::<P>(g_1 * a + g_1, g_2), pairing::<P>(g_1 * a, g_2) * e);
+
+    assert_eq!(multi_pairing::<P>(&[(g_1 * a, g_2), (-g_1, g_2 * a)]), Fp12::<P>::ONE);
+    assert_eq!(pairing::<P>(G1::<P>::IDENTITY, g_2), Fp12::<P>::ONE);
+    assert_eq!(pairing::<P>(g_1, G2::<P>::IDENTITY), Fp12::<P>::ONE);
+  }
+
+  #[test]
+  fn bn254_bilinearity() { bilinearity::<Bn254>(); }
+
+  #[test]
+  fn bls12_381_bilinearity() { bilinearity::<Bls12381>(); }
+
+  /// Checks that the hard part is a plain exponentiation by `exponent` after the easy part.
+  fn hard_part<P: PairingConfig>(exponent: &str)
+  where rand::distributions::Standard: rand::distributions::Distribution<Fp12<P>> {
+    let f = rand::thread_rng().gen::<Fp12<P>>();
+    let easy = f.conjugate() * f.inverse().unwrap();
+    let easy = easy.frobenius_map(2) * easy;
+    assert_eq!(final_exponentiation::<P>(f), pow_limbs(easy, &limbs(exponent)));
+  }
+
+  #[test]
+  fn bn254_hard_part() {
+    // (p^4 - p^2 + 1) / r
+    hard_part::<Bn254>(
+      "1baaa710b0759ad331ec15183177faf6c0eb522d5b122784e529a5861876f6b3b1b1355d189227d79581e16f3f\
+       d90c66b887d56d5095f23aaa441e3954bcf8adcc7b44c87cdbacff1154e7e1da014fd5abf5cc4f49c36d4e81bb482ccdf42b1",
+    );
+  }
+
+  #[test]
+  fn bls12_381_hard_part() {
+    // (p^4 - p^2 + 1) / r
+    hard_part::<Bls12381>(
+      "f686b3d807d01c0bd38c3195c899ed3cde88eeb996ca394506632528d6a9a2f230063cf081517f68f7764c28b6\
+       f8ae5a72bce8d63cb9f827eca0ba621315b2076995003fc77a17988f8761bdc51dc2378b9039096d1b767f17fc\
+       bde783765915c97f36c6f18212ed0b283ed237db421d160aeb6a1e79983774940996754c8c71a2629b0dea2369\
+       05ce937335d5b68fa9912aae208ccf1e516c3f438e3ba79",
+    );
+  }
+
+  #[test]
+  fn bls12_381_generators() {
+    // the generator of GT published by the zkcrypto and arkworks implementations of BLS12-381,
+    // c0.c0.c0 first, whose final exponentiations compute the cube of the pairing
+    let published = [
+      "1250ebd871fc0a92a7b2d83168d0d727272d441befa15c503dd8e90ce98db3e7b6d194f60839c508a84305aaca1789b6",
+      "089a1c5b46e5110b86750ec6a532348868a84045483c92b7af5af689452eafabf1a8943e50439f1d59882a98eaa0170f",
+      "1368bb445c7c2d209703f239689ce34c0378a68e72a6b3b216da0e22a5031b54ddff57309396b38c881c4c849ec23e87",
+      "193502b86edb8857c273fa075a50512937e0794e1e65a7617c90d8bd66065b1fffe51d7a579973b1315021ec3c19934f",
+      "01b2f522473d171391125ba84dc4007cfbf2f8da752f7c74185203fcca589ac719c34dffbbaad8431dad1c1fb597aaa5",
+      "018107154f25a764bd3c79937a45b84546da634b8f6be14a8061e55cceba478b23f7dacaa35c8ca78beae9624045b4b6",
+      "19f26337d205fb469cd6bd15c3d5a04dc88784fbb3d0b2dbdea54d43b2b73f2cbb12d58386a8703e0f948226e47ee89d",
+      "06fba23eb7c5af0d9f80940ca771b6ffd5857baaf222eb95a7d2809d61bfe02e1bfd1b68ff02f0b8102ae1c2d5d5ab1a",
+      "11b8b424cd48bf38fcef68083b0b0ec5c81a93b330ee1a677d0d15ff7b984e8978ef48881e32fac91b93b47333e2ba57",
+      "03350f55a7aefcd3c31b4fcb6ce5771cc6a0e9786ab5973320c806ad360829107ba810c5a09ffdd9be2291a0c25a99a2",
+      "04c581234d086a9902249b64728ffd21a189e87935a954051c7cdba7b3872629a4fafc05066245cb9108f0242d0fe3ef",
+      "0f41e58663bf08cf068672cbd01a7ec73baca4d72ca93544deff686bfd6df543d48eaa24afe47e1efde449383b676631",
+    ]
+    .map(|c| Bls12381BaseField::from_be_bytes(&hex::decode(c).unwrap()).unwrap());
+    let published = Fp12::<Bls12381>::new(array::from_fn(|i| {
+      Fp6::<Bls12381>::new(array::from_fn(|j| {
+        Fp2::<Bls12381>::new([0, 1].map(|k| published[6 * i + 2 * j + k]))
+      }))
+    }));
+    let e = pairing::<Bls12381>(G1::<Bls12381>::GENERATOR, G2::<Bls12381>::GENERATOR);
+    assert_eq!(e.pow(3), published);
+    assert_ne!(e, published);
+  }
+
+  #[test]
+  fn frobenius_on_twist() {
+    // π acts as multiplication by p on G2, and p = 6x^2 mod r for BN curves
+    let q = G2::<Bn254>::GENERATOR;
+    let x = Scalar::<Bn254>::from(Bn254::X);
+    let p = Scalar::<Bn254>::from(6u64) * x * x;
+    assert_eq!(frobenius::<Bn254>(q.point(), &Untwist::new()), (q * p).point());
+    // and p = x mod r for BLS12 curves
+    let q = G2::<Bls12381>::GENERATOR;
+    let x = -Scalar::<Bls12381>::from(Bls12381::X);
+    assert_eq!(frobenius::<Bls12381>(q.point(), &Untwist::new()), (q * x).point());
+  }
+}
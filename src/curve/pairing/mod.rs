@@ -1,8 +1,25 @@
-//! Pairing operations for the Pluto curve.
+//! Pairings on elliptic curves.
+//!
+//! This module computes the simplified Tate [`pairing`] and the [`weil_pairing`] with a Miller loop
+//! over the curve's own base field, for the Pluto curve and other curves small enough that the
+//! subgroup order fits in a `usize`.
+//!
+//! The [`ate`] module implements the optimal ate pairing of the BN and BLS12 curves used in
+//! practice, over towers of extensions of big prime fields. The [`torsion`] module finds the
+//! embedding degree and the $r$-torsion points the pairings are evaluated on, and [`toy`] searches
+//! for small pairing-friendly curves to experiment with beyond the Pluto curve.
 
 use std::fmt::Debug;
 
 use super::*;
+use crate::algebra::{
+  field::pow_limbs,
+  limbs::{div_rem_small, sub_small},
+};
+
+pub mod ate;
+pub mod torsion;
+pub mod toy;
 
 /// Compute the simplified Tate pairing of two points on the curve.
 ///
@@ -22,8 +39,8 @@ use super::*;
 /// The result of the pairing, an element of rth root of unity in base field of the curve.
 ///
 /// ## Panics
-/// Panics if either input is not in the R-torsion group via an exhaustive check that the point
-/// added to itself R times is the point itself for both inputs.
+/// Panics if either input is not in the R-torsion group, i.e. if $RP \neq \mathcal{O}$, checked
+/// with [`AffinePoint::is_torsion`].
 ///
 /// ## Notes
 /// This uses the [Miller loop](https://crypto.stanford.edu/pbc/notes/ep/miller.html) algorithm to compute the rational map required for pairing.
@@ -35,22 +52,69 @@ pub fn pairing<C: EllipticCurve + Debug + PartialEq, const R: usize>(
   q: AffinePoint<C>,
 ) -> C::BaseField {
   // Check that both inputs are r torsion points on the curve
-  let mut result = p;
-  for _ in 0..R {
-    result += p;
-  }
-  assert_eq!(result, p);
-  let mut result = q;
-  for _ in 0..R {
-    result += q;
-  }
-  assert_eq!(result, q);
+  assert!(p.is_torsion(&[R as u64]), "p is not an R-torsion point");
+  assert!(q.is_torsion(&[R as u64]), "q is not an R-torsion point");
 
   // Compute the Miller loop
   let val = miller_loop::<C, R>(p, q);
 
-  // Do the final exponentiation
-  val.pow((C::BaseField::ORDER - 1) / R)
+  // Do the final exponentiation by (q - 1) / R, on limbs as q may not fit in a usize
+  let q_minus_one = sub_small(&C::BaseField::order(), 1);
+  pow_limbs(val, &div_rem_small(&q_minus_one, R as u64).0)
+}
+
+/// Compute the Weil pairing $e_R(P, Q) = (-1)^R f_{R,P}(Q) / f_{R,Q}(P)$ of two points on the
+/// curve.
+///
+/// Unlike the Tate [`pairing()`], the Weil pairing needs no final exponentiation: its values are
+/// already $R$-th roots of unity. It is alternating, with $e_R(P, P) = 1$, and non-degenerate on
+/// the full $R$-torsion $E\[R\]$. The curve is therefore taken over the extension whose degree is
+/// the [`embedding_degree`](torsion::embedding_degree), where a basis of $E\[R\]$ is given by
+/// [`torsion::torsion_structure`].
+///
+/// ## Arguments
+/// * `const R` - The order of the R-torsion group.
+/// * `p`, `q` - Two points of the R-torsion group.
+///
+/// ## Returns
+/// The result of the pairing, an R-th root of unity in the base field of the curve.
+///
+/// ## Panics
+/// Panics if either input is not in the R-torsion group.
+///
+/// Points that are multiples of each other, including equal points and the point at infinity, give
+/// `1` as the pairing is alternating. The lines of the Miller loops go through multiples of the
+/// points, so one of them vanishing at the other point shows that the points are dependent, with
+/// no extra group operations. Otherwise the Miller functions are evaluated away from their zeros
+/// and poles, and give `1` for dependent points anyway.
+///
+/// ## Notes
+/// Both Miller functions are evaluated at a single point, which is valid for the normalized
+/// functions of [Miller](https://crypto.stanford.edu/miller/miller.pdf). The lines of
+/// `miller_loop` are normalized up to the same sign for $P$ and $Q$, which cancels out in the
+/// quotient.
+pub fn weil_pairing<C: EllipticCurve + Debug + PartialEq, const R: usize>(
+  p: AffinePoint<C>,
+  q: AffinePoint<C>,
+) -> C::BaseField {
+  // Check that both inputs are r torsion points on the curve
+  assert!(p.is_torsion(&[R as u64]), "p is not an R-torsion point");
+  assert!(q.is_torsion(&[R as u64]), "q is not an R-torsion point");
+  // e(P, kP) = e(P, P)^k = 1
+  if p == AffinePoint::Infinity || q == AffinePoint::Infinity {
+    return C::BaseField::ONE;
+  }
+  let ((f_p, _, p_vanished), (f_q, _, q_vanished)) =
+    (miller_function::<C, R>(p, q), miller_function::<C, R>(q, p));
+  if p_vanished || q_vanished {
+    return C::BaseField::ONE;
+  }
+
+  let ratio = f_p / f_q;
+  match R % 2 {
+    0 => ratio,
+    _ => -ratio,
+  }
 }
 
 /// Evaluate a rational function on a divisor f_{r,P}(D_{Q}) in logarithmic time complexity using an
@@ -59,6 +123,18 @@ pub(crate) fn miller_loop<C: EllipticCurve + Debug + PartialEq, const R: usize>(
   p: AffinePoint<C>,
   q: AffinePoint<C>,
 ) -> C::BaseField {
+  let (x, zeros, _) = miller_function::<C, R>(p, q);
+  assert_eq!(zeros, 0);
+  x
+}
+
+/// The loop of [`miller_loop`], skipping the lines that vanish at `q`. Also returns the number of
+/// zeros minus the number of poles skipped, and whether any line vanished at `q`, which happens
+/// exactly when `q` is a multiple of `p`.
+fn miller_function<C: EllipticCurve + Debug + PartialEq, const R: usize>(
+  p: AffinePoint<C>,
+  q: AffinePoint<C>,
+) -> (C::BaseField, i32, bool) {
   // Use the R to get a binary representation, then loop over the binary representation to do the
   // algorithm.
   let mut x = C::BaseField::ONE;
@@ -66,6 +142,7 @@ pub(crate) fn miller_loop<C: EllipticCurve + Debug + PartialEq, const R: usize>(
 
   let r = format!("{:b}", R);
   let mut zeros = 0;
+  let mut vanished = false;
   for bit in r.chars().skip(1) {
     // f_{2m,P} <- f_{m,P}^2.(l_{[m]P,[m]P}(Q)/v_{[2m]P}(Q))
     let tangent = tangent_line::<C>(z, q);
@@ -73,11 +150,13 @@ pub(crate) fn miller_loop<C: EllipticCurve + Debug + PartialEq, const R: usize>(
     x = x.pow(2);
     if tangent == C::BaseField::ZERO {
       zeros += 1;
+      vanished = true;
     } else {
       x *= tangent;
     }
     if vertical == C::BaseField::ZERO {
       zeros -= 1;
+      vanished = true;
     } else {
       x /= vertical;
     }
@@ -89,6 +168,7 @@ pub(crate) fn miller_loop<C: EllipticCurve + Debug + PartialEq, const R: usize>(
       if z + p == AffinePoint::Infinity {
         if line == C::BaseField::ZERO {
           zeros += 1;
+          vanished = true;
         } else {
           x *= line;
         }
@@ -96,11 +176,13 @@ pub(crate) fn miller_loop<C: EllipticCurve + Debug + PartialEq, const R: usize>(
         let vertical = vertical_line(z + p, q);
         if line_function::<C>(z, p, q) == C::BaseField::ZERO {
           zeros += 1;
+          vanished = true;
         } else {
           x *= line;
         }
         if vertical == C::BaseField::ZERO {
           zeros -= 1;
+          vanished = true;
         } else {
           x /= vertical;
         }
@@ -110,8 +192,7 @@ pub(crate) fn miller_loop<C: EllipticCurve + Debug + PartialEq, const R: usize>(
     }
   }
 
-  assert_eq!(zeros, 0);
-  x
+  (x, zeros, vanished)
 }
 
 /// Creates a line function through the given points `a` and `b` and evaluates it at the point
@@ -206,9 +287,9 @@ impl Distribution<AffinePoint<PlutoBaseCurve>> for Standard {
       if rhs.euler_criterion() {
         // Flip a coin and pick the square root
         if rand::random::<bool>() {
-          return AffinePoint::new(x, rhs.sqrt().unwrap().0);
+          return AffinePoint::Point(x, rhs.sqrt().unwrap().0);
         }
-        return AffinePoint::new(x, rhs.sqrt().unwrap().1);
+        return AffinePoint::Point(x, rhs.sqrt().unwrap().1);
       }
     }
   }
@@ -224,9 +305,9 @@ impl Distribution<AffinePoint<PlutoExtendedCurve>> for Standard {
         x.pow(3) + x * PlutoExtendedCurve::EQUATION_A + PlutoExtendedCurve::EQUATION_B;
       if rhs.euler_criterion() {
         if rand::random::<bool>() {
-          return AffinePoint::new(x, rhs.sqrt().unwrap().0);
+          return AffinePoint::Point(x, rhs.sqrt().unwrap().0);
         }
-        return AffinePoint::new(x, rhs.sqrt().unwrap().1);
+        return AffinePoint::Point(x, rhs.sqrt().unwrap().1);
       }
     }
   }
@@ -236,46 +317,6 @@ impl Distribution<AffinePoint<PlutoExtendedCurve>> for Standard {
 mod tests {
   use super::*;
 
-  /// Compute weil pairing $w_{r} = f_{r,P}(D_Q)/f_{r,Q}(D_P)$ for points $P, Q \in E[F_{q^k}][r]$.
-  /// $D_P,D_Q$ are degree zero divisors with disjoint supports to $f_{r,Q},f_{r,P}$ respectively.
-  /// D_P have divisor equal to (P)-(O), and (D_Q) = (Q)-(O)
-  pub fn weil_pairing<const R: usize>(
-    p: AffinePoint<PlutoExtendedCurve>,
-    q: AffinePoint<PlutoExtendedCurve>,
-  ) -> <PlutoExtendedCurve as EllipticCurve>::BaseField {
-    // Check that both inputs are r torsion points on the curve
-    let mut result = p;
-    for _ in 0..R {
-      result += p;
-    }
-    assert_eq!(result, p);
-    let mut result = q;
-    for _ in 0..R {
-      result += q;
-    }
-    assert_eq!(result, q);
-
-    // to keep the support disjoint, a random element `S` on extended curve is used, which shouldn't
-    // be equal to P, -Q, P-Q
-    let mut rng = rand::thread_rng();
-    let mut s = rng.gen::<AffinePoint<PlutoExtendedCurve>>();
-    while s == p || s == -q || s == p - q {
-      s = rng.gen::<AffinePoint<PlutoExtendedCurve>>();
-    }
-
-    // (D_Q) ~ (Q+S) - (S) (equivalent divisors)
-    // Compute the Miller loop for f_{r,P}(D_Q) as f_{r,P}(Q+S)/f_{r,P}(S)
-    let num =
-      miller_loop::<PlutoExtendedCurve, R>(p, q + s) * miller_loop::<PlutoExtendedCurve, R>(q, -s);
-
-    // (D_P) ~ (P-S) - (-S) (equivalent divisors)
-    // compute the miller loop f_{r,Q}(D_P) as f_{r,Q}(P-S)/f_{r,Q}(-S)
-    let den =
-      miller_loop::<PlutoExtendedCurve, R>(q, p - s) * miller_loop::<PlutoExtendedCurve, R>(p, s);
-
-    num / den
-  }
-
   #[test]
   fn random_point() {
     let mut rng = rand::thread_rng();
@@ -315,6 +356,7 @@ mod tests {
         cube_root_of_unity * PlutoBaseFieldExtension::from(x),
         PlutoBaseFieldExtension::from(y),
       )
+      .unwrap()
     } else {
       panic!("Generator is not a point");
     };
@@ -340,6 +382,7 @@ mod tests {
         cube_root_of_unity * PlutoBaseFieldExtension::from(x),
         PlutoBaseFieldExtension::from(y),
       )
+      .unwrap()
     } else {
       panic!("Generator is not a point");
     };
@@ -372,10 +415,10 @@ mod tests {
     #[case] weil_result: PlutoBaseFieldExtension,
     #[case] tate_result: PlutoBaseFieldExtension,
   ) {
-    let a = AffinePoint::<PlutoExtendedCurve>::new(a_x, a_y);
-    let b = AffinePoint::<PlutoExtendedCurve>::new(b_x, b_y);
+    let a = AffinePoint::<PlutoExtendedCurve>::new(a_x, a_y).unwrap();
+    let b = AffinePoint::<PlutoExtendedCurve>::new(b_x, b_y).unwrap();
 
-    let result = weil_pairing::<17>(a, b);
+    let result = weil_pairing::<PlutoExtendedCurve, 17>(a, b);
     assert_eq!(result, weil_result);
 
     let result = pairing::<PlutoExtendedCurve, 17>(a, b);
@@ -393,6 +436,7 @@ mod tests {
         cube_root_of_unity * PlutoBaseFieldExtension::from(x),
         PlutoBaseFieldExtension::from(y),
       )
+      .unwrap()
     } else {
       panic!("Generator is not a point");
     };
@@ -401,7 +445,7 @@ mod tests {
     let result2 = pairing::<PlutoExtendedCurve, 17>(q, p);
     let weil_from_tate_pairing = result / result2;
 
-    let weil_pair = weil_pairing::<17>(p, q);
+    let weil_pair = weil_pairing::<PlutoExtendedCurve, 17>(p, q);
 
     assert_eq!(
       weil_pair.pow((<PlutoExtendedCurve as EllipticCurve>::BaseField::ORDER - 1) / 17),
@@ -426,6 +470,7 @@ mod tests {
         cube_root_of_unity * PlutoBaseFieldExtension::from(x),
         PlutoBaseFieldExtension::from(y),
       )
+      .unwrap()
     } else {
       panic!("Generator is not a point");
     };
@@ -449,6 +494,7 @@ mod tests {
         cube_root_of_unity * PlutoBaseFieldExtension::from(x),
         PlutoBaseFieldExtension::from(y),
       )
+      .unwrap()
     } else {
       panic!("Generator is not a point");
     };
@@ -456,4 +502,48 @@ mod tests {
     let rhs = pairing::<PlutoExtendedCurve, 17>(p, q) * pairing::<PlutoExtendedCurve, 17>(p, r);
     assert_eq!(lhs, rhs);
   }
+
+  #[test]
+  fn final_exponentiation_by_frobenius() {
+    let p = AffinePoint::<PlutoExtendedCurve>::from(AffinePoint::<PlutoBaseCurve>::GENERATOR);
+    let cube_root_of_unity = PlutoBaseFieldExtension::primitive_root_of_unity(3);
+    let q = if let AffinePoint::<PlutoBaseCurve>::Point(x, y) =
+      AffinePoint::<PlutoBaseCurve>::GENERATOR
+    {
+      AffinePoint::<PlutoExtendedCurve>::new(
+        cube_root_of_unity * PlutoBaseFieldExtension::from(x),
+        PlutoBaseFieldExtension::from(y),
+      )
+      .unwrap()
+    } else {
+      panic!("Generator is not a point");
+    };
+
+    // (p^2 - 1)/r = (p - 1) (p + 1)/r and f^(p - 1) = f^p / f only costs a Frobenius map.
+    let f = miller_loop::<PlutoExtendedCurve, 17>(p, q);
+    let result = (f.frobenius(1) / f).pow((101 + 1) / 17);
+    assert_eq!(result, pairing::<PlutoExtendedCurve, 17>(p, q));
+
+    // The pairing lands in the norm-one subgroup, where the Frobenius map is inversion.
+    assert_eq!(result.norm(), PlutoBaseField::ONE);
+    assert_eq!(result.frobenius(1), result.inverse().unwrap());
+  }
+
+  #[test]
+  fn weil_pairing_of_dependent_points() {
+    let torsion::TorsionStructure::Full(p, q) =
+      torsion::torsion_structure::<PlutoExtendedCurve>(17)
+    else {
+      panic!("the 17-torsion is full over the extension of embedding degree 2");
+    };
+    let three = PlutoScalarField::new(3);
+    // the pairing is 1 on every multiple of P, whether or not a line of the Miller loops vanishes
+    for multiple in (0..17).map(|k| p * PlutoScalarField::new(k)) {
+      assert_eq!(weil_pairing::<PlutoExtendedCurve, 17>(p, multiple), PlutoBaseFieldExtension::ONE);
+      assert_eq!(weil_pairing::<PlutoExtendedCurve, 17>(multiple, p), PlutoBaseFieldExtension::ONE);
+    }
+    let e = weil_pairing::<PlutoExtendedCurve, 17>(p, q);
+    assert_ne!(e, PlutoBaseFieldExtension::ONE);
+    assert_eq!(weil_pairing::<PlutoExtendedCurve, 17>(p, q + p * three), e);
+  }
 }
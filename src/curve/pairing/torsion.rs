@@ -0,0 +1,137 @@
+//! The $r$-torsion of a curve, where pairings take their inputs.
+//!
+//! For a prime $r$ dividing the number of points of a curve $E$ over $\mathbb{F}_q$, the
+//! $r$-torsion $E\[r\]$ over the algebraic closure is $\mathbb{Z}/r \times \mathbb{Z}/r$, but only
+//! part of it may have coordinates in a given field: $E(\mathbb{F}_{q^j})\[r\]$ is either trivial,
+//! cyclic of order $r$, or the full $E\[r\]$. When $r$ does not divide $q - 1$, the full
+//! $r$-torsion appears exactly over $\mathbb{F}_{q^k}$ for the [`embedding_degree`] $k$ (a theorem
+//! of Balasubramanian and Koblitz), which is where the pairings are non-degenerate.
+//!
+//! [`torsion_structure`] finds which case holds over the base field of a curve, with generators,
+//! by going through every point. It is only meant for the toy curves of `ronkathon`, like the
+//! [`PlutoExtendedCurve`], whose 17-torsion is full, or the curves of the [`toy`]
+//! generator.
+use super::*;
+use crate::algebra::field::SquareRoot;
+
+/// The structure of the $r$-torsion $E(\mathbb{F})\[r\]$ of a curve over its base field, for a
+/// prime $r$, with generators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TorsionStructure<C: EllipticCurve> {
+  /// Only the point at infinity, $r$ does not divide the number of points.
+  Trivial,
+  /// A cyclic group $\mathbb{Z}/r$ generated by the point.
+  Cyclic(AffinePoint<C>),
+  /// The full $r$-torsion $\mathbb{Z}/r \times \mathbb{Z}/r$, with a basis of two independent
+  /// points.
+  Full(AffinePoint<C>, AffinePoint<C>),
+}
+
+/// Returns the embedding degree of a subgroup of order `r` of a curve over a field with `q`
+/// elements, the smallest `k` such that `r` divides `q^k - 1`, i.e. the order of `q` modulo `r`.
+///
+/// The pairings of the subgroup take their values in $\mathbb{F}_{q^k}$, the smallest extension
+/// that contains the $r$-th roots of unity. Random curves have an embedding degree about as large
+/// as `r`, so pairing-friendly curves are built on purpose to have a small one, like `2` for the
+/// supersingular Pluto curve or `12` for BN254.
+///
+/// Returns `None` if `r < 2` or `r` divides `q`, when no such `k` exists.
+pub fn embedding_degree(q: u64, r: u64) -> Option<u64> {
+  if r < 2 || q % r == 0 {
+    return None;
+  }
+  let q = q % r;
+  let (mut power, mut k) = (q, 1);
+  while power != 1 {
+    power = (power as u128 * q as u128 % r as u128) as u64;
+    k += 1;
+  }
+  Some(k)
+}
+
+/// Finds the structure of the $r$-torsion of the curve over its base field, for a prime `r`.
+///
+/// Every element of the base field is tried as an `x` coordinate, with both square roots of
+/// $x^3 + ax + b$ as `y`, and the first point of order `r` is kept as a generator. Finding a point
+/// of order `r` that is not one of its multiples means the $r$-torsion is full, and the two points
+/// form a basis that can be fed to [`weil_pairing`] or [`pairing`](super::pairing()).
+///
+/// This takes $O(q \log r)$ operations for a base field with $q$ elements, and is only meant for
+/// toy fields.
+pub fn torsion_structure<C: EllipticCurve>(r: usize) -> TorsionStructure<C>
+where C::BaseField: SquareRoot {
+  let a: C::BaseField = C::EQUATION_A.into();
+  let b: C::BaseField = C::EQUATION_B.into();
+  let q = match C::BaseField::order()[..] {
+    [q] => q as usize,
+    _ => panic!("the base field is too large to go through all of its elements"),
+  };
+  // the nonzero elements are the powers of the primitive element
+  let elements = std::iter::once(C::BaseField::ZERO).chain(
+    std::iter::successors(Some(C::BaseField::ONE), |&x| Some(x * C::BaseField::PRIMITIVE_ELEMENT))
+      .take(q - 1),
+  );
+
+  let mut generator: Option<(AffinePoint<C>, Vec<AffinePoint<C>>)> = None;
+  for x in elements {
+    let Some(y) = (x * x * x + a * x + b).square_root() else {
+      continue;
+    };
+    for point in [AffinePoint::Point(x, y), AffinePoint::Point(x, -y)] {
+      if !point.is_torsion(&[r as u64]) {
+        continue;
+      }
+      match &generator {
+        None => {
+          let multiples =
+            std::iter::successors(Some(point), |&q| Some(q + point)).take(r).collect();
+          generator = Some((point, multiples));
+        },
+        Some((p, multiples)) if !multiples.contains(&point) => {
+          return TorsionStructure::Full(*p, point);
+        },
+        Some(_) => {},
+      }
+    }
+  }
+  match generator {
+    Some((p, _)) => TorsionStructure::Cyclic(p),
+    None => TorsionStructure::Trivial,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn embedding_degrees() {
+    assert_eq!(embedding_degree(PlutoPrime::Base as u64, PlutoPrime::Scalar as u64), Some(2));
+    assert_eq!(embedding_degree(101, 5), Some(1));
+    assert_eq!(embedding_degree(101, 101), None);
+    assert_eq!(embedding_degree(101, 1), None);
+    // 2 generates the multiplicative group modulo 101
+    assert_eq!(embedding_degree(2, 101), Some(100));
+  }
+
+  #[test]
+  fn pluto_torsion() {
+    // the base curve has 102 = 2 * 3 * 17 points, a cyclic group
+    let TorsionStructure::Cyclic(p) = torsion_structure::<PlutoBaseCurve>(17) else {
+      panic!("the 17-torsion of the base curve is cyclic");
+    };
+    assert!(p.is_torsion(&[17]) && p != AffinePoint::Infinity);
+    assert!(matches!(torsion_structure::<PlutoBaseCurve>(2), TorsionStructure::Cyclic(_)));
+    assert_eq!(torsion_structure::<PlutoBaseCurve>(5), TorsionStructure::Trivial);
+
+    // x^3 = -3 has the three roots of the 2-torsion over F_{101^2}, which holds the cube roots of
+    // unity
+    assert!(matches!(torsion_structure::<PlutoExtendedCurve>(2), TorsionStructure::Full(..)));
+    let TorsionStructure::Full(p, q) = torsion_structure::<PlutoExtendedCurve>(17) else {
+      panic!("the 17-torsion is full over the extension of embedding degree 2");
+    };
+    let e = weil_pairing::<PlutoExtendedCurve, 17>(p, q);
+    assert_ne!(e, PlutoBaseFieldExtension::ONE);
+    assert_eq!(e.pow(17), PlutoBaseFieldExtension::ONE);
+  }
+}
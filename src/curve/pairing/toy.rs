@@ -0,0 +1,352 @@
+//! A search for small pairing-friendly curves, to experiment with pairings beyond the Pluto curve.
+//!
+//! The search returns [`ToyCurve`]s, plain integers describing a curve $y^2 = x^3 + ax + b$ over
+//! $\mathbb{F}_p$ with a subgroup of prime order $r$ and a small [`embedding_degree`] $k$. Their
+//! [`Display`] implementation prints an [`EllipticCurve`] implementation over [`PrimeField<P>`]
+//! ready to be pasted in a module, and the same curve over `GaloisField<k, P>` holds the full
+//! $r$-torsion, whose basis is found by [`torsion_structure`](super::torsion::torsion_structure).
+//!
+//! ## Supersingular curves
+//! For $p \equiv 2 \pmod 3$, cubing is a bijection of $\mathbb{F}_p$, so the curve $y^2 = x^3 + b$
+//! has exactly one point for each $y$, $p + 1$ points with the point at infinity. A prime
+//! $r > 3$ dividing $p + 1$ doesn't divide $p - 1$, but divides $p^2 - 1$: the embedding degree is
+//! 2. The [`PlutoBaseCurve`] is the case $p = 101$, $b = 3$ and $r = 17$.
+//!
+//! Nothing needs to be searched for but a point of order $r$, which is a point multiplied by the
+//! cofactor. [`supersingular_curve`] takes $p$ and $b$ as const parameters so that this is done
+//! with [`AffinePoint::clear_cofactor`] over [`PrimeField<P>`].
+//!
+//! ## MNT curves
+//! [Miyaji, Nakabayashi and Takano](https://eprint.iacr.org/2001/058) characterized the ordinary
+//! curves of prime order $n = p + 1 - t$ and embedding degree 3, 4 or 6 by their trace $t$:
+//! - $k = 3$: $p = 12l^2 - 1$ and $t = -1 \pm 6l$;
+//! - $k = 4$: $p = l^2 + l + 1$ and $t = -l$ or $t = l + 1$;
+//! - $k = 6$: $p = 4l^2 + 1$ and $t = 1 \pm 2l$.
+//!
+//! Real MNT curves are built from the solutions of a Pell equation with the complex multiplication
+//! method. For toy primes, it is much simpler to count the points of every curve until one has $n$
+//! points, which always exists as the trace satisfies the Hasse bound $|t| \leq 2 \sqrt{p}$.
+//!
+//! The curves found have a prime number of points, so any point generates the whole group. The
+//! search counts the points of up to $p^2$ curves in time $p$ each, so it only reaches primes of a
+//! few thousands in practice, but the products are reduced through `u128` and stay correct for
+//! every `u64` prime.
+use std::fmt;
+
+use super::{torsion::embedding_degree, *};
+use crate::algebra::{
+  field::{prime::PrimeField, SquareRoot},
+  number_theory::{is_prime, jacobi, sqrt_mod},
+};
+
+/// The curve $y^2 = x^3 + b$ over [`PrimeField<P>`] of [`supersingular_curve`], whose subgroup of
+/// order $r$ is not known to the type system: the scalar field is only a placeholder, and the
+/// generator is not used.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+struct Supersingular<const P: usize, const B: usize>;
+
+impl<const P: usize, const B: usize> EllipticCurve for Supersingular<P, B> {
+  type BaseField = PrimeField<P>;
+  type Coefficient = PrimeField<P>;
+  type ScalarField = PrimeField<P>;
+
+  const COFACTOR: &'static [u64] = &[(P as u64 + 1) / largest_prime_factor(P as u64 + 1)];
+  const EQUATION_A: Self::Coefficient = PrimeField::new(0);
+  const EQUATION_B: Self::Coefficient = PrimeField::new(B);
+  const GENERATOR: (Self::BaseField, Self::BaseField) = (PrimeField::new(0), PrimeField::new(0));
+  const ORDER: usize = largest_prime_factor(P as u64 + 1) as usize;
+}
+
+/// The parameters of a curve $y^2 = x^3 + ax + b$ over $\mathbb{F}_p$ found by the search.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToyCurve {
+  /// The prime `p` of the base field.
+  pub p:                u64,
+  /// Coefficient `a` of the curve equation.
+  pub a:                u64,
+  /// Coefficient `b` of the curve equation.
+  pub b:                u64,
+  /// The number of points of the curve over $\mathbb{F}_p$, `cofactor * r`.
+  pub points:           u64,
+  /// The prime order `r` of the subgroup, which becomes the [`EllipticCurve::ORDER`].
+  pub r:                u64,
+  /// The cofactor `h` of the subgroup.
+  pub cofactor:         u64,
+  /// The embedding degree `k` of the subgroup.
+  pub embedding_degree: u64,
+  /// A generator of the subgroup of order `r`.
+  pub generator:        (u64, u64),
+}
+
+impl ToyCurve {
+  /// Builds the curve with the `generator` of its subgroup of prime order `r` dividing `points`,
+  /// if the embedding degree is defined.
+  fn new(p: u64, a: u64, b: u64, points: u64, r: u64, generator: (u64, u64)) -> Option<Self> {
+    let embedding_degree = embedding_degree(p, r)?;
+    Some(Self { p, a, b, points, r, cofactor: points / r, embedding_degree, generator })
+  }
+}
+
+/// Prints the curve as an [`EllipticCurve`] implementation named after its prime.
+impl fmt::Display for ToyCurve {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let Self { p, a, b, points, r, cofactor, embedding_degree, generator: (x, y) } = *self;
+    let a_term = match a {
+      0 => String::new(),
+      _ => format!(" + {a}x"),
+    };
+    let b_term = match b {
+      0 => String::new(),
+      _ => format!(" + {b}"),
+    };
+    writeln!(f, "/// The curve `y^2 = x^3{a_term}{b_term}` over `F_{p}`, with {points} points.")?;
+    writeln!(f, "///")?;
+    writeln!(f, "/// Its subgroup of prime order {r} has embedding degree {embedding_degree}.")?;
+    writeln!(f, "#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]")?;
+    writeln!(f, "pub struct ToyCurve{p};")?;
+    writeln!(f)?;
+    writeln!(f, "impl EllipticCurve for ToyCurve{p} {{")?;
+    writeln!(f, "  type BaseField = PrimeField<{p}>;")?;
+    writeln!(f, "  type Coefficient = PrimeField<{p}>;")?;
+    writeln!(f, "  type ScalarField = PrimeField<{r}>;")?;
+    writeln!(f)?;
+    writeln!(f, "  const COFACTOR: &'static [u64] = &[{cofactor}];")?;
+    writeln!(f, "  const EQUATION_A: Self::Coefficient = PrimeField::new({a});")?;
+    writeln!(f, "  const EQUATION_B: Self::Coefficient = PrimeField::new({b});")?;
+    writeln!(f, "  const GENERATOR: (Self::BaseField, Self::BaseField) =")?;
+    writeln!(f, "    (PrimeField::new({x}), PrimeField::new({y}));")?;
+    writeln!(f, "  const ORDER: usize = {r};")?;
+    write!(f, "}}")
+  }
+}
+
+/// Returns the supersingular curve $y^2 = x^3 + b$ over $\mathbb{F}_p$ for $p$ = `P` and $b$ =
+/// `B`, with embedding degree 2 for the largest prime factor $r$ of $p + 1$. Like for
+/// [`PrimeField<P>`], it is a compile-time error if `P` is not a prime.
+///
+/// Returns `None` unless $p > 3$, $p \equiv 2 \pmod 3$, $b$ is not a multiple of $p$, and $p + 1$
+/// has a prime factor $r > 3$.
+pub fn supersingular_curve<const P: usize, const B: usize>() -> Option<ToyCurve> {
+  let (p, b) = (P as u64, B as u64);
+  if p <= 3 || p % 3 != 2 || b % p == 0 {
+    return None;
+  }
+  let r = Supersingular::<P, B>::ORDER as u64;
+  if r <= 3 {
+    return None;
+  }
+  let generator = (0..P).find_map(|x| {
+    let x = PrimeField::<P>::new(x);
+    let y = (x * x * x + PrimeField::new(B)).square_root()?;
+    match AffinePoint::<Supersingular<P, B>>::Point(x, y).clear_cofactor() {
+      AffinePoint::Point(x, y) => Some((x.value as u64, y.value as u64)),
+      AffinePoint::Infinity => None,
+    }
+  })?;
+  ToyCurve::new(p, 0, b % p, p + 1, r, generator)
+}
+
+/// Returns MNT curves of embedding degree `k` over prime fields below `max_p`, one for each prime
+/// order $n$ given by the MNT traces.
+///
+/// ## Panics
+/// If `k` is not 3, 4 or 6.
+pub fn mnt_curves(k: u64, max_p: u64) -> Vec<ToyCurve> {
+  // the prime p and the two traces for the parameter l
+  let family = |l: i64| match k {
+    3 => (12 * l * l - 1, [-1 + 6 * l, -1 - 6 * l]),
+    4 => (l * l + l + 1, [-l, l + 1]),
+    6 => (4 * l * l + 1, [1 + 2 * l, 1 - 2 * l]),
+    _ => panic!("MNT curves have embedding degree 3, 4 or 6"),
+  };
+  let mut curves = Vec::new();
+  for l in 1.. {
+    let (p, traces) = family(l);
+    if p >= max_p as i64 {
+      break;
+    }
+    let p = p as u64;
+    if !is_prime(p) {
+      continue;
+    }
+    for t in traces {
+      let n = (p as i64 + 1 - t) as u64;
+      if !is_prime(n) || embedding_degree(p, n) != Some(k) {
+        continue;
+      }
+      let curve = (1..p)
+        .flat_map(|a| (1..p).map(move |b| (a, b)))
+        .filter(|&(a, b)| {
+          // the discriminant 4a^3 + 27b^2 must not vanish
+          let (p, a3, b) = (p as u128, rhs(p, 0, 0, a) as u128, b as u128);
+          (4 * a3 + 27 * (b * b % p)) % p != 0
+        })
+        .find(|&(a, b)| count_points(p, a, b) == n)
+        .and_then(|(a, b)| ToyCurve::new(p, a, b, n, n, point(p, a, b)?));
+      curves.extend(curve);
+    }
+  }
+  curves
+}
+
+/// Evaluates $x^3 + ax + b \bmod p$ for `a`, `b` and `x` below `p`.
+fn rhs(p: u64, a: u64, b: u64, x: u64) -> u64 {
+  let (p, a, b, x) = (p as u128, a as u128, b as u128, x as u128);
+  (((x * x % p + a) % p * x + b) % p) as u64
+}
+
+/// Counts the points of $y^2 = x^3 + ax + b$ over $\mathbb{F}_p$ for an odd prime $p$: each `x`
+/// gives `1 + (x^3 + ax + b | p)` points, plus the point at infinity.
+fn count_points(p: u64, a: u64, b: u64) -> u64 {
+  let sum: i64 = (0..p).map(|x| jacobi(rhs(p, a, b, x), p) as i64).sum();
+  (p as i64 + 1 + sum) as u64
+}
+
+/// Returns a point of $y^2 = x^3 + ax + b$ over $\mathbb{F}_p$ with the smallest `x`, taking the
+/// square root of the first right-hand side that is a square.
+fn point(p: u64, a: u64, b: u64) -> Option<(u64, u64)> {
+  (0..p).find_map(|x| sqrt_mod(rhs(p, a, b, x), p).map(|y| (x, y)))
+}
+
+/// Returns the largest prime factor of `n > 1`, by trial division.
+const fn largest_prime_factor(mut n: u64) -> u64 {
+  let mut factor = 2;
+  while factor * factor <= n {
+    match n % factor {
+      0 => n /= factor,
+      _ => factor += 1,
+    }
+  }
+  n
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    algebra::field::{extension::GaloisField, prime::PrimeField},
+    curve::pairing::torsion::{torsion_structure, TorsionStructure},
+  };
+
+  /// The curve `y^2 = x^3 + 1` over `F_173`, with 174 points.
+  ///
+  /// Its subgroup of prime order 29 has embedding degree 2.
+  #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+  pub struct ToyCurve173;
+
+  impl EllipticCurve for ToyCurve173 {
+    type BaseField = PrimeField<173>;
+    type Coefficient = PrimeField<173>;
+    type ScalarField = PrimeField<29>;
+
+    const COFACTOR: &'static [u64] = &[6];
+    const EQUATION_A: Self::Coefficient = PrimeField::new(0);
+    const EQUATION_B: Self::Coefficient = PrimeField::new(1);
+    const GENERATOR: (Self::BaseField, Self::BaseField) =
+      (PrimeField::new(37), PrimeField::new(151));
+    const ORDER: usize = 29;
+  }
+
+  /// [`ToyCurve173`] over `F_{173^2}`, with `174^2 = 1044 * 29` points and the full 29-torsion.
+  #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+  pub struct ToyCurve173Extended;
+
+  impl EllipticCurve for ToyCurve173Extended {
+    type BaseField = GaloisField<2, 173>;
+    type Coefficient = PrimeField<173>;
+    type ScalarField = PrimeField<29>;
+
+    const COFACTOR: &'static [u64] = &[1044];
+    const EQUATION_A: Self::Coefficient = PrimeField::new(0);
+    const EQUATION_B: Self::Coefficient = PrimeField::new(1);
+    const GENERATOR: (Self::BaseField, Self::BaseField) = (
+      GaloisField::new([PrimeField::new(37), PrimeField::ZERO]),
+      GaloisField::new([PrimeField::new(151), PrimeField::ZERO]),
+    );
+  }
+
+  #[test]
+  fn pluto_curve() {
+    let curve = supersingular_curve::<{ PlutoPrime::Base as usize }, 3>().unwrap();
+    assert_eq!(curve.a, 0);
+    assert_eq!(curve.r, PlutoBaseCurve::ORDER as u64);
+    assert_eq!(PlutoBaseCurve::COFACTOR, [curve.cofactor]);
+    assert_eq!(curve.embedding_degree, 2);
+    let (x, y) = curve.generator;
+    let g = AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::from(x), PlutoBaseField::from(y));
+    assert!(g.unwrap().is_torsion(&[17]));
+
+    assert_eq!(supersingular_curve::<101, 0>(), None);
+    assert_eq!(supersingular_curve::<103, 1>(), None);
+    // 2^4 * 3 has no large prime factor
+    assert_eq!(supersingular_curve::<47, 1>(), None);
+  }
+
+  #[test]
+  fn emitted_curve() {
+    let curve = supersingular_curve::<173, 1>().unwrap();
+    assert!(curve.to_string().contains("impl EllipticCurve for ToyCurve173 {"));
+    assert!(curve.to_string().starts_with("/// The curve `y^2 = x^3 + 1` over `F_173`"));
+    assert_eq!(curve.r, ToyCurve173::ORDER as u64);
+    assert_eq!(ToyCurve173::COFACTOR, [curve.cofactor]);
+    let (x, y) = curve.generator;
+    assert_eq!(ToyCurve173::GENERATOR, (PrimeField::from(x), PrimeField::from(y)));
+    assert!(AffinePoint::<ToyCurve173>::GENERATOR.is_in_prime_subgroup());
+    let extended = AffinePoint::<ToyCurve173Extended>::GENERATOR;
+    assert!(extended.is_on_curve() && extended.is_in_prime_subgroup());
+
+    let TorsionStructure::Full(p, q) = torsion_structure::<ToyCurve173Extended>(29) else {
+      panic!("the 29-torsion is full over the extension of embedding degree 2");
+    };
+    let e = pairing::<ToyCurve173Extended, 29>(p, q);
+    assert_ne!(e, GaloisField::ONE);
+    assert_eq!(
+      pairing::<ToyCurve173Extended, 29>(p * PrimeField::new(2), q * PrimeField::new(3)),
+      e.pow(6)
+    );
+    let w = weil_pairing::<ToyCurve173Extended, 29>(p, q);
+    assert_ne!(w, GaloisField::ONE);
+    assert_eq!(w.pow(29), GaloisField::ONE);
+  }
+
+  /// Checks the supersingular curve over `F_P` and that its generator has order `r`.
+  fn check_supersingular<const P: usize, const B: usize>() {
+    let curve = supersingular_curve::<P, B>().unwrap();
+    let ToyCurve { p, a, b, points, r, cofactor, embedding_degree, generator: (x, y) } = curve;
+    assert_eq!(count_points(p, a, b), points);
+    assert_eq!(points, cofactor * r);
+    assert!(is_prime(r));
+    assert_eq!(embedding_degree, 2);
+    let generator =
+      AffinePoint::<Supersingular<P, B>>::new(PrimeField::from(x), PrimeField::from(y));
+    assert!(generator.unwrap().is_torsion(&[r]));
+  }
+
+  #[test]
+  fn supersingular() {
+    check_supersingular::<29, 1>();
+    check_supersingular::<41, 1>();
+    check_supersingular::<59, 1>();
+    check_supersingular::<83, 1>();
+    check_supersingular::<89, 1>();
+    check_supersingular::<443, 5>();
+    // 23 + 1 = 2^3 * 3
+    assert_eq!(supersingular_curve::<23, 1>(), None);
+  }
+
+  #[rstest]
+  #[case(3)]
+  #[case(4)]
+  #[case(6)]
+  fn mnt(#[case] k: u64) {
+    let curves = mnt_curves(k, 2000);
+    assert!(!curves.is_empty());
+    for ToyCurve { p, a, b, points, r, cofactor, embedding_degree, generator } in curves {
+      assert_eq!(count_points(p, a, b), points);
+      assert_eq!((points, cofactor), (r, 1));
+      assert!(is_prime(r));
+      assert_eq!(embedding_degree, k);
+      assert_eq!(generator, point(p, a, b).unwrap());
+    }
+  }
+}
@@ -8,7 +8,11 @@
 //! Note that this would be cleaner if we could use trait specialization to keep the default
 //! implementations in the trait itself, but this feature is not yet to that point of utility.
 
-use super::*;
+use super::{
+  hash_to_curve::{try_and_increment, MapToCurve},
+  subgroup::PrimeOrderPoint,
+  *,
+};
 use crate::algebra::field::extension::PlutoExtensions;
 
 /// The [`PlutoBaseCurve`] is an the base field set to the [`PlutoBaseField`]. This is the curve
@@ -29,6 +33,7 @@ impl EllipticCurve for PlutoBaseCurve {
   type Coefficient = PlutoBaseField;
   type ScalarField = PlutoScalarField;
 
+  const COFACTOR: &'static [u64] = &[6];
   const EQUATION_A: Self::Coefficient = PlutoBaseField::ZERO;
   const EQUATION_B: Self::Coefficient = PlutoBaseField::new(3);
   const GENERATOR: (Self::BaseField, Self::BaseField) =
@@ -41,6 +46,7 @@ impl EllipticCurve for PlutoExtendedCurve {
   type Coefficient = PlutoBaseField;
   type ScalarField = PlutoScalarField;
 
+  const COFACTOR: &'static [u64] = &[36];
   const EQUATION_A: Self::Coefficient = PlutoBaseField::ZERO;
   const EQUATION_B: Self::Coefficient = PlutoBaseField::new(3);
   const GENERATOR: (Self::BaseField, Self::BaseField) = (
@@ -56,13 +62,30 @@ impl From<AffinePoint<PlutoBaseCurve>> for AffinePoint<PlutoExtendedCurve> {
       AffinePoint::Point(x, y) => {
         let x = PlutoBaseFieldExtension::from(x);
         let y = PlutoBaseFieldExtension::from(y);
-        AffinePoint::new(x, y)
+        AffinePoint::Point(x, y)
       },
       AffinePoint::Infinity => AffinePoint::Infinity,
     }
   }
 }
 
+impl From<PrimeOrderPoint<PlutoBaseCurve>> for PrimeOrderPoint<PlutoExtendedCurve> {
+  /// The embedding of the base curve preserves the order of points.
+  fn from(point: PrimeOrderPoint<PlutoBaseCurve>) -> Self {
+    PrimeOrderPoint::new_unchecked(point.point().into())
+  }
+}
+
+/// The Pluto curves have `a = 0`, so the simplified SWU map would need an isogeny, and the curve is
+/// hashed to with [`try_and_increment`] instead.
+impl MapToCurve for PlutoBaseCurve {
+  fn map_to_curve(u: PlutoBaseField) -> AffinePoint<Self> { try_and_increment(u) }
+}
+
+impl MapToCurve for PlutoExtendedCurve {
+  fn map_to_curve(u: PlutoBaseFieldExtension) -> AffinePoint<Self> { try_and_increment(u) }
+}
+
 // TODO: have to remove const trait from finite field for this. Ask Colin or Waylon if that's
 // alright
 // impl<C: EllipticCurve> Distribution<AffinePoint<C>> for Standard {
@@ -73,9 +96,9 @@ impl From<AffinePoint<PlutoBaseCurve>> for AffinePoint<PlutoExtendedCurve> {
 //       let rhs = x.pow(3) + x * C::EQUATION_A.into() + C::EQUATION_B.into();
 //       if rhs.euler_criterion() {
 //         if rand::random::<bool>() {
-//           return AffinePoint::new(x, rhs.sqrt().unwrap().0);
+//           return AffinePoint::new(x, rhs.sqrt().unwrap().0).unwrap();
 //         } else {
-//           return AffinePoint::new(x, rhs.sqrt().unwrap().1);
+//           return AffinePoint::new(x, rhs.sqrt().unwrap().1).unwrap();
 //         }
 //       }
 //     }
@@ -92,33 +115,33 @@ mod pluto_base_curve_tests {
 
     let two_g = g.double();
     let expected_2g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(68), PlutoBaseField::new(74));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(68), PlutoBaseField::new(74)).unwrap();
     let expected_negative_2g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(68), PlutoBaseField::new(27));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(68), PlutoBaseField::new(27)).unwrap();
     assert_eq!(two_g, expected_2g);
     assert_eq!(-two_g, expected_negative_2g);
 
     let four_g = two_g.double();
     let expected_4g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(65), PlutoBaseField::new(98));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(65), PlutoBaseField::new(98)).unwrap();
     let expected_negative_4g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(65), PlutoBaseField::new(3));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(65), PlutoBaseField::new(3)).unwrap();
     assert_eq!(four_g, expected_4g);
     assert_eq!(-four_g, expected_negative_4g);
 
     let eight_g = four_g.double();
     let expected_8g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(18), PlutoBaseField::new(49));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(18), PlutoBaseField::new(49)).unwrap();
     let expected_negative_8g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(18), PlutoBaseField::new(52));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(18), PlutoBaseField::new(52)).unwrap();
     assert_eq!(eight_g, expected_8g);
     assert_eq!(-eight_g, expected_negative_8g);
 
     let sixteen_g = eight_g.double();
     let expected_16g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(1), PlutoBaseField::new(99));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(1), PlutoBaseField::new(99)).unwrap();
     let expected_negative_16g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(1), PlutoBaseField::new(2));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(1), PlutoBaseField::new(2)).unwrap();
     assert_eq!(sixteen_g, expected_16g);
     assert_eq!(-sixteen_g, expected_negative_16g);
     assert_eq!(g, -sixteen_g);
@@ -142,25 +165,25 @@ mod pluto_base_curve_tests {
     let two_g = g.double();
     let three_g = g + two_g;
     let expected_3g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(26), PlutoBaseField::new(45));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(26), PlutoBaseField::new(45)).unwrap();
     let expected_negative_3g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(26), PlutoBaseField::new(56));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(26), PlutoBaseField::new(56)).unwrap();
     assert_eq!(three_g, expected_3g);
     assert_eq!(-three_g, expected_negative_3g);
 
     let four_g = g + three_g;
     let expected_4g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(65), PlutoBaseField::new(98));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(65), PlutoBaseField::new(98)).unwrap();
     let expected_negative_4g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(65), PlutoBaseField::new(3));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(65), PlutoBaseField::new(3)).unwrap();
     assert_eq!(four_g, expected_4g);
     assert_eq!(-four_g, expected_negative_4g);
 
     let five_g = g + four_g;
     let expected_5g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(12), PlutoBaseField::new(32));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(12), PlutoBaseField::new(32)).unwrap();
     let expected_negative_5g =
-      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(12), PlutoBaseField::new(69));
+      AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(12), PlutoBaseField::new(69)).unwrap();
     assert_eq!(five_g, expected_5g);
     assert_eq!(-five_g, expected_negative_5g);
 
@@ -197,9 +220,10 @@ mod pluto_extended_curve_tests {
       PlutoBaseFieldExtension::new([PlutoBaseField::new(90), PlutoBaseField::ZERO]),
       PlutoBaseFieldExtension::new([PlutoBaseField::ZERO, PlutoBaseField::new(82)]),
     )
+    .unwrap()
   }
 
-  fn false_point() -> AffinePoint<PlutoExtendedCurve> {
+  fn false_point() -> Result<AffinePoint<PlutoExtendedCurve>, PointError> {
     AffinePoint::<PlutoExtendedCurve>::new(
       PlutoBaseFieldExtension::new([PlutoBaseField::new(36), PlutoBaseField::ZERO]),
       PlutoBaseFieldExtension::new([PlutoBaseField::ZERO, PlutoBaseField::new(81)]),
@@ -211,15 +235,17 @@ mod pluto_extended_curve_tests {
       PlutoBaseFieldExtension::new([PlutoBaseField::new(36), PlutoBaseField::ZERO]),
       PlutoBaseFieldExtension::new([PlutoBaseField::ZERO, PlutoBaseField::new(31)]),
     )
+    .unwrap()
   }
 
   #[rstest]
   #[case(AffinePoint::<PlutoExtendedCurve>::GENERATOR)]
   #[case(generator())]
   #[case(point())]
-  #[should_panic]
-  #[case(false_point())]
-  fn on_curve(#[case] p: AffinePoint<PlutoExtendedCurve>) { let _ = p; }
+  fn on_curve(#[case] p: AffinePoint<PlutoExtendedCurve>) { assert!(p.is_on_curve()); }
+
+  #[test]
+  fn off_curve() { assert_eq!(false_point(), Err(PointError::NotOnCurve)); }
 
   #[test]
   fn point_doubling() {
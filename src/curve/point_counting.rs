@@ -0,0 +1,635 @@
+//! Counting the points of curves $y^2 = x^3 + ax + b$ over prime fields [`PrimeField<P>`].
+//!
+//! By Hasse's theorem a curve over $\mathbb{F}_p$ has $p + 1 - t$ points, where the trace of the
+//! Frobenius map $t$ satisfies $|t| \leq 2 \sqrt{p}$, and counting the points amounts to finding
+//! $t$. The hand-entered [`EllipticCurve::ORDER`] and [`EllipticCurve::COFACTOR`] of a curve should
+//! multiply to this count.
+//! - [`naive_count`] adds up the number of square roots of $x^3 + ax + b$ for every $x$, which
+//!   takes $O(p)$ Legendre symbols.
+//! - [`bsgs_count`] looks for a multiple of the order of a point in the Hasse interval with baby
+//!   steps and giant steps, in $O(p^{1/4})$ group operations. Points are added until only one
+//!   multiple of the lcm of their orders is left in the interval. This may never happen for the
+//!   curve itself, but Mestre showed that it does for either the curve or its quadratic twist when
+//!   $p > 229$.
+//! - [`schoof_count`] computes $t$ modulo small primes $\ell$ until their product exceeds
+//!   $4\sqrt{p}$, and recovers it with the Chinese remainder theorem. On the $\ell$-torsion, the
+//!   Frobenius map $\pi(x, y) = (x^p, y^p)$ satisfies $\pi^2 - t \pi + p = 0$, so $t \bmod \ell$ is
+//!   the $\tau$ such that $\pi^2(P) + [p \bmod \ell] P = [\tau] \pi(P)$. This is checked for a
+//!   generic $\ell$-torsion point $P = (x, y)$, computing modulo the division polynomial
+//!   $\psi_\ell$ whose roots are the $x$-coordinates of the $\ell$-torsion points. Everything is
+//!   polynomial in $\log p$.
+//!
+//! [`group_structure`] then tells cyclic groups apart from products
+//! $\mathbb{Z}/n \times \mathbb{Z}/m$, and [`count_over_extension`] gives the number of points over
+//! the extensions $\mathbb{F}_{p^k}$ from the count over $\mathbb{F}_p$.
+use std::{collections::HashMap, marker::PhantomData};
+
+use super::{scalar_mul::double_and_add, *};
+use crate::{
+  algebra::{
+    field::{prime::PrimeField, LegendreSymbol, SquareRoot},
+    number_theory::{crt, factorize, is_prime, isqrt, lcm},
+  },
+  polynomial::{Monomial, Polynomial},
+};
+
+/// The largest prime $\ell$ that [`schoof_count`] computes the trace modulo. The product of the
+/// primes up to 17 exceeds $4 \sqrt{p}$ for $p$ up to about $1.6 \cdot 10^{10}$.
+const MAX_SCHOOF_PRIME: usize = 17;
+
+/// Number of coefficients of the polynomials in Schoof's algorithm, enough for the division
+/// polynomial $\psi_\ell$ of degree $(\ell^2 - 1)/2$ and for the products of the recurrence that
+/// computes it.
+const SCHOOF_TERMS: usize = (MAX_SCHOOF_PRIME * MAX_SCHOOF_PRIME - 1) / 2 + 1;
+
+/// A polynomial over [`PrimeField<P>`] with [`SCHOOF_TERMS`] coefficients.
+type FpPolynomial<const P: usize> = Polynomial<Monomial, PrimeField<P>, SCHOOF_TERMS>;
+
+/// A point $(X(x), y Y(x))$ of the curve over $\mathbb{F}_p[x, y]/(\psi_\ell, y^2 - x^3 - ax - b)$,
+/// stored as the pair of polynomials $(X, Y)$, `None` being the point at infinity.
+type TorsionPoint<const P: usize> = Option<(FpPolynomial<P>, FpPolynomial<P>)>;
+
+/// How many points in a row have to leave the exponent unchanged before [`group_structure`] stops.
+const GROUP_STRUCTURE_ROUNDS: usize = 40;
+
+/// The structure of the group of points of a curve, $\mathbb{Z}/n \times \mathbb{Z}/m$ with $m$
+/// dividing $n$ and $p - 1$.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GroupStructure {
+  /// $\mathbb{Z}/n$, generated by a single point.
+  Cyclic(usize),
+  /// $\mathbb{Z}/n \times \mathbb{Z}/m$ with $m > 1$ dividing $n$.
+  Product(usize, usize),
+}
+
+/// Counts the points of the curve, including the point at infinity, by computing the Legendre
+/// symbol of $x^3 + ax + b$ for every $x \in \mathbb{F}_p$.
+///
+/// ## Panics
+/// If `P` is 2 or 3, or if the curve is singular.
+pub fn naive_count<C: EllipticCurve<BaseField = PrimeField<P>>, const P: usize>() -> usize {
+  let (a, b) = coefficients::<C, P>();
+  naive(a, b)
+}
+
+/// Counts the points of the curve with baby steps and giant steps in the Hasse interval, using
+/// Mestre's trick of also looking at the quadratic twist. Falls back to [`naive_count`] for
+/// $p \leq 229$, where the trick can fail.
+///
+/// ## Panics
+/// If `P` is 2 or 3, or if the curve is singular.
+pub fn bsgs_count<
+  C: EllipticCurve<BaseField = PrimeField<P>, Coefficient = PrimeField<P>>,
+  const P: usize,
+>() -> usize {
+  let (a, b) = coefficients::<C, P>();
+  if P <= 229 {
+    return naive(a, b);
+  }
+  let d = PrimeField::<P>::PRIMITIVE_ELEMENT;
+  let (low, high) = hasse_interval(P);
+  let (mut exponent, mut twist_exponent) = (1, 1);
+  for x in 0..P {
+    let x = PrimeField::<P>::new(x);
+    let rhs = x * x * x + a * x + b;
+    match rhs.square_root() {
+      Some(y) => {
+        exponent = lcm(exponent, bsgs_order(AffinePoint::<C>::Point(x, y), low, high) as u64);
+        if let Some(n) = unique_multiple(exponent as usize, low, high) {
+          return n;
+        }
+      },
+      None => {
+        let y = (rhs / d).square_root().expect("the quotient of two non-residues is a square");
+        let point = AffinePoint::<QuadraticTwist<C, P>>::Point(d * x, d * d * y);
+        twist_exponent = lcm(twist_exponent, bsgs_order(point, low, high) as u64);
+        if let Some(n) = unique_multiple(twist_exponent as usize, low, high) {
+          return 2 * P + 2 - n;
+        }
+      },
+    }
+  }
+  unreachable!("the exponent of the curve or of its twist determines the number of points")
+}
+
+/// Counts the points of the curve with Schoof's algorithm, see the [module documentation](self).
+///
+/// ## Panics
+/// - If `P` is 2 or 3, or if the curve is singular.
+/// - If `P` is larger than about $1.6 \cdot 10^{10}$, where primes above 17 are needed.
+pub fn schoof_count<C: EllipticCurve<BaseField = PrimeField<P>>, const P: usize>() -> usize {
+  let (a, b) = coefficients::<C, P>();
+  schoof(a, b)
+}
+
+/// Finds the structure $\mathbb{Z}/n \times \mathbb{Z}/m$ of the group of points of the curve.
+///
+/// The number of points $nm$ is given by [`schoof_count`], and $n$ is the exponent of the group,
+/// the lcm of the orders of its points. Random points are added until the lcm stays the same for
+/// 40 points in a row. While the lcm is not the exponent, a random point increases it with
+/// probability at least $1/2$, so the structure is wrong with probability about $2^{-40}$ at most.
+///
+/// ## Panics
+/// If `P` is 2 or 3, or if the curve is singular.
+pub fn group_structure<C: EllipticCurve<BaseField = PrimeField<P>>, const P: usize>(
+  rng: &mut impl Rng,
+) -> GroupStructure {
+  let (a, b) = coefficients::<C, P>();
+  let n = schoof(a, b);
+  let (mut exponent, mut unchanged) = (1, 0);
+  while exponent < n && unchanged < GROUP_STRUCTURE_ROUNDS {
+    let x = rng.gen::<PrimeField<P>>();
+    let Some(y) = (x * x * x + a * x + b).square_root() else {
+      continue;
+    };
+    let next = lcm(exponent as u64, order(AffinePoint::<C>::Point(x, y), n) as u64) as usize;
+    (exponent, unchanged) = match next == exponent {
+      true => (exponent, unchanged + 1),
+      false => (next, 0),
+    };
+  }
+  match n / exponent {
+    1 => GroupStructure::Cyclic(n),
+    m => GroupStructure::Product(exponent, m),
+  }
+}
+
+/// Returns the number of points over $\mathbb{F}_{p^k}$ of a curve with `points` points over
+/// $\mathbb{F}_p$.
+///
+/// The Frobenius map has two eigenvalues $\alpha, \beta$ with $\alpha + \beta = t$ and
+/// $\alpha \beta = p$, and the curve has $p^k + 1 - (\alpha^k + \beta^k)$ points over
+/// $\mathbb{F}_{p^k}$, where $s_k = \alpha^k + \beta^k$ follows $s_k = t s_{k-1} - p s_{k-2}$.
+///
+/// ## Panics
+/// If the result does not fit in a `usize`.
+pub fn count_over_extension(p: usize, points: usize, k: u32) -> usize {
+  let (p, t) = (p as i128, p as i128 + 1 - points as i128);
+  let (mut s, mut s_next) = (2, t);
+  for _ in 0..k {
+    (s, s_next) = (s_next, t * s_next - p * s);
+  }
+  (p.pow(k) + 1 - s).try_into().expect("the number of points does not fit in a usize")
+}
+
+/// Returns the coefficients of the curve as elements of the base field.
+fn coefficients<C: EllipticCurve<BaseField = PrimeField<P>>, const P: usize>(
+) -> (PrimeField<P>, PrimeField<P>) {
+  assert!(P > 3, "the short Weierstrass form needs a characteristic larger than 3");
+  let (a, b): (PrimeField<P>, PrimeField<P>) = (C::EQUATION_A.into(), C::EQUATION_B.into());
+  let discriminant = PrimeField::from(4_usize) * a * a * a + PrimeField::from(27_usize) * b * b;
+  assert_ne!(discriminant, PrimeField::ZERO, "the curve is singular");
+  (a, b)
+}
+
+/// Each `x` gives two points if $x^3 + ax + b$ is a nonzero square, one if it is zero, and none
+/// otherwise.
+fn naive<const P: usize>(a: PrimeField<P>, b: PrimeField<P>) -> usize {
+  let affine: usize = (0..P)
+    .map(|x| {
+      let x = PrimeField::<P>::new(x);
+      match (x * x * x + a * x + b).legendre() {
+        LegendreSymbol::Zero => 1,
+        LegendreSymbol::QuadraticResidue => 2,
+        LegendreSymbol::QuadraticNonResidue => 0,
+      }
+    })
+    .sum();
+  affine + 1
+}
+
+/// Returns the interval $[p + 1 - 2 \sqrt{p}, p + 1 + 2 \sqrt{p}]$ of Hasse's theorem.
+fn hasse_interval(p: usize) -> (usize, usize) {
+  let width = isqrt(4 * p as u64) as usize;
+  (p + 1 - width, p + 1 + width)
+}
+
+/// Returns the multiple of `n` in `[low, high]` if there is exactly one, assuming there is one.
+fn unique_multiple(n: usize, low: usize, high: usize) -> Option<usize> {
+  let first = low.div_ceil(n) * n;
+  (first + n > high).then_some(first)
+}
+
+/// Returns the order of a point of a curve with a number of points in `[low, high]`, from a
+/// multiple of the order in the interval found with baby steps and giant steps.
+fn bsgs_order<C: EllipticCurve>(point: AffinePoint<C>, low: usize, high: usize) -> usize {
+  let steps = isqrt((high - low) as u64) as usize + 1;
+  // -[j] point for j < steps
+  let mut baby_steps = HashMap::new();
+  let mut multiple = AffinePoint::Infinity;
+  for j in 0..steps {
+    baby_steps.entry(-multiple).or_insert(j);
+    multiple += point;
+  }
+  // [low + i steps] point = -[j] point
+  let giant_step = multiple;
+  let mut current = double_and_add(point, &[low as u64]);
+  let multiple = (0..=steps)
+    .find_map(|i| {
+      let found = baby_steps.get(&current).map(|j| low + i * steps + j);
+      current += giant_step;
+      found
+    })
+    .expect("the number of points is in the Hasse interval");
+  order(point, multiple)
+}
+
+/// Returns the order of a point given a multiple of it, by removing prime factors from the multiple
+/// while the point is still killed by it.
+fn order<C: EllipticCurve>(point: AffinePoint<C>, multiple: usize) -> usize {
+  let mut order = multiple;
+  for (q, _) in factorize(multiple as u64) {
+    let q = q as usize;
+    while order % q == 0 && double_and_add(point, &[(order / q) as u64]) == AffinePoint::Infinity {
+      order /= q;
+    }
+  }
+  order
+}
+
+/// The quadratic twist $y^2 = x^3 + a d^2 x + b d^3$ of a curve by the non-residue
+/// $d$ = [`FiniteField::PRIMITIVE_ELEMENT`], which is isomorphic to $d y^2 = x^3 + ax + b$ through
+/// $(x, y) \mapsto (d x, d^2 y)$. The two curves have $2p + 2$ points in total. Only its equation
+/// matters, so the other constants are placeholders.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct QuadraticTwist<C, const P: usize>(PhantomData<C>);
+
+impl<C: EllipticCurve<BaseField = PrimeField<P>, Coefficient = PrimeField<P>>, const P: usize>
+  QuadraticTwist<C, P>
+{
+  /// Multiplies field elements in constant context.
+  const fn mul(lhs: PrimeField<P>, rhs: PrimeField<P>) -> PrimeField<P> {
+    PrimeField::new((lhs.value as u128 * rhs.value as u128 % P as u128) as usize)
+  }
+}
+
+impl<C: EllipticCurve<BaseField = PrimeField<P>, Coefficient = PrimeField<P>>, const P: usize>
+  EllipticCurve for QuadraticTwist<C, P>
+{
+  type BaseField = PrimeField<P>;
+  type Coefficient = PrimeField<P>;
+  type ScalarField = C::ScalarField;
+
+  const COFACTOR: &'static [u64] = &[1];
+  const EQUATION_A: PrimeField<P> = Self::mul(
+    C::EQUATION_A,
+    Self::mul(PrimeField::<P>::PRIMITIVE_ELEMENT, PrimeField::<P>::PRIMITIVE_ELEMENT),
+  );
+  const EQUATION_B: PrimeField<P> = Self::mul(
+    Self::mul(C::EQUATION_B, PrimeField::<P>::PRIMITIVE_ELEMENT),
+    Self::mul(PrimeField::<P>::PRIMITIVE_ELEMENT, PrimeField::<P>::PRIMITIVE_ELEMENT),
+  );
+  const GENERATOR: (PrimeField<P>, PrimeField<P>) = (PrimeField::ZERO, PrimeField::ZERO);
+}
+
+/// Schoof's algorithm: the trace modulo 2 from the roots of $x^3 + ax + b$, and modulo the odd
+/// primes $\ell \neq p$ from the action of the Frobenius map on the $\ell$-torsion.
+fn schoof<const P: usize>(a: PrimeField<P>, b: PrimeField<P>) -> usize {
+  let rhs = FpPolynomial::<P>::from([b, a, PrimeField::ZERO, PrimeField::ONE]);
+  let x = FpPolynomial::<P>::from([PrimeField::ZERO, PrimeField::ONE]);
+
+  // the number of points is even iff there is a point (x, 0) of order 2, i.e. x^3 + ax + b has a
+  // root in F_p, a common root with x^p - x
+  let x_p = x.pow_mod(P, &rhs);
+  let t_2 = match (x_p - x).gcd(&rhs).degree() {
+    0 => 1,
+    _ => 0,
+  };
+
+  let mut congruences = vec![(t_2, 2)];
+  let mut modulus = 2;
+  let mut primes = (3..).step_by(2).filter(|&l| l != P as u64 && is_prime(l));
+  // the trace is unique in (-2 sqrt(p), 2 sqrt(p)) once the modulus exceeds 4 sqrt(p)
+  while (modulus as u128).pow(2) <= 16 * P as u128 {
+    let l = primes.next().expect("there are infinitely many primes") as usize;
+    assert!(l <= MAX_SCHOOF_PRIME, "the field is too large for primes up to {MAX_SCHOOF_PRIME}");
+    congruences.push((trace_mod(a, &rhs, l) as u64, l as u64));
+    modulus *= l as u64;
+  }
+
+  let (t, modulus) = crt(&congruences).expect("the moduli are coprime");
+  let t = match t > modulus / 2 {
+    true => t as i128 - modulus as i128,
+    false => t as i128,
+  };
+  (P as i128 + 1 - t) as usize
+}
+
+/// Returns $t \bmod \ell$, the $\tau$ such that $\pi^2(P) + [p] P = [\tau] \pi(P)$ for the
+/// $\ell$-torsion points $P$.
+///
+/// The points are represented by their coordinates over $\mathbb{F}_p[x]/(f)$, starting with the
+/// division polynomial $f = \psi_\ell$. When $\psi_\ell$ is not irreducible, the ring is not a
+/// field and an element to invert may have a nontrivial common factor with $f$. The factor still
+/// describes a set of $\ell$-torsion points, for which the equation also gives $t$, so the
+/// computation starts over with $f$ replaced by the factor.
+fn trace_mod<const P: usize>(a: PrimeField<P>, rhs: &FpPolynomial<P>, l: usize) -> usize {
+  let mut modulus = division_polynomials(a, rhs.coefficients[0], l).swap_remove(l);
+  loop {
+    match frobenius_trace(a, rhs, l, &modulus) {
+      Ok(t) => return t,
+      Err(factor) => modulus = factor,
+    }
+  }
+}
+
+/// Finds $\tau$ over $\mathbb{F}_p[x]/(f)$, or returns a factor of $f$ if some element turns out
+/// not to be invertible.
+#[allow(clippy::result_large_err)]
+fn frobenius_trace<const P: usize>(
+  a: PrimeField<P>,
+  rhs: &FpPolynomial<P>,
+  l: usize,
+  modulus: &FpPolynomial<P>,
+) -> Result<usize, FpPolynomial<P>> {
+  let ring = TorsionRing { a, rhs: *rhs % *modulus, modulus: *modulus };
+  let x = FpPolynomial::<P>::from([PrimeField::ZERO, PrimeField::ONE]) % *modulus;
+
+  // pi(x, y) = (x^p, y^p) with y^p = y (y^2)^((p - 1) / 2)
+  let x_p = x.pow_mod(P, modulus);
+  let y_p = ring.rhs.pow_mod((P - 1) / 2, modulus);
+  let frobenius = Some((x_p, y_p));
+  // pi^2(x, y) = (x^(p^2), y (y^(p - 1))^(p + 1)) as y^(p^2 - 1) = (y^(p - 1))^(p + 1)
+  let y_p_p = y_p.mul_mod(&y_p.pow_mod(P, modulus), modulus);
+  let frobenius_squared = Some((x_p.pow_mod(P, modulus), y_p_p));
+
+  let generic = Some((x, FpPolynomial::from([PrimeField::ONE])));
+  let target = ring.add(&frobenius_squared, &ring.mul(&generic, P % l)?)?;
+  if target.is_none() {
+    return Ok(0);
+  }
+  let mut multiple = frobenius;
+  for tau in 1..l {
+    if multiple == target {
+      return Ok(tau);
+    }
+    multiple = ring.add(&multiple, &frobenius)?;
+  }
+  unreachable!("pi^2 + p is a multiple of pi on the l-torsion")
+}
+
+/// Returns the division polynomials $f_0, \ldots, f_n$ of $y^2 = x^3 + ax + b$, where
+/// $\psi_m = f_m$ for odd $m$ and $\psi_m = 2y f_m$ for even $m$, so that they only depend on $x$.
+///
+/// They follow from $\psi_0$ to $\psi_4$ by the recurrences
+/// $$\psi_{2m+1} = \psi_{m+2} \psi_m^3 - \psi_{m-1} \psi_{m+1}^3, \quad
+/// \psi_{2m} = \psi_m (\psi_{m+2} \psi_{m-1}^2 - \psi_{m-2} \psi_{m+1}^2) / 2y,$$
+/// where the even ones bring a factor $(2y)^4 = 16 (x^3 + ax + b)^2$ to the odd ones.
+///
+/// ## Panics
+/// If `n` is larger than [`MAX_SCHOOF_PRIME`].
+fn division_polynomials<const P: usize>(
+  a: PrimeField<P>,
+  b: PrimeField<P>,
+  n: usize,
+) -> Vec<FpPolynomial<P>> {
+  let c = |k: usize| PrimeField::<P>::from(k);
+  let zero = PrimeField::ZERO;
+  let mut f = vec![
+    FpPolynomial::<P>::from([zero]),
+    FpPolynomial::<P>::from([PrimeField::ONE]),
+    FpPolynomial::<P>::from([PrimeField::ONE]),
+    // 3x^4 + 6ax^2 + 12bx - a^2
+    FpPolynomial::<P>::from([-a * a, c(12) * b, c(6) * a, zero, c(3)]),
+    // 2 (x^6 + 5ax^4 + 20bx^3 - 5a^2x^2 - 4abx - 8b^2 - a^3)
+    FpPolynomial::<P>::from([
+      -c(2) * (c(8) * b * b + a * a * a),
+      -c(8) * a * b,
+      -c(10) * a * a,
+      c(40) * b,
+      c(10) * a,
+      zero,
+      c(2),
+    ]),
+  ];
+  let mul = FpPolynomial::<P>::mul_within;
+  let rhs = FpPolynomial::<P>::from([b, a, zero, PrimeField::ONE]);
+  let rhs_squared = mul(&rhs, &rhs).scale(c(16));
+  let cube = |p: &FpPolynomial<P>| mul(p, &mul(p, p));
+  for k in 5..=n {
+    let m = k / 2;
+    let next = match (k % 2, m % 2) {
+      (1, 0) => mul(&rhs_squared, &mul(&f[m + 2], &cube(&f[m]))) - mul(&f[m - 1], &cube(&f[m + 1])),
+      (1, _) => mul(&f[m + 2], &cube(&f[m])) - mul(&rhs_squared, &mul(&f[m - 1], &cube(&f[m + 1]))),
+      _ => mul(
+        &f[m],
+        &(mul(&f[m + 2], &mul(&f[m - 1], &f[m - 1])) - mul(&f[m - 2], &mul(&f[m + 1], &f[m + 1]))),
+      ),
+    };
+    f.push(next);
+  }
+  f.truncate(n + 1);
+  f
+}
+
+/// The curve over $\mathbb{F}_p[x]/(f)$, where its points are written $(X, yY)$ with $y^2$ replaced
+/// by $x^3 + ax + b$. Operations return a factor of $f$ when they need to invert a zero divisor.
+struct TorsionRing<const P: usize> {
+  /// Coefficient `a` of the curve.
+  a:       PrimeField<P>,
+  /// $x^3 + ax + b$ reduced modulo $f$.
+  rhs:     FpPolynomial<P>,
+  /// The modulus $f$.
+  modulus: FpPolynomial<P>,
+}
+
+// a factor of the modulus is found at most a few times per prime, so it is not worth boxing
+#[allow(clippy::result_large_err)]
+impl<const P: usize> TorsionRing<P> {
+  /// Adds two points: with the slope $\lambda = y L$, the sum is $(X_3, y Y_3)$ where
+  /// $X_3 = y^2 L^2 - X_1 - X_2$ and $Y_3 = L (X_1 - X_3) - Y_1$.
+  fn add(
+    &self,
+    lhs: &TorsionPoint<P>,
+    rhs: &TorsionPoint<P>,
+  ) -> Result<TorsionPoint<P>, FpPolynomial<P>> {
+    let ((x_1, y_1), (x_2, y_2)) = match (lhs, rhs) {
+      (None, point) | (point, None) => return Ok(*point),
+      (Some(lhs), Some(rhs)) => (lhs, rhs),
+    };
+    if x_1 == x_2 {
+      let y_sum = *y_1 + *y_2;
+      return match (y_1 == y_2, y_sum.is_zero()) {
+        (true, _) => self.double(lhs),
+        (false, true) => Ok(None),
+        // the points are equal for some roots of f and opposite for the others
+        (false, false) => Err(y_sum.gcd(&self.modulus)),
+      };
+    }
+    let slope = (*y_2 - *y_1).mul_mod(&self.inverse(&(*x_2 - *x_1))?, &self.modulus);
+    Ok(Some(self.chord(&slope, x_1, y_1, x_2)))
+  }
+
+  /// Doubles a point: $\lambda = (3X^2 + a) / 2yY = y (3X^2 + a) / (2 (x^3 + ax + b) Y)$.
+  fn double(&self, point: &TorsionPoint<P>) -> Result<TorsionPoint<P>, FpPolynomial<P>> {
+    let Some((x, y)) = point else {
+      return Ok(None);
+    };
+    if y.is_zero() {
+      return Ok(None);
+    }
+    let numerator = x.mul_mod(x, &self.modulus).scale(PrimeField::from(3_usize))
+      + FpPolynomial::<P>::from([self.a]);
+    let denominator = self.rhs.mul_mod(y, &self.modulus).scale(PrimeField::from(2_usize));
+    let slope = numerator.mul_mod(&self.inverse(&denominator)?, &self.modulus);
+    Ok(Some(self.chord(&slope, x, y, x)))
+  }
+
+  /// The third point on the line of slope $y L$ through $(X_1, y Y_1)$ and $(X_2, y Y_2)$, negated.
+  fn chord(
+    &self,
+    slope: &FpPolynomial<P>,
+    x_1: &FpPolynomial<P>,
+    y_1: &FpPolynomial<P>,
+    x_2: &FpPolynomial<P>,
+  ) -> (FpPolynomial<P>, FpPolynomial<P>) {
+    let slope_squared = self.rhs.mul_mod(&slope.mul_mod(slope, &self.modulus), &self.modulus);
+    let x_3 = slope_squared - *x_1 - *x_2;
+    let y_3 = slope.mul_mod(&(*x_1 - x_3), &self.modulus) - *y_1;
+    (x_3, y_3)
+  }
+
+  /// Multiplies a point by `n` with double-and-add.
+  fn mul(&self, point: &TorsionPoint<P>, n: usize) -> Result<TorsionPoint<P>, FpPolynomial<P>> {
+    let mut result = None;
+    for i in (0..usize::BITS - n.leading_zeros()).rev() {
+      result = self.double(&result)?;
+      if (n >> i) & 1 == 1 {
+        result = self.add(&result, point)?;
+      }
+    }
+    Ok(result)
+  }
+
+  /// Inverts an element, or returns its common factor with the modulus.
+  fn inverse(&self, element: &FpPolynomial<P>) -> Result<FpPolynomial<P>, FpPolynomial<P>> {
+    // r_i = s_i element (mod f)
+    let (mut r_0, mut r_1) = (self.modulus, *element);
+    let (mut s_0, mut s_1) =
+      (FpPolynomial::<P>::from([PrimeField::ZERO]), FpPolynomial::<P>::from([PrimeField::ONE]));
+    while !r_1.is_zero() {
+      let (quotient, remainder) = r_0.quotient_and_remainder(r_1);
+      let s_2 = s_0 - quotient.mul_within(&s_1);
+      (r_0, r_1, s_0, s_1) = (r_1, remainder, s_1, s_2);
+    }
+    match r_0.degree() {
+      0 => Ok(s_0.scale(r_0.leading_coefficient().inverse().unwrap()) % self.modulus),
+      _ => Err(r_0.monic()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use rand::thread_rng;
+
+  use super::*;
+  use crate::algebra::field::prime::Mersenne31;
+
+  /// Declares a curve over a prime field to count the points of. Only its equation matters, so the
+  /// other constants are placeholders.
+  macro_rules! curve {
+    ($name:ident, $field:ty, $a:expr, $b:expr) => {
+      #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+      struct $name;
+
+      impl EllipticCurve for $name {
+        type BaseField = $field;
+        type Coefficient = $field;
+        type ScalarField = PlutoScalarField;
+
+        const COFACTOR: &'static [u64] = &[1];
+        const EQUATION_A: $field = <$field>::new($a);
+        const EQUATION_B: $field = <$field>::new($b);
+        const GENERATOR: ($field, $field) = (<$field>::ZERO, <$field>::ZERO);
+        const ORDER: usize = 1;
+      }
+    };
+  }
+
+  const MERSENNE_31: usize = 0x7FFF_FFFF;
+
+  type F10007 = PrimeField<10007>;
+
+  curve!(FullTwoTorsion, PlutoBaseField, 100, 0);
+  curve!(FullTenTorsion, PlutoBaseField, 1, 0);
+  curve!(Curve10007, F10007, 3, 7);
+  curve!(Twist10007, F10007, 0, 5);
+  curve!(Mersenne31Curve, Mersenne31, 1, 3);
+  curve!(Mersenne31Secp, Mersenne31, 0, 7);
+
+  #[test]
+  fn pluto_constants() {
+    let points = PlutoBaseCurve::ORDER * PlutoBaseCurve::COFACTOR[0] as usize;
+    assert_eq!(naive_count::<PlutoBaseCurve, 101>(), points);
+    assert_eq!(bsgs_count::<PlutoBaseCurve, 101>(), points);
+    assert_eq!(schoof_count::<PlutoBaseCurve, 101>(), points);
+    assert_eq!(
+      group_structure::<PlutoBaseCurve, 101>(&mut thread_rng()),
+      GroupStructure::Cyclic(points)
+    );
+
+    // the extended curve is the same curve over F_{101^2}
+    assert_eq!(
+      count_over_extension(PlutoPrime::Base as usize, points, 2),
+      PlutoExtendedCurve::ORDER * PlutoExtendedCurve::COFACTOR[0] as usize
+    );
+  }
+
+  #[test]
+  fn group_structures() {
+    let mut rng = thread_rng();
+    // y^2 = x^3 - x has the three points (-1, 0), (0, 0) and (1, 0) of order 2
+    assert_eq!(naive_count::<FullTwoTorsion, 101>(), 104);
+    assert_eq!(group_structure::<FullTwoTorsion, 101>(&mut rng), GroupStructure::Product(52, 2));
+    assert_eq!(schoof_count::<FullTenTorsion, 101>(), 100);
+    assert_eq!(group_structure::<FullTenTorsion, 101>(&mut rng), GroupStructure::Product(10, 10));
+  }
+
+  #[test]
+  fn counts_agree() {
+    let expected = naive_count::<Curve10007, 10007>();
+    assert_eq!(bsgs_count::<Curve10007, 10007>(), expected);
+    assert_eq!(schoof_count::<Curve10007, 10007>(), expected);
+    let expected = naive_count::<Twist10007, 10007>();
+    assert_eq!(bsgs_count::<Twist10007, 10007>(), expected);
+    assert_eq!(schoof_count::<Twist10007, 10007>(), expected);
+    assert_eq!(
+      schoof_count::<Mersenne31Curve, MERSENNE_31>(),
+      bsgs_count::<Mersenne31Curve, MERSENNE_31>()
+    );
+    assert_eq!(
+      schoof_count::<Mersenne31Secp, MERSENNE_31>(),
+      bsgs_count::<Mersenne31Secp, MERSENNE_31>()
+    );
+  }
+
+  #[test]
+  fn division_polynomials_vanish_on_torsion() {
+    let a = PlutoBaseCurve::EQUATION_A;
+    let b = PlutoBaseCurve::EQUATION_B;
+    let f = division_polynomials(a, b, MAX_SCHOOF_PRIME);
+    for x in 0..101 {
+      let x = PlutoBaseField::new(x);
+      let Some(y) = (x * x * x + b).square_root() else {
+        continue;
+      };
+      // psi_n = 2y f_n also vanishes at the points of order 2 for even n
+      if y == PlutoBaseField::ZERO {
+        continue;
+      }
+      for (n, f_n) in f.iter().enumerate().skip(1) {
+        let multiple = double_and_add(AffinePoint::<PlutoBaseCurve>::Point(x, y), &[n as u64]);
+        assert_eq!(f_n.evaluate(x) == PlutoBaseField::ZERO, multiple == AffinePoint::Infinity);
+      }
+    }
+  }
+
+  #[test]
+  fn extensions() {
+    // a supersingular curve has trace 0 over F_p and -2p over F_{p^2}
+    assert_eq!(count_over_extension(101, 102, 1), 102);
+    assert_eq!(count_over_extension(101, 102, 2), 102 * 102);
+    assert_eq!(count_over_extension(101, 102, 4), (101 * 101 - 1) * (101 * 101 - 1));
+  }
+}
@@ -0,0 +1,225 @@
+//! Points in [homogeneous projective coordinates](https://hyperelliptic.org/EFD/g1p/auto-shortw-projective.html).
+//!
+//! A point $(X : Y : Z)$ with $Z \neq 0$ stands for the affine point $(X / Z, Y / Z)$, and the
+//! point at infinity is $(0 : 1 : 0)$. The curve equation becomes
+//! $$ Y^2 Z = X^3 + a X Z^2 + b Z^3. $$
+//!
+//! Addition uses the complete formulas of [Renes, Costello and Batina](https://eprint.iacr.org/2015/1060):
+//! a single branch-free expression which also doubles and handles the point at infinity, for any
+//! pair of points on a curve without points of order two, e.g. a curve of prime order. On other
+//! curves the formulas degenerate to $(0 : 0 : 0)$ when $P - Q$ has order two, and the sum is then
+//! computed in [`JacobianPoint`](super::jacobian::JacobianPoint) coordinates instead.
+use super::{jacobian::JacobianPoint, *};
+
+/// A point on the curve `C` in projective coordinates `(X : Y : Z)`, representing the affine point
+/// `(X / Z, Y / Z)`, or the point at infinity if `Z = 0`.
+#[derive(Clone, Copy, Debug)]
+pub struct ProjectivePoint<C: EllipticCurve> {
+  x: C::BaseField,
+  y: C::BaseField,
+  z: C::BaseField,
+}
+
+impl<C: EllipticCurve> ProjectivePoint<C> {
+  /// Creates a point from its projective coordinates.
+  ///
+  /// ## Errors
+  /// [`PointError::NotOnCurve`] if the coordinates don't satisfy
+  /// $Y^2 Z = X^3 + a X Z^2 + b Z^3$, or are all zero.
+  pub fn new(x: C::BaseField, y: C::BaseField, z: C::BaseField) -> Result<Self, PointError> {
+    let point = Self { x, y, z };
+    match point.is_on_curve() {
+      true => Ok(point),
+      false => Err(PointError::NotOnCurve),
+    }
+  }
+
+  /// Returns the coordinates `(X, Y, Z)`.
+  pub fn coordinates(&self) -> (C::BaseField, C::BaseField, C::BaseField) {
+    (self.x, self.y, self.z)
+  }
+
+  /// Returns whether the point is the point at infinity.
+  pub fn is_identity(&self) -> bool { self.z == C::BaseField::ZERO }
+}
+
+impl<C: EllipticCurve> PartialEq for ProjectivePoint<C> {
+  /// Compares the affine points scaled by $Z_1 Z_2$.
+  fn eq(&self, other: &Self) -> bool {
+    self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+  }
+}
+
+impl<C: EllipticCurve> Eq for ProjectivePoint<C> {}
+
+impl<C: EllipticCurve> Group for ProjectivePoint<C> {
+  type Scalar = C::ScalarField;
+
+  const IDENTITY: Self =
+    Self { x: C::BaseField::ZERO, y: C::BaseField::ONE, z: C::BaseField::ZERO };
+
+  /// Adds two points with the complete formulas of Renes, Costello and Batina (Algorithm 1):
+  /// $$
+  /// \begin{aligned}
+  /// X_3 &= (X_1 Y_2 + X_2 Y_1) U - (Y_1 Z_2 + Y_2 Z_1) W, \\
+  /// Y_3 &= (3 X_1 X_2 + a Z_1 Z_2) W + V U, \\
+  /// Z_3 &= (Y_1 Z_2 + Y_2 Z_1) V + (X_1 Y_2 + X_2 Y_1)(3 X_1 X_2 + a Z_1 Z_2),
+  /// \end{aligned}
+  /// $$
+  /// where $U, V = Y_1 Y_2 \mp (a (X_1 Z_2 + X_2 Z_1) + 3 b Z_1 Z_2)$ and
+  /// $W = a X_1 X_2 + 3 b (X_1 Z_2 + X_2 Z_1) - a^2 Z_1 Z_2$.
+  fn op(&self, rhs: &Self) -> Self {
+    let a: C::BaseField = C::EQUATION_A.into();
+    let b3: C::BaseField = C::BaseField::from(3) * C::EQUATION_B.into();
+    let xx = self.x * rhs.x;
+    let yy = self.y * rhs.y;
+    let zz = self.z * rhs.z;
+    let xy = self.x * rhs.y + rhs.x * self.y;
+    let xz = self.x * rhs.z + rhs.x * self.z;
+    let yz = self.y * rhs.z + rhs.y * self.z;
+
+    let u = yy - (a * xz + b3 * zz);
+    let v = yy + (a * xz + b3 * zz);
+    let w = a * xx + b3 * xz - a * a * zz;
+    let t = C::BaseField::from(3) * xx + a * zz;
+    let sum = Self { x: xy * u - yz * w, y: t * w + v * u, z: yz * v + xy * t };
+    let zero = C::BaseField::ZERO;
+    match (sum.x, sum.y, sum.z) == (zero, zero, zero) {
+      // exceptional pair, only possible on curves with points of order two
+      true => (JacobianPoint::from(*self) + JacobianPoint::from(*rhs)).into(),
+      false => sum,
+    }
+  }
+
+  fn inverse(&self) -> Option<Self> { Some(-*self) }
+
+  fn scalar_mul(&self, scalar: Self::Scalar) -> Self { double_and_add(*self, &scalar.to_limbs()) }
+}
+
+impl<C: EllipticCurve> CurveGroup for ProjectivePoint<C> {
+  type BaseField = C::BaseField;
+
+  /// Doubles with the complete addition formulas, which don't distinguish `P + P` from `P + Q`.
+  fn double(self) -> Self { self.op(&self) }
+
+  fn is_on_curve(&self) -> bool {
+    let a: C::BaseField = C::EQUATION_A.into();
+    let b: C::BaseField = C::EQUATION_B.into();
+    let (x, y, z) = (self.x, self.y, self.z);
+    let zero = C::BaseField::ZERO;
+    (x, y, z) != (zero, zero, zero) && y * y * z == x * x * x + a * x * z * z + b * z * z * z
+  }
+
+  fn xy(&self) -> (Self::BaseField, Self::BaseField, bool) { AffinePoint::from(*self).xy() }
+
+  fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+    Self {
+      x: select_coordinate(a.x, b.x, choice),
+      y: select_coordinate(a.y, b.y, choice),
+      z: select_coordinate(a.z, b.z, choice),
+    }
+  }
+}
+
+impl_curve_ops!(ProjectivePoint);
+
+impl<C: EllipticCurve> From<AffinePoint<C>> for ProjectivePoint<C> {
+  fn from(point: AffinePoint<C>) -> Self {
+    match point {
+      AffinePoint::Point(x, y) => Self { x, y, z: C::BaseField::ONE },
+      AffinePoint::Infinity => Self::IDENTITY,
+    }
+  }
+}
+
+impl<C: EllipticCurve> From<ProjectivePoint<C>> for AffinePoint<C> {
+  /// Normalises the point to $Z = 1$, at the cost of one field inversion.
+  fn from(point: ProjectivePoint<C>) -> Self {
+    match point.z.inverse() {
+      Some(z_inv) => AffinePoint::Point(point.x * z_inv, point.y * z_inv),
+      None => AffinePoint::Infinity,
+    }
+  }
+}
+
+impl<C: EllipticCurve> From<JacobianPoint<C>> for ProjectivePoint<C> {
+  /// $(X : Y : Z) \mapsto (X Z : Y : Z^3)$, without any inversion.
+  fn from(point: JacobianPoint<C>) -> Self {
+    let (x, y, z) = point.coordinates();
+    match point.is_identity() {
+      true => Self::IDENTITY,
+      false => Self { x: x * z, y, z: z * z * z },
+    }
+  }
+}
+
+impl<C: EllipticCurve> From<ProjectivePoint<C>> for JacobianPoint<C> {
+  /// $(X : Y : Z) \mapsto (X Z : Y Z^2 : Z)$, without any inversion.
+  fn from(point: ProjectivePoint<C>) -> Self {
+    match point.is_identity() {
+      true => Self::IDENTITY,
+      false => Self { x: point.x * point.z, y: point.y * point.z * point.z, z: point.z },
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::curve::pluto_curve::PlutoBaseCurve;
+
+  /// All the points of the curve, including the point of order two.
+  fn points() -> Vec<AffinePoint<PlutoBaseCurve>> {
+    let mut points = vec![AffinePoint::Infinity];
+    for x in 0..PlutoPrime::Base as usize {
+      for y in 0..PlutoPrime::Base as usize {
+        let point = AffinePoint::Point(PlutoBaseField::new(x), PlutoBaseField::new(y));
+        if point.is_on_curve() {
+          points.push(point);
+        }
+      }
+    }
+    points
+  }
+
+  #[test]
+  fn conversions() {
+    let g = AffinePoint::<PlutoBaseCurve>::GENERATOR;
+    let projective = ProjectivePoint::from(g);
+    assert_eq!(projective, ProjectivePoint::GENERATOR);
+    assert_eq!(AffinePoint::from(projective), g);
+    assert_eq!(
+      AffinePoint::<PlutoBaseCurve>::from(ProjectivePoint::IDENTITY),
+      AffinePoint::Infinity
+    );
+
+    let jacobian = JacobianPoint::from(g).double() + JacobianPoint::from(g);
+    assert_eq!(ProjectivePoint::from(jacobian), projective * PlutoScalarField::new(3));
+    assert_eq!(JacobianPoint::from(ProjectivePoint::from(jacobian)), jacobian);
+    assert_eq!(ProjectivePoint::from(jacobian).xy(), jacobian.xy());
+  }
+
+  #[test]
+  fn group_law_matches_affine() {
+    let points = points();
+    for &p in &points {
+      for &q in &points {
+        let sum = ProjectivePoint::from(p) + ProjectivePoint::from(q);
+        assert!(sum.is_on_curve());
+        assert_eq!(AffinePoint::from(sum), p + q);
+      }
+    }
+  }
+
+  #[test]
+  fn scalar_multiplication() {
+    let g = ProjectivePoint::<PlutoBaseCurve>::GENERATOR;
+    let mut multiple = ProjectivePoint::IDENTITY;
+    for k in 0..PlutoPrime::Scalar as usize {
+      assert_eq!(g * PlutoScalarField::new(k), multiple);
+      assert_eq!(AffinePoint::from(g) * PlutoScalarField::new(k), AffinePoint::from(multiple));
+      multiple += g;
+    }
+    assert_eq!(multiple, ProjectivePoint::IDENTITY);
+  }
+}
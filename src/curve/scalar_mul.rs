@@ -0,0 +1,315 @@
+//! Scalar multiplication strategies for any [`CurveGroup`].
+//!
+//! Computing $kP$ by adding $P$ to itself $k$ times is hopeless for cryptographic scalars, so all
+//! strategies scan the binary expansion of $k$ and take $O(\log k)$ doublings. They differ in how
+//! many additions they need on top, and in what they leak about $k$:
+//! - [`double_and_add`] adds $P$ for every set bit, about $\log k / 2$ additions on average.
+//! - [`fixed_window`] processes `w` bits at a time with the precomputed multiples $0, P, \ldots,
+//!   (2^w - 1)P$, for one addition per window.
+//! - [`wnaf`] writes $k$ in the width-`w` non-adjacent form, whose nonzero digits are odd and at
+//!   least `w` apart. Negation is free on a curve, so only the odd multiples $P, 3P, \ldots,
+//!   (2^{w-1} - 1)P$ are precomputed, in a [`WnafTable`] that can be reused for many scalars, and
+//!   about $\log k / (w + 1)$ additions are needed.
+//! - [`montgomery_ladder`] performs one addition and one doubling for every bit of the scalar,
+//!   whatever its value, which makes the sequence of group operations independent of the secret
+//!   scalar. It is not constant-time, as the field arithmetic below is not.
+//!
+//! Scalars are given as little-endian 64-bit limbs, so that scalars larger than a `usize` can be
+//! used. The strategies are also exposed through the [`ScalarMul`] enum to be selected at runtime.
+use std::cmp::Ordering;
+
+use crypto_bigint::subtle::Choice;
+
+use super::*;
+
+/// The scalar multiplication algorithms, see the [module documentation](self).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScalarMul {
+  /// [`double_and_add`].
+  DoubleAndAdd,
+  /// [`fixed_window`] with windows of the given width.
+  FixedWindow(usize),
+  /// [`wnaf`] with the given width.
+  Wnaf(usize),
+  /// [`montgomery_ladder`].
+  MontgomeryLadder,
+}
+
+impl ScalarMul {
+  /// Computes `scalar * point` with the strategy.
+  pub fn mul<G: CurveGroup>(self, point: G, scalar: &[u64]) -> G {
+    match self {
+      ScalarMul::DoubleAndAdd => double_and_add(point, scalar),
+      ScalarMul::FixedWindow(width) => fixed_window(point, scalar, width),
+      ScalarMul::Wnaf(width) => wnaf(point, scalar, width),
+      ScalarMul::MontgomeryLadder => montgomery_ladder(point, scalar),
+    }
+  }
+}
+
+/// Returns bit `i` of the scalar.
+fn bit(scalar: &[u64], i: usize) -> bool {
+  scalar.get(i / 64).is_some_and(|limb| (limb >> (i % 64)) & 1 == 1)
+}
+
+/// Returns the number of significant bits of the scalar.
+pub(crate) fn bits(scalar: &[u64]) -> usize {
+  match scalar.iter().rposition(|&limb| limb != 0) {
+    Some(i) => 64 * i + (u64::BITS - scalar[i].leading_zeros()) as usize,
+    None => 0,
+  }
+}
+
+/// Returns the `width` bits of the scalar starting at bit `start`, as an integer.
+pub(crate) fn window(scalar: &[u64], start: usize, width: usize) -> usize {
+  (0..width).filter(|&j| bit(scalar, start + j)).map(|j| 1 << j).sum()
+}
+
+/// Computes `scalar * point` with the double-and-add algorithm, scanning the bits of `scalar` from
+/// the most significant one: the accumulator is doubled for every bit, and `point` is added to it
+/// for every set bit. Both the number of additions and the time they take reveal the scalar.
+pub fn double_and_add<G: CurveGroup>(point: G, scalar: &[u64]) -> G {
+  (0..bits(scalar)).rev().fold(G::IDENTITY, |acc, i| {
+    let acc = acc.double();
+    match bit(scalar, i) {
+      true => acc.op(&point),
+      false => acc,
+    }
+  })
+}
+
+/// Computes `scalar * point` with fixed windows of `width` bits: after precomputing the multiples
+/// $0, P, \ldots, (2^w - 1)P$, every window costs `width` doublings and a single addition of the
+/// multiple given by its bits.
+///
+/// ## Panics
+/// If `width` is not between 1 and 16, as the table holds $2^w$ points.
+pub fn fixed_window<G: CurveGroup>(point: G, scalar: &[u64], width: usize) -> G {
+  assert!((1..=16).contains(&width), "window width should be between 1 and 16");
+  let mut multiples = vec![G::IDENTITY; 1 << width];
+  for i in 1..multiples.len() {
+    multiples[i] = multiples[i - 1].op(&point);
+  }
+  (0..bits(scalar).div_ceil(width)).rev().fold(G::IDENTITY, |acc, i| {
+    let acc = (0..width).fold(acc, |acc, _| acc.double());
+    acc.op(&multiples[window(scalar, i * width, width)])
+  })
+}
+
+/// Returns the width-`w` non-adjacent form of the scalar, least significant digit first: digits
+/// $d_i$ with $k = \sum_i d_i 2^i$ that are either zero or odd with $|d_i| < 2^{w-1}$, and such
+/// that any `w` consecutive digits contain at most one nonzero digit.
+///
+/// While $k$ is nonzero, an odd $k$ outputs its centered residue $d \equiv k \bmod 2^w$ and
+/// continues with $k - d$, which is divisible by $2^w$ so is followed by at least $w - 1$ zeros.
+///
+/// ## Panics
+/// If `width` is not between 2 and 63.
+pub fn wnaf_digits(scalar: &[u64], width: usize) -> Vec<i64> {
+  assert!((2..64).contains(&width), "wNAF width should be between 2 and 63");
+  // one more limb for the carry of k - d with d negative
+  let mut k = scalar.to_vec();
+  k.push(0);
+  let mut digits = Vec::with_capacity(bits(scalar) + 1);
+  while k.iter().any(|&limb| limb != 0) {
+    let mut digit = 0;
+    if k[0] & 1 == 1 {
+      digit = (k[0] & ((1 << width) - 1)) as i64;
+      if digit >= 1 << (width - 1) {
+        digit -= 1 << width;
+      }
+      // k -= digit, propagating the borrow or the carry
+      let mut carry = digit.unsigned_abs();
+      for limb in k.iter_mut() {
+        let (value, overflow) = match digit > 0 {
+          true => limb.overflowing_sub(carry),
+          false => limb.overflowing_add(carry),
+        };
+        *limb = value;
+        carry = overflow as u64;
+      }
+    }
+    digits.push(digit);
+    // k >>= 1
+    for i in 0..k.len() {
+      k[i] = (k[i] >> 1) | k.get(i + 1).map_or(0, |next| next << 63);
+    }
+  }
+  digits
+}
+
+/// The odd multiples $P, 3P, \ldots, (2^{w-1} - 1)P$ of a point, precomputed once to multiply it by
+/// many scalars with the width-`w` non-adjacent form of [`wnaf_digits`].
+#[derive(Clone, Debug)]
+pub struct WnafTable<G: CurveGroup> {
+  width:         usize,
+  odd_multiples: Vec<G>,
+}
+
+impl<G: CurveGroup> WnafTable<G> {
+  /// Precomputes the odd multiples of `point` needed for digits of width `width`.
+  ///
+  /// ## Panics
+  /// If `width` is not between 2 and 16, as the table holds $2^{w-2}$ points.
+  pub fn new(point: G, width: usize) -> Self {
+    assert!((2..=16).contains(&width), "wNAF table width should be between 2 and 16");
+    let double = point.double();
+    let mut odd_multiples = vec![point; 1 << (width - 2)];
+    for i in 1..odd_multiples.len() {
+      odd_multiples[i] = odd_multiples[i - 1].op(&double);
+    }
+    Self { width, odd_multiples }
+  }
+
+  /// Computes `scalar * point`, with one doubling per digit and one addition or subtraction of a
+  /// precomputed multiple per nonzero digit.
+  pub fn mul(&self, scalar: &[u64]) -> G {
+    wnaf_digits(scalar, self.width).iter().rev().fold(G::IDENTITY, |acc, &digit| {
+      let acc = acc.double();
+      let multiple = self.odd_multiples[(digit.unsigned_abs() as usize) / 2];
+      match digit.cmp(&0) {
+        Ordering::Greater => acc.op(&multiple),
+        Ordering::Less => acc - multiple,
+        Ordering::Equal => acc,
+      }
+    })
+  }
+}
+
+/// Computes `scalar * point` with the width-`width` non-adjacent form, see [`WnafTable`] to reuse
+/// the precomputed multiples across scalars.
+///
+/// ## Panics
+/// If `width` is not between 2 and 16.
+pub fn wnaf<G: CurveGroup>(point: G, scalar: &[u64], width: usize) -> G {
+  WnafTable::new(point, width).mul(scalar)
+}
+
+/// Computes `scalar * point` with the Montgomery ladder, keeping $R_1 - R_0 = P$: a zero bit maps
+/// $(R_0, R_1)$ to $(2R_0, R_0 + R_1)$ and a set bit to $(R_0 + R_1, 2R_1)$.
+///
+/// Every one of the `64 * scalar.len()` bits, leading zeros included, costs exactly one addition
+/// and one doubling. A set bit exchanges the accumulators before and after the step with
+/// [`CurveGroup::conditional_select`], so that the bit is neither branched on nor used as an index.
+/// This only fixes the sequence of group operations: the ladder is not constant-time, since the
+/// selections, the group operations and the field arithmetic below them all take time that
+/// depends on the values of the points.
+pub fn montgomery_ladder<G: CurveGroup>(point: G, scalar: &[u64]) -> G {
+  let (mut r0, mut r1) = (G::IDENTITY, point);
+  for i in (0..64 * scalar.len()).rev() {
+    let choice = Choice::from(bit(scalar, i) as u8);
+    conditional_swap(&mut r0, &mut r1, choice);
+    r1 = r0.op(&r1);
+    r0 = r0.double();
+    conditional_swap(&mut r0, &mut r1, choice);
+  }
+  r0
+}
+
+/// Swaps `a` and `b` if `choice` is set, without branching on it.
+fn conditional_swap<G: CurveGroup>(a: &mut G, b: &mut G, choice: Choice) {
+  let (old_a, old_b) = (*a, *b);
+  *a = G::conditional_select(&old_a, &old_b, choice);
+  *b = G::conditional_select(&old_b, &old_a, choice);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::curve::{
+    jacobian::JacobianPoint,
+    pluto_curve::{PlutoBaseCurve, PlutoExtendedCurve},
+    projective::ProjectivePoint,
+  };
+
+  const STRATEGIES: [ScalarMul; 9] = [
+    ScalarMul::DoubleAndAdd,
+    ScalarMul::FixedWindow(1),
+    ScalarMul::FixedWindow(3),
+    ScalarMul::FixedWindow(4),
+    ScalarMul::FixedWindow(7),
+    ScalarMul::Wnaf(2),
+    ScalarMul::Wnaf(3),
+    ScalarMul::Wnaf(5),
+    ScalarMul::MontgomeryLadder,
+  ];
+
+  /// Checks all strategies against repeated addition.
+  fn cross_check<G: CurveGroup>(point: G) {
+    let mut multiple = G::IDENTITY;
+    for k in 0..100 {
+      for strategy in STRATEGIES {
+        assert_eq!(strategy.mul(point, &[k]), multiple, "{strategy:?} with k = {k}");
+      }
+      multiple = multiple.op(&point);
+    }
+  }
+
+  #[test]
+  fn strategies_agree() {
+    cross_check(AffinePoint::<PlutoBaseCurve>::GENERATOR);
+    cross_check(JacobianPoint::<PlutoBaseCurve>::GENERATOR);
+    cross_check(ProjectivePoint::<PlutoBaseCurve>::GENERATOR);
+    cross_check(JacobianPoint::<PlutoExtendedCurve>::GENERATOR);
+  }
+
+  #[test]
+  fn multi_limb_scalars() {
+    let g = JacobianPoint::<PlutoBaseCurve>::GENERATOR;
+    let scalar = [u64::MAX, 0x1234_5678_9abc_def0, 3];
+    // the generator has order 17
+    let reduced = scalar.iter().rev().fold(0, |acc, &limb| ((acc << 64) | limb as u128) % 17);
+    let expected = double_and_add(g, &[reduced as u64]);
+    for strategy in STRATEGIES {
+      assert_eq!(strategy.mul(g, &scalar), expected, "{strategy:?}");
+    }
+    assert_eq!(ScalarMul::MontgomeryLadder.mul(g, &[]), JacobianPoint::IDENTITY);
+  }
+
+  #[test]
+  fn non_adjacent_form() {
+    for width in 2..8 {
+      for scalar in [[0, 0], [1, 0], [0x2d, 0], [u64::MAX, 0], [u64::MAX, u64::MAX], [7, 1 << 63]] {
+        let digits = wnaf_digits(&scalar, width);
+        // k = Σ d_i 2^i, computed modulo 2^128
+        let value = digits.iter().enumerate().fold(0u128, |acc, (i, &d)| {
+          acc.wrapping_add((d as i128 as u128).checked_shl(i as u32).unwrap_or(0))
+        });
+        assert_eq!(value, (scalar[1] as u128) << 64 | scalar[0] as u128);
+        assert!(digits.iter().all(|d| d % 2 != 0 || *d == 0));
+        assert!(digits.iter().all(|d| d.unsigned_abs() < 1 << (width - 1)));
+        assert!(digits.windows(width).all(|w| w.iter().filter(|&&d| d != 0).count() <= 1));
+        assert!(digits.len() <= 129);
+      }
+    }
+    assert_eq!(wnaf_digits(&[7], 2), vec![-1, 0, 0, 1]);
+  }
+
+  #[test]
+  fn reusable_table() {
+    let g = ProjectivePoint::<PlutoBaseCurve>::GENERATOR;
+    let table = WnafTable::new(g, 4);
+    for k in 0..40 {
+      assert_eq!(table.mul(&[k]), montgomery_ladder(g, &[k]));
+    }
+  }
+
+  #[test]
+  fn conditional_swap_points() {
+    let g = AffinePoint::<PlutoBaseCurve>::GENERATOR;
+    for (a, b) in [(g, g.double()), (AffinePoint::Infinity, g), (g, AffinePoint::Infinity)] {
+      let (mut x, mut y) = (a, b);
+      conditional_swap(&mut x, &mut y, Choice::from(0));
+      assert_eq!((x, y), (a, b));
+      conditional_swap(&mut x, &mut y, Choice::from(1));
+      assert_eq!((x, y), (b, a));
+    }
+    let (a, b) = (JacobianPoint::<PlutoBaseCurve>::GENERATOR, JacobianPoint::IDENTITY);
+    assert_eq!(JacobianPoint::conditional_select(&a, &b, Choice::from(1)), b);
+    assert_eq!(JacobianPoint::conditional_select(&a, &b, Choice::from(0)), a);
+  }
+
+  #[test]
+  #[should_panic(expected = "window width should be between 1 and 16")]
+  fn oversized_window() { fixed_window(JacobianPoint::<PlutoBaseCurve>::GENERATOR, &[5], 17); }
+}
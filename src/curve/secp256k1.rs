@@ -0,0 +1,67 @@
+//! The secp256k1 curve $y^2 = x^3 + 7$ of [SEC 2](https://www.secg.org/sec2-v2.pdf), section
+//! 2.4.1, used for signatures in Bitcoin and Ethereum.
+//!
+//! Its base field is the 256-bit prime field [`Secp256k1BaseField`] of order
+//! $p = 2^{256} - 2^{32} - 977$, and the curve has a prime number $n$ of points, so every point
+//! other than the point at infinity generates the whole group and the cofactor is 1. The order $n$
+//! does not fit in a `usize`, so [`EllipticCurve::ORDER`] cannot be evaluated and the order of the
+//! group is given by the modulus of the [`Secp256k1ScalarField`] instead.
+
+use crypto_bigint::U256;
+
+use super::*;
+use crate::algebra::field::big_prime::{Secp256k1BaseField, Secp256k1ScalarField};
+
+/// The [`Secp256k1`] curve, defined by the equation `y^2 = x^3 + 7` over the
+/// [`Secp256k1BaseField`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Secp256k1;
+
+impl EllipticCurve for Secp256k1 {
+  type BaseField = Secp256k1BaseField;
+  type Coefficient = Secp256k1BaseField;
+  type ScalarField = Secp256k1ScalarField;
+
+  const COFACTOR: &'static [u64] = &[1];
+  const EQUATION_A: Self::Coefficient = Secp256k1BaseField::ZERO;
+  const EQUATION_B: Self::Coefficient = Secp256k1BaseField::new(U256::from_u8(7));
+  const GENERATOR: (Self::BaseField, Self::BaseField) = (
+    Secp256k1BaseField::new(U256::from_be_hex(
+      "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    )),
+    Secp256k1BaseField::new(U256::from_be_hex(
+      "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    )),
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn generator() {
+    let g = AffinePoint::<Secp256k1>::GENERATOR;
+    assert!(g.is_on_curve());
+    assert!(g.is_in_prime_subgroup());
+    assert_eq!(g * -Secp256k1ScalarField::ONE, -g);
+    assert_eq!(g * Secp256k1ScalarField::ZERO, AffinePoint::Infinity);
+  }
+
+  #[test]
+  fn scalar_mul() {
+    // 2G from the list of small multiples of the generator
+    let two_g = AffinePoint::<Secp256k1>::new(
+      Secp256k1BaseField::new(U256::from_be_hex(
+        "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+      )),
+      Secp256k1BaseField::new(U256::from_be_hex(
+        "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+      )),
+    )
+    .unwrap();
+    let g = AffinePoint::<Secp256k1>::GENERATOR;
+    assert_eq!(g.double(), two_g);
+    assert_eq!(g * Secp256k1ScalarField::from(2u64), two_g);
+  }
+}
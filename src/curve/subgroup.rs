@@ -0,0 +1,235 @@
+//! Points of the subgroup of prime order.
+//!
+//! A curve with $h \cdot r$ points, for a large prime $r$, is used through its subgroup of order
+//! $r$: the discrete logarithm is only as hard as the largest prime factor of the order of a
+//! point. A point of small order accepted from someone else leaks the secret scalar it is
+//! multiplied with modulo that order, in ECDH for instance, and a point off the curve can even land
+//! on a weaker curve with the same $a$ coefficient, since the group law never uses $b$.
+//!
+//! [`PrimeOrderPoint`] can only be created from a point that is on the curve and killed by $r$,
+//! the order of the scalar field, so that the protocols taking it don't have to check it again.
+//! The group law keeps points in the subgroup, so the sums and multiples of such points are
+//! [`PrimeOrderPoint`]s too.
+use super::{scalar_mul::montgomery_ladder, *};
+
+/// A point on the curve `C` in the subgroup of prime order, the order of `C::ScalarField`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrimeOrderPoint<C: EllipticCurve>(AffinePoint<C>);
+
+impl<C: EllipticCurve> PrimeOrderPoint<C> {
+  /// Checks that the point is on the curve and in the subgroup of prime order.
+  ///
+  /// ## Errors
+  /// [`PointError::NotOnCurve`] or [`PointError::NotInSubgroup`] if the point fails the check.
+  pub fn new(point: AffinePoint<C>) -> Result<Self, PointError> {
+    if !point.is_on_curve() {
+      return Err(PointError::NotOnCurve);
+    }
+    match point.is_in_prime_subgroup() {
+      true => Ok(Self(point)),
+      false => Err(PointError::NotInSubgroup),
+    }
+  }
+
+  /// Creates a point in the subgroup of prime order from its coordinates, see
+  /// [`PrimeOrderPoint::new`].
+  pub fn from_coordinates(x: C::BaseField, y: C::BaseField) -> Result<Self, PointError> {
+    Self::new(AffinePoint::new(x, y)?)
+  }
+
+  /// Maps any point on the curve into the subgroup of prime order with
+  /// [`AffinePoint::clear_cofactor`].
+  ///
+  /// ## Errors
+  /// [`PointError::NotOnCurve`] if the point is not on the curve, and
+  /// [`PointError::NotInSubgroup`] if the cofactor of `C` is wrong.
+  pub fn clear_cofactor(point: AffinePoint<C>) -> Result<Self, PointError> {
+    match point.is_on_curve() {
+      true => Self::new(point.clear_cofactor()),
+      false => Err(PointError::NotOnCurve),
+    }
+  }
+
+  /// Wraps a point known to be in the subgroup of prime order, like a combination of such points
+  /// computed in other coordinates, without checking it again in release builds.
+  pub(crate) fn new_unchecked(point: AffinePoint<C>) -> Self {
+    debug_assert!(point.is_on_curve() && point.is_in_prime_subgroup());
+    Self(point)
+  }
+
+  /// Returns the underlying [`AffinePoint`].
+  pub const fn point(&self) -> AffinePoint<C> { self.0 }
+
+  /// Multiplies the point by a secret scalar, like a private key or a nonce, with the
+  /// [`montgomery_ladder`] in Jacobian coordinates. Unlike the [`double_and_add`] behind `*`, the
+  /// group operations don't depend on the scalar, and all the limbs of the scalar field are
+  /// processed so that the number of operations doesn't reveal its size either. The timing of the
+  /// field arithmetic still does, so this is not constant-time.
+  pub fn mul_secret(&self, scalar: C::ScalarField) -> Self {
+    let mut limbs = scalar.to_limbs();
+    limbs.resize(C::ScalarField::modulus().len(), 0);
+    Self(montgomery_ladder(JacobianPoint::from(self.0), &limbs).into())
+  }
+}
+
+impl<C: EllipticCurve> TryFrom<AffinePoint<C>> for PrimeOrderPoint<C> {
+  type Error = PointError;
+
+  fn try_from(point: AffinePoint<C>) -> Result<Self, Self::Error> { Self::new(point) }
+}
+
+impl<C: EllipticCurve> From<PrimeOrderPoint<C>> for AffinePoint<C> {
+  fn from(point: PrimeOrderPoint<C>) -> Self { point.0 }
+}
+
+impl<C: EllipticCurve> Hash for PrimeOrderPoint<C> {
+  fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.0.hash(state) }
+}
+
+impl<C: EllipticCurve> Finite for PrimeOrderPoint<C> {
+  const ORDER: usize = C::ORDER;
+}
+
+impl<C: EllipticCurve> Group for PrimeOrderPoint<C> {
+  type Scalar = C::ScalarField;
+
+  const IDENTITY: Self = Self(AffinePoint::Infinity);
+
+  fn op(&self, b: &Self) -> Self { Self(self.0 + b.0) }
+
+  fn inverse(&self) -> Option<Self> { Some(-*self) }
+
+  fn scalar_mul(&self, b: Self::Scalar) -> Self { *self * b }
+}
+
+impl<C: EllipticCurve> FiniteGroup for PrimeOrderPoint<C> {}
+impl<C: EllipticCurve> AbelianGroup for PrimeOrderPoint<C> {}
+
+impl<C: EllipticCurve> FiniteCyclicGroup for PrimeOrderPoint<C> {
+  const GENERATOR: Self = Self(AffinePoint::GENERATOR);
+}
+
+impl<C: EllipticCurve> CurveGroup for PrimeOrderPoint<C> {
+  type BaseField = C::BaseField;
+
+  fn double(self) -> Self { Self(self.0.double()) }
+
+  fn is_on_curve(&self) -> bool { self.0.is_on_curve() }
+
+  fn xy(&self) -> (Self::BaseField, Self::BaseField, bool) { self.0.xy() }
+
+  fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+    Self(AffinePoint::conditional_select(&a.0, &b.0, choice))
+  }
+}
+
+impl<C: EllipticCurve> Default for PrimeOrderPoint<C> {
+  fn default() -> Self { <Self as FiniteCyclicGroup>::GENERATOR }
+}
+
+impl<C: EllipticCurve> Add for PrimeOrderPoint<C> {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self::Output { self.op(&rhs) }
+}
+
+impl<C: EllipticCurve> AddAssign for PrimeOrderPoint<C> {
+  fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+
+impl<C: EllipticCurve> Neg for PrimeOrderPoint<C> {
+  type Output = Self;
+
+  fn neg(self) -> Self::Output { Self(-self.0) }
+}
+
+impl<C: EllipticCurve> Sub for PrimeOrderPoint<C> {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self::Output { self + -rhs }
+}
+
+impl<C: EllipticCurve> SubAssign for PrimeOrderPoint<C> {
+  fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+
+impl<C: EllipticCurve> Mul<C::ScalarField> for PrimeOrderPoint<C> {
+  type Output = Self;
+
+  fn mul(self, rhs: C::ScalarField) -> Self::Output { Self(self.0 * rhs) }
+}
+
+impl<C: EllipticCurve> MulAssign<C::ScalarField> for PrimeOrderPoint<C> {
+  fn mul_assign(&mut self, rhs: C::ScalarField) { *self = *self * rhs; }
+}
+
+impl<C: EllipticCurve> Sum for PrimeOrderPoint<C> {
+  fn sum<I: Iterator<Item = Self>>(iter: I) -> Self { iter.fold(Self::IDENTITY, |x, y| x + y) }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::curve::pluto_curve::{PlutoBaseCurve, PlutoExtendedCurve};
+
+  #[test]
+  fn validation() {
+    let g = AffinePoint::<PlutoBaseCurve>::GENERATOR;
+    assert_eq!(PrimeOrderPoint::new(g).map(|p| p.point()), Ok(g));
+    assert!(PrimeOrderPoint::<PlutoBaseCurve>::new(AffinePoint::Infinity).is_ok());
+
+    // (1, 3) is not on y^2 = x^3 + 3
+    let off_curve =
+      AffinePoint::<PlutoBaseCurve>::Point(PlutoBaseField::ONE, PlutoBaseField::new(3));
+    assert_eq!(PrimeOrderPoint::new(off_curve), Err(PointError::NotOnCurve));
+    assert_eq!(PrimeOrderPoint::clear_cofactor(off_curve), Err(PointError::NotOnCurve));
+    assert_eq!(
+      PrimeOrderPoint::<PlutoBaseCurve>::from_coordinates(
+        PlutoBaseField::ONE,
+        PlutoBaseField::new(3)
+      ),
+      Err(PointError::NotOnCurve)
+    );
+
+    // cubing is a bijection of F_101 as 3 does not divide 100, so x^3 = -3 has a single solution
+    let order_two = (0..PlutoPrime::Base as usize)
+      .map(|x| AffinePoint::<PlutoBaseCurve>::Point(PlutoBaseField::new(x), PlutoBaseField::ZERO))
+      .find(|p| p.is_on_curve())
+      .unwrap();
+    assert!(order_two.is_torsion(&[2]));
+    assert_eq!(PrimeOrderPoint::try_from(order_two), Err(PointError::NotInSubgroup));
+    assert_eq!(PrimeOrderPoint::clear_cofactor(order_two), Ok(PrimeOrderPoint::IDENTITY));
+  }
+
+  #[test]
+  fn clear_cofactor() {
+    let mut cleared = 0;
+    for x in 0..PlutoPrime::Base as usize {
+      for y in 0..PlutoPrime::Base as usize {
+        let Ok(point) =
+          AffinePoint::<PlutoBaseCurve>::new(PlutoBaseField::new(x), PlutoBaseField::new(y))
+        else {
+          continue;
+        };
+        let subgroup_point = PrimeOrderPoint::clear_cofactor(point).unwrap();
+        assert!(subgroup_point.point().is_in_prime_subgroup());
+        assert_eq!(point.is_in_prime_subgroup(), PrimeOrderPoint::new(point).is_ok());
+        cleared += 1;
+      }
+    }
+    // the 101 points of the curve other than the point at infinity
+    assert_eq!(cleared, 101);
+  }
+
+  #[test]
+  fn group_law() {
+    let g = PrimeOrderPoint::<PlutoExtendedCurve>::GENERATOR;
+    let three = PlutoScalarField::new(3);
+    assert_eq!((g * three).point(), AffinePoint::GENERATOR * three);
+    assert_eq!(g + g + g, g * three);
+    assert_eq!(g - g, PrimeOrderPoint::IDENTITY);
+    assert!((g * three).point().is_in_prime_subgroup());
+    assert_eq!([g, g, g].into_iter().sum::<PrimeOrderPoint<_>>(), g * three);
+    assert_eq!(AffinePoint::from(g.double()), AffinePoint::GENERATOR.double());
+  }
+}
@@ -1,5 +1,3 @@
-use std::array;
-
 use super::*;
 use crate::curve::pairing::{line_function, miller_loop, pairing, tangent_line, vertical_line};
 
@@ -27,6 +25,7 @@ impl EllipticCurve for TestCurve {
   // TODO: incorrect
   type ScalarField = TestField;
 
+  const COFACTOR: &'static [u64] = &[12];
   const EQUATION_A: Self::Coefficient = TestField::ONE;
   const EQUATION_B: Self::Coefficient = TestField::ZERO;
   // In this case, this isn't really the generator for the curve, but rather the generator for the
@@ -44,6 +43,8 @@ impl EllipticCurve for TestCurveExtended {
   // TODO: incorrect
   type ScalarField = TestField;
 
+  // 60^2 points over F_59^2
+  const COFACTOR: &'static [u64] = &[720];
   const EQUATION_A: Self::Coefficient = TestField::ONE;
   const EQUATION_B: Self::Coefficient = TestField::ZERO;
   const GENERATOR: (Self::BaseField, Self::BaseField) =
@@ -82,6 +83,7 @@ fn five_torsion() {
         TestExtension::new([TestField::from(0usize), TestField::from(1usize)])
           * TestExtension::from(y),
       )
+      .unwrap()
     } else {
       panic!("Generator is not a point");
     };
@@ -116,7 +118,7 @@ fn vertical_line_2p() {
   println!("2P: {:?}", two_p);
   // We should get:
   // 2P = Point(PrimeField { value: 35 }, PrimeField { value: 31 })
-  assert_eq!(two_p, AffinePoint::new(TestField::new(35), TestField::new(31)));
+  assert_eq!(two_p, AffinePoint::new(TestField::new(35), TestField::new(31)).unwrap());
 
   let v_2p = |x| vertical_line::<TestCurve>(two_p, x);
 
@@ -133,7 +135,7 @@ fn tangent_line_p() {
   println!("P: {:?}", p);
   // We should get:
   // P = Point(PrimeField { value: 25 }, PrimeField { value: 30 })
-  assert_eq!(p, AffinePoint::new(TestField::new(25), TestField::new(30)));
+  assert_eq!(p, AffinePoint::new(TestField::new(25), TestField::new(30)).unwrap());
 
   let t_p = |x| tangent_line::<TestCurve>(p, x);
 
@@ -156,8 +158,8 @@ fn line_from_p_to_2p() {
   // We should get:
   // P = Point(PrimeField { value: 25 }, PrimeField { value: 30 })
   // 2P = Point(PrimeField { value: 35 }, PrimeField { value: 31 })
-  assert_eq!(p, AffinePoint::new(TestField::new(25), TestField::new(30)));
-  assert_eq!(two_p, AffinePoint::new(TestField::new(35), TestField::new(31)));
+  assert_eq!(p, AffinePoint::new(TestField::new(25), TestField::new(30)).unwrap());
+  assert_eq!(two_p, AffinePoint::new(TestField::new(35), TestField::new(31)).unwrap());
 
   let l_p_2p = |x| line_function::<TestCurve>(p, two_p, x);
 
@@ -175,13 +177,15 @@ fn line_from_p_to_2p() {
 fn miller_loop_check() {
   let (p, q) = if let AffinePoint::<TestCurve>::Point(x, y) = AffinePoint::<TestCurve>::GENERATOR {
     (
-      AffinePoint::<TestCurveExtended>::new(TestExtension::from(x), TestExtension::from(y)),
+      AffinePoint::<TestCurveExtended>::new(TestExtension::from(x), TestExtension::from(y))
+        .unwrap(),
       // Apply the distortion map
       AffinePoint::<TestCurveExtended>::new(
         -TestExtension::from(x),
         TestExtension::new([TestField::from(0usize), TestField::from(1usize)])
           * TestExtension::from(y),
-      ),
+      )
+      .unwrap(),
     )
   } else {
     panic!("Generator is not a point");
@@ -207,13 +211,15 @@ fn miller_loop_check() {
 fn pairing_check() {
   let (p, q) = if let AffinePoint::<TestCurve>::Point(x, y) = AffinePoint::<TestCurve>::GENERATOR {
     (
-      AffinePoint::<TestCurveExtended>::new(TestExtension::from(x), TestExtension::from(y)),
+      AffinePoint::<TestCurveExtended>::new(TestExtension::from(x), TestExtension::from(y))
+        .unwrap(),
       // Apply the distortion map
       AffinePoint::<TestCurveExtended>::new(
         -TestExtension::from(x),
         TestExtension::new([TestField::from(0usize), TestField::from(1usize)])
           * TestExtension::from(y),
-      ),
+      )
+      .unwrap(),
     )
   } else {
     panic!("Generator is not a point");
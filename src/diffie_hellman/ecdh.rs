@@ -1,26 +1,40 @@
 //! Elliptic Curve Diffie Hellman Key Exchange Algorithm
-use crate::{algebra::field::FiniteField, curve::CurveGroup};
+use crate::curve::{subgroup::PrimeOrderPoint, EllipticCurve};
 
 /// Compute a shared secret from a local secret `d_a` and a foreign elliptic curve point `q_b`.
 ///
 /// ## Arguments
 ///
 /// * `d_a` - The local secret.
-/// * `q_b` - The foreign point on the curve.
+/// * `q_b` - The foreign point on the curve, which must have been checked to be in the subgroup of
+///   prime order with [`PrimeOrderPoint::new`], as a point of small order would leak `d_a` modulo
+///   its order.
 ///
 /// ## Returns
 ///
-/// The computed shared secret.
-pub fn compute_shared_secret<F: FiniteField, G: CurveGroup<Scalar = F>>(d_a: F, q_b: G) -> G {
-  q_b * d_a
+/// The computed shared secret, multiplied with [`PrimeOrderPoint::mul_secret`] so that the group
+/// operations don't depend on `d_a`.
+pub fn compute_shared_secret<C: EllipticCurve>(
+  d_a: C::ScalarField,
+  q_b: PrimeOrderPoint<C>,
+) -> PrimeOrderPoint<C> {
+  q_b.mul_secret(d_a)
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
   use crate::{
-    algebra::{field::prime::PlutoScalarField, group::FiniteCyclicGroup, Finite},
-    curve::{pluto_curve::PlutoBaseCurve, AffinePoint},
+    algebra::{
+      field::{big_prime::Secp256k1ScalarField, prime::PlutoScalarField, FieldEncoding},
+      group::FiniteCyclicGroup,
+      Finite,
+    },
+    curve::{
+      p256::P256, pluto_curve::PlutoBaseCurve, secp256k1::Secp256k1, AffinePoint, CurveGroup,
+      PointError,
+    },
+    Field, PlutoBaseField,
   };
 
   #[test]
@@ -30,12 +44,76 @@ mod tests {
     let d_a = PlutoScalarField::new(rand::Rng::gen_range(&mut rng, 1..=PlutoScalarField::ORDER));
     let d_b = PlutoScalarField::new(rand::Rng::gen_range(&mut rng, 1..=PlutoScalarField::ORDER));
 
-    let q_a = AffinePoint::<PlutoBaseCurve>::GENERATOR * d_a;
-    let q_b = AffinePoint::<PlutoBaseCurve>::GENERATOR * d_b;
+    let q_a = PrimeOrderPoint::<PlutoBaseCurve>::GENERATOR * d_a;
+    let q_b = PrimeOrderPoint::<PlutoBaseCurve>::GENERATOR * d_b;
 
     let shared_secret_a = compute_shared_secret(d_a, q_b);
     let shared_secret_b = compute_shared_secret(d_b, q_a);
 
     assert_eq!(shared_secret_a, shared_secret_b);
   }
+
+  #[test]
+  fn rejects_small_order_points() {
+    // (x, 0) has order two, so it would reveal the parity of the secret
+    let point = (0..101)
+      .map(|x| AffinePoint::<PlutoBaseCurve>::Point(PlutoBaseField::new(x), PlutoBaseField::ZERO))
+      .find(|point| point.is_on_curve())
+      .unwrap();
+    assert_eq!(PrimeOrderPoint::new(point), Err(PointError::NotInSubgroup));
+  }
+
+  /// Parses a point of `C` from its big-endian hex coordinates.
+  fn point<C: EllipticCurve<BaseField: FieldEncoding>>(x: &str, y: &str) -> PrimeOrderPoint<C> {
+    let coordinate = |c: &str| C::BaseField::from_be_bytes(&hex::decode(c).unwrap()).unwrap();
+    PrimeOrderPoint::from_coordinates(coordinate(x), coordinate(y)).unwrap()
+  }
+
+  /// Parses a scalar of `C` from big-endian hex.
+  fn scalar<C: EllipticCurve<ScalarField: FieldEncoding>>(s: &str) -> C::ScalarField {
+    C::ScalarField::from_be_bytes(&hex::decode(s).unwrap()).unwrap()
+  }
+
+  #[test]
+  fn p256_shared_secret() {
+    // the first P-256 vector of the NIST CAVS ECC CDH primitive tests
+    let q_cavs = point::<P256>(
+      "700c48f77f56584c5cc632ca65640db91b6bacce3a4df6b42ce7cc838833d287",
+      "db71e509e3fd9b060ddb20ba5c51dcc5948d46fbf640dfe0441782cab85fa4ac",
+    );
+    let d_iut = scalar::<P256>("7d7dc5f71eb29ddaf80d6214632eeae03d9058af1fb6d22ed80badb62bc1a534");
+    let q_iut = point::<P256>(
+      "ead218590119e8876b29146ff89ca61770c4edbbf97d38ce385ed281d8a6b230",
+      "28af61281fd35e2fa7002523acc85a429cb06ee6648325389f59edfce1405141",
+    );
+    assert_eq!(PrimeOrderPoint::GENERATOR.mul_secret(d_iut), q_iut);
+    let (z, ..) = compute_shared_secret(d_iut, q_cavs).xy();
+    assert_eq!(
+      z.to_be_bytes(),
+      hex::decode("46fc62106420ff012e54a434fbdd2d25ccc5852060561e68040dd7778997bd7b").unwrap()
+    );
+  }
+
+  #[test]
+  fn secp256k1_shared_secret() {
+    // 2 * 3G = 3 * 2G = 6G, from the list of small multiples of the generator
+    let two_g = point::<Secp256k1>(
+      "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+      "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+    );
+    let three_g = PrimeOrderPoint::<Secp256k1>::GENERATOR * Secp256k1ScalarField::from(3);
+    let shared_secret = compute_shared_secret(Secp256k1ScalarField::from(2), three_g);
+    assert_eq!(shared_secret, compute_shared_secret(Secp256k1ScalarField::from(3), two_g));
+    let (x, ..) = shared_secret.xy();
+    assert_eq!(
+      x.to_be_bytes(),
+      hex::decode("fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a1460297556").unwrap()
+    );
+
+    let (d_a, d_b) = rand::Rng::gen::<(_, _)>(&mut rand::rngs::OsRng);
+    let q_a = PrimeOrderPoint::<Secp256k1>::GENERATOR.mul_secret(d_a);
+    let q_b = PrimeOrderPoint::<Secp256k1>::GENERATOR.mul_secret(d_b);
+    assert_eq!(q_a, PrimeOrderPoint::GENERATOR * d_a);
+    assert_eq!(compute_shared_secret(d_a, q_b), compute_shared_secret(d_b, q_a));
+  }
 }
@@ -7,6 +7,7 @@ use crate::{
   curve::{
     pairing::pairing,
     pluto_curve::{PlutoBaseCurve, PlutoExtendedCurve},
+    subgroup::PrimeOrderPoint,
     AffinePoint, EllipticCurve,
   },
 };
@@ -26,10 +27,10 @@ use crate::{
 /// A tuple containing the computed points `(p_a, q_a)`.
 pub fn compute_local_pair(
   d_a: PlutoScalarField,
-) -> (AffinePoint<PlutoBaseCurve>, AffinePoint<PlutoExtendedCurve>) {
-  let p_a = AffinePoint::<PlutoBaseCurve>::GENERATOR * d_a;
+) -> (PrimeOrderPoint<PlutoBaseCurve>, PrimeOrderPoint<PlutoExtendedCurve>) {
+  let p_a = PrimeOrderPoint::<PlutoBaseCurve>::GENERATOR * d_a;
 
-  let q_a = AffinePoint::<PlutoExtendedCurve>::GENERATOR * d_a;
+  let q_a = PrimeOrderPoint::<PlutoExtendedCurve>::GENERATOR * d_a;
 
   (p_a, q_a)
 }
@@ -44,8 +45,8 @@ pub fn compute_local_pair(
 /// ## Arguments
 ///
 /// * `d_a` - The local secret.
-/// * `p_b` - The foreign point on the base curve.
-/// * `q_c` - The foreign point on the extended curve.
+/// * `p_b` - The foreign point on the base curve, in the subgroup of prime order.
+/// * `q_c` - The foreign point on the extended curve, in the subgroup of prime order.
 ///
 /// ## Returns
 ///
@@ -62,12 +63,12 @@ pub fn compute_local_pair(
 /// and `(q_c, p_c)`, this function may be called with either `(p_b, q_c)` or `(p_c, q_b)`.
 pub fn compute_shared_secret(
   d_a: <PlutoBaseCurve as EllipticCurve>::ScalarField,
-  p_b: AffinePoint<PlutoBaseCurve>,
-  q_c: AffinePoint<PlutoExtendedCurve>,
+  p_b: PrimeOrderPoint<PlutoBaseCurve>,
+  q_c: PrimeOrderPoint<PlutoExtendedCurve>,
 ) -> PlutoBaseFieldExtension {
-  let p_b = AffinePoint::<PlutoExtendedCurve>::from(p_b);
+  let p_b = AffinePoint::<PlutoExtendedCurve>::from(p_b.point());
 
-  let pairing = pairing::<_, { PlutoBaseCurve::ORDER }>(p_b, q_c);
+  let pairing = pairing::<_, { PlutoBaseCurve::ORDER }>(p_b, q_c.point());
 
   let shared_secret = pairing.pow(d_a.value);
 
@@ -79,7 +80,7 @@ mod tests {
   use super::*;
   use crate::{
     algebra::{field::prime::PlutoScalarField, group::FiniteCyclicGroup, Finite},
-    curve::{pluto_curve::PlutoBaseCurve, AffinePoint},
+    curve::pluto_curve::PlutoBaseCurve,
   };
 
   #[test]
@@ -90,16 +91,16 @@ mod tests {
 
     let (p_a, q_a) = compute_local_pair(d_a);
 
-    assert_eq!(p_a, AffinePoint::<PlutoBaseCurve>::GENERATOR * d_a);
-    assert_eq!(q_a, AffinePoint::<PlutoExtendedCurve>::GENERATOR * d_a);
+    assert_eq!(p_a.point(), AffinePoint::<PlutoBaseCurve>::GENERATOR * d_a);
+    assert_eq!(q_a.point(), AffinePoint::<PlutoExtendedCurve>::GENERATOR * d_a);
   }
 
   #[test]
   fn test_compute_tripartite_shared_secret() {
     let mut rng = rand::rngs::OsRng;
 
-    let p = AffinePoint::<PlutoBaseCurve>::GENERATOR;
-    let q = AffinePoint::<PlutoExtendedCurve>::GENERATOR;
+    let p = PrimeOrderPoint::<PlutoBaseCurve>::GENERATOR;
+    let q = PrimeOrderPoint::<PlutoExtendedCurve>::GENERATOR;
 
     let d_a = PlutoScalarField::new(rand::Rng::gen_range(&mut rng, 1..PlutoScalarField::ORDER));
     let d_b = PlutoScalarField::new(rand::Rng::gen_range(&mut rng, 1..PlutoScalarField::ORDER));
@@ -0,0 +1,148 @@
+//! ECDSA signature verification
+//!
+//! Messages are hashed with SHA-256, so signatures over [`Secp256k1`] and [`P256`] are the standard
+//! `ECDSA-SHA256` ones of those curves. They are checked against the deterministic test vectors of
+//! [RFC 6979](https://www.rfc-editor.org/rfc/rfc6979#appendix-A.2.5) for P-256, and the RFC 6979
+//! vectors commonly used for secp256k1.
+//!
+//! [`Secp256k1`]: crate::curve::secp256k1::Secp256k1
+//! [`P256`]: crate::curve::p256::P256
+use rand::Rng;
+
+use crate::{
+  algebra::{
+    field::LimbRepr,
+    group::{FiniteCyclicGroup, Group},
+  },
+  curve::{
+    scalar_mul::{bits, window},
+    subgroup::PrimeOrderPoint,
+    CurveGroup, EllipticCurve,
+  },
+  hashes::sha::Sha256,
+};
+
+#[cfg(test)] mod tests;
+
+// PARAMETERS
+// *******************************************
+// CURVE	the elliptic curve field and equation used
+// G	    a point on the curve that generates a subgroup of large prime order n
+// n	    integer order of G, means that n × G = O, n must also be prime.
+// d_A	    the private key (randomly selected) (scaler in F_n)
+// Q_A	    the public key d_a × G = Q_A (point on the curve)
+// m	    the message to send
+
+/// SIGNING ALGORITHM
+/// *******************************************
+/// 1. Compute e = HASH(m), where HASH is a cryptographic hash function.
+/// 2. Let z be the L_n leftmost bits of e, where L_n is the bit length of the group order n.
+/// 3. Select a cryptographically secure random integer k from [1, n-1].
+/// 4. Compute the curve point (x_1, y_1) = k × G.
+/// 5. Compute r = x_1 mod n. If r = 0, go back to step 3.
+/// 6. Compute s = k^(-1) (z + r * d_A) mod n. If s = 0, go back to step 3.
+/// 7. The signature is the pair (r, s). the pair (r, -s mod n) is also a valid signature.
+pub fn sign<F: LimbRepr, C: EllipticCurve<ScalarField = F, BaseField: LimbRepr>>(
+  message: &[u8],
+  private_key: F,
+) -> (F, F) {
+  let mut rng = rand::rngs::OsRng;
+  loop {
+    // Select a cryptographically secure random integer k from [1, n-1], with an extra limb so that
+    // the bias of the reduction modulo n is negligible.
+    let limbs: Vec<u64> = (0..=F::modulus().len()).map(|_| rng.gen()).collect();
+    let k = F::from_limbs(&limbs);
+    if let Some(signature) = sign_with_nonce::<F, C>(message, private_key, k) {
+      return signature;
+    }
+  }
+}
+
+/// Signs the message with the nonce `k`, following steps 1, 2 and 4 to 7 of [`sign`]. Returns
+/// `None` if `k`, `r` or `s` is zero, in which case another nonce has to be picked.
+///
+/// The nonce must be secret and never reused: two signatures with the same `k` reveal the private
+/// key. It is given explicitly to reproduce test vectors, or to derive `k` from the private key
+/// and the message as in RFC 6979.
+pub fn sign_with_nonce<F: LimbRepr, C: EllipticCurve<ScalarField = F, BaseField: LimbRepr>>(
+  message: &[u8],
+  private_key: F,
+  k: F,
+) -> Option<(F, F)> {
+  let z = hash_message::<F>(message);
+
+  // Compute the curve point (x_1, y_1) = k × G, with the ladder as k is secret.
+  let (x_1, _, is_infty) = PrimeOrderPoint::<C>::GENERATOR.mul_secret(k).xy();
+  if is_infty {
+    return None;
+  }
+  // Compute r = x_1 mod n. If r = 0, go back to step 3.
+  let r = F::from_limbs(&x_1.to_limbs());
+  if r == F::ZERO {
+    return None;
+  }
+  // Compute s = k^(-1) (z + r * d_A) mod n. If s = 0, go back to step 3.
+  let s = k.inverse()? * (z + r * private_key);
+  //  The signature is the pair (Notable not nessisarily a point on the curve) (r, s). the pair
+  //    (r, -s mod n) is also a valid signature.
+  (s != F::ZERO).then_some((r, s))
+}
+
+/// SIGNATURE VERIFICATION ALGORITHM
+/// *******************************************
+/// Check that public key Q_A is a valid point on the curve.
+/// 1. Check that Q_A != O.
+/// 2. Check that x_Q_A and y_Q_A are integers in the interval [0, p-1].
+/// 3. Check that n × Q_A = O.
+///
+/// Steps 2 and 3 are done once when the key is received, by [`PrimeOrderPoint::new`].
+///
+/// Verify that the signature is valid.
+/// 1. Verify that r and s are integers in the interval [1, n-1].
+/// 2. Compute e = HASH(m).
+/// 3. Let z be the L_n leftmost bits of e.
+/// 4. Compute u_1 = zs^(-1) mod n.
+/// 5. Compute u_2 = rs^(-1) mod n.
+/// 6. Compute the curve point (x_1, y_1) = u_1 × G + u_2 × Q_A. If = O, the signature is invalid.
+/// 7. The signature is valid if r = x_1 mod n, invalid otherwise.
+pub fn verify<F: LimbRepr, C: EllipticCurve<ScalarField = F, BaseField: LimbRepr>>(
+  m: &[u8],
+  q_a: PrimeOrderPoint<C>,
+  signature: (F, F),
+) -> bool {
+  // Check that Q_A != O.
+  if q_a == PrimeOrderPoint::IDENTITY {
+    return false;
+  }
+
+  // Verify that the signature is valid.
+  let (r, s): (F, F) = signature;
+  // Verify that r and s are integers in the interval [1, n-1].
+  if r == F::ZERO || s == F::ZERO {
+    return false;
+  }
+  let z = hash_message::<F>(m);
+  // Compute u_1 = zs^(-1) mod n.
+  let s_inv = s.inverse().unwrap();
+  let u_1 = z * s_inv;
+  // Compute u_2 = rs^(-1) mod n.
+  let u_2 = r * s_inv;
+  // Compute the curve point (x_1, y_1) = u_1 × G + u_2 × Q_A. If = O, the signature is invalid.
+  let point = (PrimeOrderPoint::<C>::GENERATOR * u_1) + (q_a * u_2);
+  let (x_1, _, is_infty) = point.xy();
+  if is_infty {
+    return false;
+  }
+  r == F::from_limbs(&x_1.to_limbs())
+}
+
+/// Computes `e = SHA-256(m)` and keeps its `L_n` leftmost bits as the integer `z`, reduced modulo
+/// the group order `n` of bit length `L_n`.
+fn hash_message<F: LimbRepr>(m: &[u8]) -> F {
+  let e = Sha256::new().digest(m);
+  let limbs: Vec<u64> =
+    e.rchunks(8).map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap())).collect();
+  let shift = (8 * e.len()).saturating_sub(bits(&F::modulus()));
+  let z: Vec<u64> = (0..limbs.len()).map(|i| window(&limbs, shift + 64 * i, 64) as u64).collect();
+  F::from_limbs(&z)
+}
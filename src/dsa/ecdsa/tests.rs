@@ -0,0 +1,108 @@
+use super::*;
+use crate::{
+  algebra::{
+    field::{
+      big_prime::P256ScalarField, prime::PlutoScalarField, Field, FieldEncoding, SquareRoot,
+    },
+    Finite,
+  },
+  curve::{p256::P256, pluto_curve::PlutoBaseCurve, secp256k1::Secp256k1, AffinePoint},
+};
+
+#[test]
+fn test_sign_verify() {
+  // secret key
+  let mut rng = rand::rngs::OsRng;
+  let s_key = PlutoScalarField::new(rand::Rng::gen_range(&mut rng, 1..=PlutoScalarField::ORDER));
+
+  // public key
+  let q_a = PrimeOrderPoint::<PlutoBaseCurve>::GENERATOR * s_key;
+  let m = b"Hello, world!";
+  // sign the message
+  let signature = sign::<PlutoScalarField, PlutoBaseCurve>(m, s_key);
+  println!("signature = {:?}", signature);
+  assert!(verify(m, q_a, signature));
+}
+
+#[test]
+fn test_invalid_signature() {
+  // secret key
+  let mut rng = rand::rngs::OsRng;
+  let s_key = PlutoScalarField::new(rand::Rng::gen_range(&mut rng, 1..=PlutoScalarField::ORDER));
+  // public key
+  let q_a = PrimeOrderPoint::<PlutoBaseCurve>::GENERATOR * s_key;
+  let m = b"Hello, Pluto!";
+  // sign the message
+  let mut signature = sign::<PlutoScalarField, PlutoBaseCurve>(m, s_key);
+  // Modify the signature to make it invalid
+  signature.0 = PlutoScalarField::ZERO; // Invalidate r
+  assert!(!verify(m, q_a, signature), "Signature should be invalid but was verified as valid.");
+}
+
+#[test]
+fn test_identity_public_key() {
+  let m = b"Hello, Pluto!";
+  let signature = sign::<PlutoScalarField, PlutoBaseCurve>(m, PlutoScalarField::ONE);
+  assert!(!verify(m, PrimeOrderPoint::<PlutoBaseCurve>::IDENTITY, signature));
+}
+
+/// Checks one line of `test.input`: `private key:public key:message:k:r || s`, with the public key
+/// as an uncompressed SEC1 point and everything else in hex.
+fn check_vector<F, C>(v: &[&str])
+where
+  F: LimbRepr + FieldEncoding,
+  C: EllipticCurve<ScalarField = F, BaseField: LimbRepr + FieldEncoding + SquareRoot>, {
+  let scalar = |s: &str| F::from_be_bytes(&hex::decode(s).unwrap()).unwrap();
+  let private_key = scalar(v[0]);
+  let public_key = AffinePoint::<C>::from_bytes(&hex::decode(v[1]).unwrap()).unwrap();
+  let public_key = PrimeOrderPoint::new(public_key).unwrap();
+  let message = hex::decode(v[2]).unwrap();
+  let k = scalar(v[3]);
+  let (r, s) = (scalar(&v[4][..64]), scalar(&v[4][64..]));
+
+  assert_eq!(PrimeOrderPoint::<C>::GENERATOR * private_key, public_key);
+
+  // the secp256k1 vectors are normalized to the lower of s and n - s
+  let (r_1, s_1) = sign_with_nonce::<F, C>(&message, private_key, k).unwrap();
+  assert_eq!(r_1, r);
+  assert!(s_1 == s || s_1 == -s);
+
+  assert!(verify(&message, public_key, (r, s)));
+  assert!(verify(&message, public_key, (r, -s)));
+  assert!(!verify(&message, public_key, (r, s + F::ONE)));
+  assert!(!verify(&message, public_key, (s, r)));
+  assert!(!verify(b"another message", public_key, (r, s)));
+  assert!(!verify(&message, -public_key, (r, s)));
+}
+
+/// Test against the P-256 vectors of [RFC 6979](https://www.rfc-editor.org/rfc/rfc6979#appendix-A.2.5)
+/// with SHA-256, and secp256k1 vectors with deterministic nonces from the same RFC.
+#[test]
+fn test_vectors() {
+  let file = include_str!("./test.input");
+  for line in file.lines() {
+    let v: Vec<_> = line.split(':').collect();
+    match v[0] {
+      "p256" => check_vector::<_, P256>(&v[1..]),
+      "secp256k1" => check_vector::<_, Secp256k1>(&v[1..]),
+      curve => panic!("unknown curve {curve}"),
+    }
+  }
+}
+
+#[test]
+fn test_sign_verify_secp256k1() {
+  let s_key = rand::Rng::gen(&mut rand::rngs::OsRng);
+  let q_a = PrimeOrderPoint::<Secp256k1>::GENERATOR * s_key;
+  let m = b"Hello, world!";
+  let signature = sign::<_, Secp256k1>(m, s_key);
+  assert!(verify(m, q_a, signature));
+}
+
+#[test]
+fn test_zero_nonce() {
+  assert_eq!(
+    sign_with_nonce::<_, P256>(b"sample", P256ScalarField::ONE, P256ScalarField::ZERO),
+    None
+  );
+}
@@ -4,17 +4,32 @@
 //!     1. [RFC8032] "Edwards-Curve Digital Signature Algorithm (EdDSA)"
 //!     2. [EdwardsRevisited] "Twisted Edwards Curves Revisited", Hisil, H., Wong, K., Carter, G.,
 //!       and E. Dawson
+//!     3. [RFC9380] "Hashing to Elliptic Curves"
 use std::ops::{Add, AddAssign, Mul};
 
 use crypto_bigint::{
   impl_modulus,
   modular::{ConstMontyForm, ConstMontyParams},
+  subtle::{Choice, ConditionallySelectable},
   Encoding, U256, U512,
 };
 
+use crate::{
+  algebra::field::{
+    big_prime::{BigPrimeField, BigPrimeParams},
+    SquareRoot,
+  },
+  curve::hash_to_curve::hash_to_field,
+  hashes::sha::Sha512,
+};
+
 // `P`: Prime number defining the base field
 impl_modulus!(P, U256, "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed");
 
+impl BigPrimeParams<{ U256::LIMBS }> for P {
+  const PRIMITIVE_ELEMENT: U256 = U256::from_u8(2);
+}
+
 /// `P` minus 2. Used for calculation of the inverse.
 pub const P_2: U256 =
   U256::from_be_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeb");
@@ -53,6 +68,15 @@ const D: BaseField = BaseField::new(&U256::from_be_hex(
   "52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3",
 ));
 
+/// The coefficient `J` of Curve25519, the Montgomery curve `t^2 = s^3 + J s^2 + s` birationally
+/// equivalent to Ed25519.
+const J: BaseField = BaseField::new(&U256::from_u32(486662));
+
+/// The even square root of `-(J + 2)`, which scales the map from Curve25519 to Ed25519.
+const SQRT_MINUS_J_MINUS_TWO: BaseField = BaseField::new(&U256::from_be_hex(
+  "0f26edf460a006bbd27b08dc03fc4f7ec5a1d3d14b7d1a82cc6e04aaff457e06",
+));
+
 /// Constant representing zero in the `ScalarField` type.
 pub const SF_ZERO: ScalarField = ScalarField::new(&U256::ZERO);
 /// Constant representing zero in the `ScalarField64` type.
@@ -91,27 +115,15 @@ fn get_sign_bit(x: &BaseField) -> u8 { x.retrieve().to_le_bytes()[0] & 1 }
 fn inv(x: BaseField) -> BaseField { x.pow(&P_2) }
 
 /// Find the square root of an element of the `BaseField`
-/// It uses the algorithm given in Section 5.1.1 of [RFC8032] utilizing the special case of
-/// `P = 5 (mod 8)`. To read more, see: (https://en.wikipedia.org/wiki/Quadratic_residue#Prime_or_prime_power_modulus)
+/// As `P = 5 (mod 8)` this is the algorithm given in Section 5.1.1 of [RFC8032], see
+/// [`atkin`][crate::algebra::field::sqrt::atkin] and [`SquareRoot`] for the general case.
 pub fn sqrt(x: &BaseField) -> Option<BaseField> {
-  const TWO: U256 = U256::from_u8(2u8);
-  const THREE: U256 = U256::from_u8(3u8);
-
-  let mut exp = (P::MODULUS.get() + THREE).shr(3);
-  let y1 = x.pow(&exp);
-
-  if y1 * y1 == *x {
-    return Some(y1);
-  }
+  BigPrimeField::<P, { U256::LIMBS }> { value: *x }.square_root().map(|root| root.value)
+}
 
-  exp = (P::MODULUS.get() - U256::ONE).shr(2);
-  let z = BaseField::new(&TWO).pow(&exp);
-  let y2 = y1 * z;
-  if y2 * y2 == *x {
-    Some(y2)
-  } else {
-    None
-  }
+/// Returns true if the element of the `BaseField` is a square.
+fn is_square(x: &BaseField) -> bool {
+  BigPrimeField::<P, { U256::LIMBS }> { value: *x }.is_square()
 }
 
 impl Coordinate {
@@ -200,6 +212,46 @@ impl Coordinate {
   }
 }
 
+impl Coordinate {
+  /// Maps a field element to the curve with the Elligator 2 map onto Curve25519 (section 6.7.1 of
+  /// [RFC9380]), followed by the rational map to Ed25519 of its appendix D.1.
+  ///
+  /// With the non-square `Z = 2`, one of $s_1 = -J / (1 + 2u^2)$ and $s_2 = -s_1 - J$ makes
+  /// $s^3 + J s^2 + s$ a square and is the `s` coordinate of a point on Curve25519, whose `t`
+  /// coordinate is odd for $s_1$ and even for $s_2$. The point is then sent to
+  /// $(\sqrt{-(J + 2)} \, s / t, (s - 1) / (s + 1))$, or to the identity if $t = 0$ or $s = -1$.
+  ///
+  /// The image is not necessarily in the subgroup of prime order, see
+  /// [`Coordinate::hash_to_curve`].
+  pub fn map_to_curve(u: BaseField) -> Self {
+    let g = |s: BaseField| (s + J) * s.square() + s;
+    let denominator = BF_ONE + BF_TWO * u.square();
+    let s1 = match denominator == BF_ZERO {
+      true => -J,
+      false => -J * inv(denominator),
+    };
+    let (s, t, odd) = match is_square(&g(s1)) {
+      true => (s1, sqrt(&g(s1)).unwrap(), 1),
+      false => (-s1 - J, sqrt(&g(-s1 - J)).unwrap(), 0),
+    };
+    let t = if get_sign_bit(&t) == odd { t } else { -t };
+
+    if t == BF_ZERO || s + BF_ONE == BF_ZERO {
+      return IDENTITY;
+    }
+    Self::new(SQRT_MINUS_J_MINUS_TWO * s * inv(t), (s - BF_ONE) * inv(s + BF_ONE))
+  }
+
+  /// Hashes a message to the subgroup of prime order with the `edwards25519_XMD:SHA-512_ELL2_RO_`
+  /// suite of [RFC9380]: two field elements are hashed with SHA-512 and mapped with
+  /// [`Coordinate::map_to_curve`], and the sum is multiplied by the cofactor 8.
+  pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self {
+    let u = hash_to_field::<BigPrimeField<P, { U256::LIMBS }>, Sha512>(msg, dst, 2);
+    let sum = Self::map_to_curve(u[0].value) + Self::map_to_curve(u[1].value);
+    sum.double().double().double()
+  }
+}
+
 impl PartialEq for Coordinate {
   /// Checks for equality of two `Coordinate` points on the Ed25519 curve.
   fn eq(&self, other: &Self) -> bool {
@@ -245,26 +297,38 @@ impl AddAssign for Coordinate {
   fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
 }
 
+impl ConditionallySelectable for Coordinate {
+  /// Selects `a` or `b` in constant time, coordinate by coordinate.
+  fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+    Self {
+      x: BaseField::conditional_select(&a.x, &b.x, choice),
+      y: BaseField::conditional_select(&a.y, &b.y, choice),
+      t: BaseField::conditional_select(&a.t, &b.t, choice),
+      z: BaseField::conditional_select(&a.z, &b.z, choice),
+    }
+  }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
 impl Mul<ScalarField> for Coordinate {
   type Output = Self;
 
   /// Performs scalar multiplication on a `Coordinate` point.
   ///
-  /// This implementation uses the double-and-add algorithm for efficient scalar multiplication.
+  /// This implementation uses the Montgomery ladder, see
+  /// [`montgomery_ladder`][crate::curve::scalar_mul::montgomery_ladder]: every one of the 256 bits
+  /// of the secret scalar costs one addition and one doubling, and the accumulators are exchanged
+  /// with constant-time conditional swaps instead of branching on the bit.
   fn mul(self, rhs: ScalarField) -> Self::Output {
-    if rhs == SF_ZERO {
-      return IDENTITY;
-    }
-    let mut t = rhs.retrieve();
-    let mut acc = IDENTITY;
-    let mut factor = self;
-    while t != U256::ZERO {
-      if (t & U256::ONE) == U256::ONE {
-        acc += factor;
-      }
-      factor = factor.double();
-      t = t.shr(1);
+    let scalar = rhs.retrieve();
+    let (mut r0, mut r1) = (IDENTITY, self);
+    for i in (0..U256::BITS).rev() {
+      let choice = scalar.bit(i).into();
+      Self::conditional_swap(&mut r0, &mut r1, choice);
+      r1 = r0 + r1;
+      r0 = r0.double();
+      Self::conditional_swap(&mut r0, &mut r1, choice);
     }
-    acc
+    r0
   }
 }
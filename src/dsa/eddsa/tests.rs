@@ -179,3 +179,67 @@ bench_verify![
     bench_verify_10000, 10000
     bench_verify_100000, 100000
 ];
+
+#[test]
+fn base_field_sqrt() {
+  use crypto_bigint::U256;
+
+  use super::curve::{sqrt, BaseField, BF_TWO};
+
+  let mut rng = rand::thread_rng();
+  for _ in 0..10 {
+    let x = BaseField::new(&U256::from_words(rng.gen()));
+    let root = sqrt(&(x * x)).unwrap();
+    assert!(root == x || root == -x);
+    // 2 is not a square as P = 5 (mod 8)
+    assert_eq!(sqrt(&(BF_TWO * x * x)), None);
+  }
+}
+
+/// Test vectors of appendix J.5.1 of RFC 9380 for `edwards25519_XMD:SHA-512_ELL2_RO_`.
+#[rstest]
+#[case(
+  b"",
+  "3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+  "09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21"
+)]
+#[case(
+  b"abc",
+  "608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+  "1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531"
+)]
+fn hash_to_curve(#[case] msg: &[u8], #[case] x: &str, #[case] y: &str) {
+  use crypto_bigint::U256;
+
+  use super::curve::{BaseField, Coordinate, ScalarField, IDENTITY, SF_ONE};
+
+  let dst = b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_";
+  let point = Coordinate::hash_to_curve(msg, dst);
+  let expected =
+    Coordinate::new(BaseField::new(&U256::from_be_hex(x)), BaseField::new(&U256::from_be_hex(y)));
+  assert_eq!(point, expected);
+  // (L - 1) P + P = L P is the identity in the subgroup of prime order
+  let minus_one = ScalarField::new(&U256::ZERO) - SF_ONE;
+  assert_eq!(point * minus_one + point, IDENTITY);
+}
+
+#[test]
+fn elligator2_cofactor() {
+  use crypto_bigint::U256;
+
+  use super::curve::{BaseField, Coordinate, ScalarField, IDENTITY, SF_ONE};
+
+  let minus_one = ScalarField::new(&U256::ZERO) - SF_ONE;
+  let mut outside_subgroup = 0;
+  for u in 0..16u32 {
+    let point = Coordinate::map_to_curve(BaseField::new(&U256::from_u32(u)));
+    let decoded = Coordinate::decode(point.encode()).unwrap();
+    assert_eq!(decoded, point);
+    if point * minus_one + point != IDENTITY {
+      outside_subgroup += 1;
+    }
+    let cleared = point.double().double().double();
+    assert_eq!(cleared * minus_one + cleared, IDENTITY);
+  }
+  assert!(outside_subgroup > 0);
+}
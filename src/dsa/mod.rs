@@ -2,5 +2,3 @@
 #![doc = include_str!("./README.md")]
 pub mod ecdsa;
 pub mod eddsa;
-
-use super::*;
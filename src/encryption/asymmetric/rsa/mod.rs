@@ -8,15 +8,8 @@
 #![doc = include_str!("./README.md")]
 #[cfg(test)] mod tests;
 
-#[allow(dead_code)]
-/// Computes the modular inverse of e mod totient
-const fn mod_inverse(e: u64, totient: u64) -> u64 {
-  let mut d = 1;
-  while (d * e) % totient != 1 {
-    d += 1;
-  }
-  d
-}
+use crate::algebra::number_theory::{self, gcd, mod_inverse};
+
 /// RSAKey struct
 pub struct RSA {
   /// pub key (e,n)
@@ -57,22 +50,22 @@ impl RSA {
     cipher.pow(self.public_key.d as u32) % self.public_key.n as u32
   }
 }
-/// Key generation for the RSA algorithm
-/// TODO: Implement a secure key generation algorithm using miller rabin primality test
+/// Key generation for the RSA algorithm, checking that `p` and `q` are prime with the Baillie–PSW
+/// test of [`number_theory::is_prime`].
 pub fn rsa_key_gen(p: usize, q: usize) -> RSA {
-  assert!(is_prime(p));
-  assert!(is_prime(q));
+  assert!(number_theory::is_prime(p as u64));
+  assert!(number_theory::is_prime(q as u64));
   let n = p * q;
   let e = generate_e(p, q);
   let totient = euler_totient(p as u64, q as u64);
-  let d = mod_inverse(e, totient);
+  let d = mod_inverse(e, totient).expect("e is coprime to the totient");
   RSA { private_key: PrivateKey { e: e as usize, n }, public_key: PublicKey { d: d as usize, n } }
 }
 
 /// Generates e value for the RSA algorithm
 /// gcd of totient and e must be 1 which is equivalent to: e and totient must be coprime
 #[allow(dead_code)]
-const fn generate_e(p: usize, q: usize) -> u64 {
+fn generate_e(p: usize, q: usize) -> u64 {
   assert!(p > 1 && q > 2, "P and Q must be greater than 1");
   let totient = euler_totient(p as u64, q as u64);
   let mut e = 2;
@@ -86,38 +79,14 @@ const fn generate_e(p: usize, q: usize) -> u64 {
   panic!("Failed to find coprime e; totient should be greater than 1")
 }
 
-/// Generates a random prime number bigger than 1_000_000
+/// Generates a random prime number bigger than 1_000_000, distinct from `first_prime`
 pub fn random_prime(first_prime: usize) -> usize {
   let mut n = 1_000_000;
-  while !is_prime(n) && n != first_prime {
+  while !number_theory::is_prime(n as u64) || n == first_prime {
     n += 1;
   }
   n
 }
 
-/// Primality testing in a constant function for compile time checks
-pub const fn is_prime(n: usize) -> bool {
-  if n <= 1 {
-    return false;
-  }
-  let mut i = 2;
-  while i * i <= n {
-    if n % i == 0 {
-      return false;
-    }
-    i += 1;
-  }
-  true
-}
-
 /// Euler totient: Ψ(x)
 pub const fn euler_totient(prime_1: u64, prime_2: u64) -> u64 { (prime_1 - 1) * (prime_2 - 1) }
-
-/// GCD of two numbers
-pub const fn gcd(a: u64, b: u64) -> u64 {
-  if b == 0 {
-    a
-  } else {
-    gcd(b, a % b)
-  }
-}
@@ -30,12 +30,6 @@ fn key_gen() {
 #[should_panic]
 fn non_prime_key_gen() { rsa_key_gen(100, 200); }
 
-#[test]
-fn test_gcd() {
-  assert_eq!(gcd(10, 5), 5);
-  assert_eq!(gcd(10, 3), 1);
-}
-
 #[test]
 fn test_generate_e() {
   let e = generate_e(PRIME_1, PRIME_2);
@@ -50,9 +44,10 @@ fn test_generate_e() {
 
 #[test]
 fn test_mod_inverse() {
-  assert_eq!(mod_inverse(3, 8), 3);
-  assert_eq!(mod_inverse(5, 12), 5);
-  assert_eq!(mod_inverse(5, 24), 5);
+  assert_eq!(mod_inverse(3, 8), Some(3));
+  assert_eq!(mod_inverse(5, 12), Some(5));
+  assert_eq!(mod_inverse(5, 24), Some(5));
+  assert_eq!(mod_inverse(4, 24), None);
 }
 
 #[test]
@@ -78,13 +73,14 @@ fn test_encrypt_decrypt() {
 #[test]
 fn test_random_prime() {
   let prime = random_prime(2);
-  assert!(is_prime(prime));
+  assert!(number_theory::is_prime(prime as u64));
   assert!(prime >= 1_000_000);
 }
 
 #[test]
 fn test_random_prime_generation() {
   let prime = random_prime(2);
-  assert!(is_prime(prime));
+  assert!(number_theory::is_prime(prime as u64));
   assert!(prime >= 1_000_000);
+  assert_ne!(random_prime(prime), prime);
 }
@@ -6,7 +6,7 @@ pub mod sponge;
 
 pub use sponge::*;
 
-use crate::Field;
+use crate::{algebra::matrix::Matrix, Field};
 
 /// Poseidon config used to instantiate hash function
 #[derive(Debug, Clone)]
@@ -20,8 +20,8 @@ pub struct PoseidonConfig<F: Field> {
   num_p:           usize,
   /// number of full rounds
   num_f:           usize,
-  /// minimum distance separable matrix used to mix the state at linear layer
-  mds_matrix:      Vec<Vec<F>>,
+  /// maximum distance separable matrix used to mix the state at linear layer
+  mds_matrix:      Matrix<F>,
   /// round constants added to state at the beginning of each round
   round_constants: Vec<F>,
 }
@@ -52,7 +52,10 @@ impl<F: Field> PoseidonConfig<F> {
       "round constants should be equal to number of full and partial rounds",
     );
 
-    PoseidonConfig { width, alpha, num_f, num_p, mds_matrix: mds, round_constants: rc }
+    let mds_matrix = Matrix::new(mds);
+    assert!(mds_matrix.is_square(), "mds matrix should be square");
+
+    PoseidonConfig { width, alpha, num_f, num_p, mds_matrix, round_constants: rc }
   }
 }
 
@@ -93,17 +96,11 @@ impl<F: Field> Poseidon<F> {
   }
 
   /// applies linear layer of the round by multiplying the state with MDS matrix to mix the elements
-  fn apply_linear_layer(&mut self) {
-    let mut new_state: Vec<F> = Vec::new();
-    for i in 0..self.config.width {
-      let mut new_state_i = F::ZERO;
-      for (j, state_elem) in self.state.iter().enumerate() {
-        new_state_i += *state_elem * self.config.mds_matrix[i][j];
-      }
-      new_state.push(new_state_i);
-    }
-    self.state = new_state
-  }
+  fn apply_linear_layer(&mut self) { self.state = self.config.mds_matrix.mul_vec(&self.state) }
+
+  /// Returns the matrix of the linear layer. Use [`Matrix::is_mds`] to check that it is maximum
+  /// distance separable.
+  pub fn mds_matrix(&self) -> &Matrix<F> { &self.config.mds_matrix }
 
   /// adds round constants to the state to break the symmetricity in the state
   fn add_round_constants(&mut self, ith: usize) {
@@ -90,6 +90,28 @@ fn hash(mut pluto_poseidon: Poseidon<PlutoBaseField>) {
   assert_eq!(res, PlutoBaseField::new(20));
 }
 
+#[test]
+fn mds_matrix() {
+  // the Cauchy matrix 1 / (i - (j + WIDTH)) is MDS as long as 2 * WIDTH <= p
+  const SMALL_WIDTH: usize = 4;
+  let mds = (0..SMALL_WIDTH)
+    .map(|i| {
+      (0..SMALL_WIDTH)
+        .map(|j| {
+          (PlutoBaseField::from(i) - PlutoBaseField::from(j + SMALL_WIDTH)).inverse().unwrap()
+        })
+        .collect()
+    })
+    .collect();
+  let rc = vec![PlutoBaseField::ONE; (NUM_P + NUM_F) * SMALL_WIDTH];
+  let poseidon = Poseidon::new(SMALL_WIDTH, ALPHA, NUM_P, NUM_F, rc.clone(), mds);
+  assert!(poseidon.mds_matrix().is_mds());
+
+  let mds = vec![vec![PlutoBaseField::ONE; SMALL_WIDTH]; SMALL_WIDTH];
+  let poseidon = Poseidon::new(SMALL_WIDTH, ALPHA, NUM_P, NUM_F, rc, mds);
+  assert!(!poseidon.mds_matrix().is_mds());
+}
+
 #[test]
 #[should_panic]
 fn invalid_poseidon_config_width() {
@@ -2,25 +2,30 @@
 
 use algebra::group::FiniteCyclicGroup;
 
-use self::{curve::pairing::pairing, PlutoScalarField};
+use self::{
+  curve::{jacobian::JacobianPoint, msm::msm, pairing::pairing, subgroup::PrimeOrderPoint},
+  PlutoScalarField,
+};
 use super::*;
 
 /// simple setup to get params.
 #[allow(dead_code, clippy::type_complexity)]
-pub fn setup() -> (Vec<AffinePoint<PlutoExtendedCurve>>, Vec<AffinePoint<PlutoExtendedCurve>>) {
+pub fn setup(
+) -> (Vec<PrimeOrderPoint<PlutoExtendedCurve>>, Vec<PrimeOrderPoint<PlutoExtendedCurve>>) {
   // NOTE: For demonstration purposes only.
 
   // This is just tau from plonk by hand, it is not actually secure
   let tau: PlutoScalarField = PlutoScalarField::new(2);
 
-  let g1 = AffinePoint::<PlutoExtendedCurve>::from(AffinePoint::<PlutoBaseCurve>::GENERATOR);
-  let g2 = AffinePoint::<PlutoExtendedCurve>::GENERATOR;
+  let g1 =
+    PrimeOrderPoint::<PlutoExtendedCurve>::from(PrimeOrderPoint::<PlutoBaseCurve>::GENERATOR);
+  let g2 = PrimeOrderPoint::<PlutoExtendedCurve>::GENERATOR;
   // NOTE: Just sample the d of both for now.
   // - g1 and g2 SRS have variable sizes for diff kzg uses
   // - in eth blobs, g1 is 4096 elements, g2 is 16 elements
   // - in plonk, we need d+5 g1 elements and one g2 element
-  let mut srs_g1_points: Vec<AffinePoint<PlutoExtendedCurve>> = vec![];
-  let mut srs_g2_points: Vec<AffinePoint<PlutoExtendedCurve>> = vec![];
+  let mut srs_g1_points: Vec<PrimeOrderPoint<PlutoExtendedCurve>> = vec![];
+  let mut srs_g2_points: Vec<PrimeOrderPoint<PlutoExtendedCurve>> = vec![];
   for i in 0..7 {
     // G1 Group
 
@@ -43,28 +48,28 @@ pub fn setup() -> (Vec<AffinePoint<PlutoExtendedCurve>>, Vec<AffinePoint<PlutoEx
 }
 
 /// kzg poly commit
-/// Both binding and hiding commitment
+/// Both binding and hiding commitment, computed with a multi-scalar multiplication, see
+/// [`msm`].
 #[allow(dead_code)]
 pub fn commit(
   coeffs: Vec<PlutoScalarField>,
-  g1_srs: Vec<AffinePoint<PlutoExtendedCurve>>,
-) -> AffinePoint<PlutoExtendedCurve> {
+  g1_srs: Vec<PrimeOrderPoint<PlutoExtendedCurve>>,
+) -> PrimeOrderPoint<PlutoExtendedCurve> {
   // check srs is longer than coefs
   assert!(g1_srs.len() >= coeffs.len());
   // SUM_{i=0}^{n} (g1^tau^i * coef_i)
-  g1_srs
-    .into_iter()
-    .zip(coeffs)
-    .map(|(g1, coeff)| g1 * coeff)
-    .sum::<AffinePoint<PlutoExtendedCurve>>()
+  let points: Vec<_> =
+    g1_srs[..coeffs.len()].iter().map(|&g1| JacobianPoint::from(g1.point())).collect();
+  let scalars: Vec<_> = coeffs.into_iter().map(|coeff| [usize::from(coeff) as u64]).collect();
+  PrimeOrderPoint::new_unchecked(msm(&points, &scalars).into())
 }
 
 /// Open the commitment
 pub fn open<const D: usize>(
   coeffs: Vec<PlutoScalarField>,
   eval_point: PlutoScalarField,
-  g1_srs: Vec<AffinePoint<PlutoExtendedCurve>>,
-) -> AffinePoint<PlutoExtendedCurve> {
+  g1_srs: Vec<PrimeOrderPoint<PlutoExtendedCurve>>,
+) -> PrimeOrderPoint<PlutoExtendedCurve> {
   let poly = Polynomial::<Monomial, PlutoScalarField, D>::new(coeffs.try_into().unwrap_or_else(
     |v: Vec<PlutoScalarField>| panic!("Expected a Vec of length {} but it was {}", D, v.len()),
   ));
@@ -79,12 +84,12 @@ pub fn open<const D: usize>(
 
 /// Verify the polynomial evaluation.
 pub fn check(
-  p: AffinePoint<PlutoExtendedCurve>,
-  q: AffinePoint<PlutoExtendedCurve>,
+  p: PrimeOrderPoint<PlutoExtendedCurve>,
+  q: PrimeOrderPoint<PlutoExtendedCurve>,
   point: PlutoScalarField,
   value: PlutoScalarField,
-  g1_srs: Vec<AffinePoint<PlutoExtendedCurve>>,
-  g2_srs: Vec<AffinePoint<PlutoExtendedCurve>>,
+  g1_srs: Vec<PrimeOrderPoint<PlutoExtendedCurve>>,
+  g2_srs: Vec<PrimeOrderPoint<PlutoExtendedCurve>>,
 ) -> bool {
   let g1 = *g1_srs.first().expect("has g1 srs");
 
@@ -92,12 +97,13 @@ pub fn check(
   let g2 = g2_srs[1];
 
   // e(pi, g2 - gen * point)
-  let lhs =
-    pairing::<PlutoExtendedCurve, 17>(q, g2 - AffinePoint::<PlutoExtendedCurve>::GENERATOR * point);
+  let lhs = pairing::<PlutoExtendedCurve, 17>(
+    q.point(),
+    (g2 - PrimeOrderPoint::<PlutoExtendedCurve>::GENERATOR * point).point(),
+  );
 
   // e(p - g1 * value, gen)
-  let rhs =
-    pairing::<PlutoExtendedCurve, 17>(p - g1 * value, AffinePoint::<PlutoExtendedCurve>::GENERATOR);
+  let rhs = pairing::<PlutoExtendedCurve, 17>((p - g1 * value).point(), AffinePoint::GENERATOR);
   println!("lhs {:?}", lhs);
   println!("rhs {:?}", rhs);
 
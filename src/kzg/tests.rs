@@ -1,7 +1,12 @@
-use algebra::group::FiniteCyclicGroup;
+use algebra::group::{FiniteCyclicGroup, Group};
 
 use super::*;
-use crate::{curve::pairing::pairing, PlutoScalarField};
+use crate::{
+  curve::{
+    jacobian::JacobianPoint, msm::FixedBaseMsm, pairing::pairing, subgroup::PrimeOrderPoint,
+  },
+  PlutoScalarField,
+};
 
 #[test]
 fn test_setup() {
@@ -9,43 +14,51 @@ fn test_setup() {
   assert!(g1srs.len() == 7);
   assert!(g2srs.len() == 2);
   let expected_g1srs = vec![
-    AffinePoint::<PlutoExtendedCurve>::new(
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(1usize),
       PlutoBaseFieldExtension::from(2usize),
-    ),
-    AffinePoint::<PlutoExtendedCurve>::new(
+    )
+    .unwrap(),
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(68usize),
       PlutoBaseFieldExtension::from(74usize),
-    ),
-    AffinePoint::<PlutoExtendedCurve>::new(
+    )
+    .unwrap(),
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(65usize),
       PlutoBaseFieldExtension::from(98usize),
-    ),
-    AffinePoint::<PlutoExtendedCurve>::new(
+    )
+    .unwrap(),
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(18usize),
       PlutoBaseFieldExtension::from(49usize),
-    ),
-    AffinePoint::<PlutoExtendedCurve>::new(
+    )
+    .unwrap(),
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(1usize),
       PlutoBaseFieldExtension::from(99usize),
-    ),
-    AffinePoint::<PlutoExtendedCurve>::new(
+    )
+    .unwrap(),
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(68usize),
       PlutoBaseFieldExtension::from(27usize),
-    ),
-    AffinePoint::<PlutoExtendedCurve>::new(
+    )
+    .unwrap(),
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(65usize),
       PlutoBaseFieldExtension::from(3usize),
-    ),
+    )
+    .unwrap(),
   ];
 
   assert_eq!(g1srs, expected_g1srs);
 
-  let expected_2g = AffinePoint::<PlutoExtendedCurve>::new(
+  let expected_2g = PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
     PlutoBaseFieldExtension::new([PlutoBaseField::new(90), PlutoBaseField::ZERO]),
     PlutoBaseFieldExtension::new([PlutoBaseField::ZERO, PlutoBaseField::new(82)]),
-  );
-  let g2_gen = AffinePoint::<PlutoExtendedCurve>::GENERATOR;
+  )
+  .unwrap();
+  let g2_gen = PrimeOrderPoint::<PlutoExtendedCurve>::GENERATOR;
   let expected_g2srs = vec![g2_gen, expected_2g];
 
   assert_eq!(g2srs, expected_g2srs);
@@ -101,7 +114,7 @@ fn test_commit() {
   let coefficients = poly_1().coefficients;
   //  g1srs[0] * 11 + g1srs[1] * 11 + g1srs[2] * 11 + g1srs[3] * 1
   let commit_1 = commit(coefficients.to_vec(), g1srs.clone());
-  assert_eq!(commit_1, AffinePoint::<PlutoExtendedCurve>::Infinity);
+  assert_eq!(commit_1, PrimeOrderPoint::<PlutoExtendedCurve>::IDENTITY);
 
   println!("\n\nSECOND COMMIT");
   // p(x) = (x-1)(x-2)(x-3)(x-4)
@@ -116,10 +129,11 @@ fn test_commit() {
 
   assert_eq!(
     commit_2,
-    AffinePoint::<PlutoExtendedCurve>::new(
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(32usize),
       PlutoBaseFieldExtension::from(59usize),
     )
+    .unwrap()
   );
 
   println!("\n\nTHIRD COMMIT");
@@ -130,13 +144,27 @@ fn test_commit() {
 
   assert_eq!(
     commit_3,
-    AffinePoint::<PlutoExtendedCurve>::new(
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(32usize),
       PlutoBaseFieldExtension::from(59usize),
     )
+    .unwrap()
   );
 }
 
+#[test]
+fn fixed_base_commit() {
+  let (g1srs, _) = setup();
+  let points: Vec<_> = g1srs.iter().map(|&g1| JacobianPoint::from(g1.point())).collect();
+  // scalars of the scalar field are below 17, so fit in 5 bits
+  let table = FixedBaseMsm::new(&points, 5);
+  for coefficients in [poly_1().coefficients.to_vec(), poly_2().coefficients.to_vec()] {
+    let scalars: Vec<_> = coefficients.iter().map(|&c| [usize::from(c) as u64]).collect();
+    let commitment = AffinePoint::from(table.msm(&scalars));
+    assert_eq!(commitment, commit(coefficients, g1srs.clone()).point());
+  }
+}
+
 #[test]
 fn srs_open() {
   let (g1srs, _) = setup();
@@ -147,10 +175,11 @@ fn srs_open() {
   dbg!(sum);
   assert_eq!(
     sum,
-    AffinePoint::<PlutoExtendedCurve>::new(
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(26usize),
       PlutoBaseFieldExtension::from(45usize),
     )
+    .unwrap()
   );
 }
 
@@ -162,7 +191,7 @@ fn opening() {
   let eval_point = PlutoScalarField::new(4);
   //   let eval_result = poly.evaluate(eval_point);
   let commit = commit(poly.coefficients.clone().to_vec(), g1srs.clone());
-  assert_eq!(commit, AffinePoint::<PlutoExtendedCurve>::Infinity);
+  assert_eq!(commit, PrimeOrderPoint::<PlutoExtendedCurve>::IDENTITY);
   // p(x) = (x-1)(x-2)(x-3)
   // p(x) = - 6 + 11x -6x^2 + x^3
 
@@ -173,10 +202,11 @@ fn opening() {
 
   assert_eq!(
     open_commit,
-    AffinePoint::<PlutoExtendedCurve>::new(
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(26usize),
       PlutoBaseFieldExtension::from(45usize),
     )
+    .unwrap()
   );
 }
 
@@ -194,12 +224,12 @@ fn all_srs_combinations() {
     for g2 in &paring_params.g2srs {
       println!("Loop for g2 {:?}", g2);
       let lhs = pairing::<PlutoExtendedCurve, 17>(
-        paring_params.q,
-        *g2 - AffinePoint::<PlutoExtendedCurve>::GENERATOR * paring_params.point,
+        paring_params.q.point(),
+        (*g2 - PrimeOrderPoint::<PlutoExtendedCurve>::GENERATOR * paring_params.point).point(),
       );
 
       let rhs = pairing::<PlutoExtendedCurve, 17>(
-        paring_params.p - g1 * paring_params.value,
+        (paring_params.p - g1 * paring_params.value).point(),
         AffinePoint::<PlutoExtendedCurve>::GENERATOR,
       );
       if lhs == rhs {
@@ -233,20 +263,14 @@ fn e2e<const D: usize>(
   // We can look at `g1srs` and see it is in `G1` and `g2srs` is in `G2`
   dbg!(paring_params.g1srs.first().unwrap());
   for i in 0..17 {
-    println!("{}: {:?}", i, i * *paring_params.g1srs.first().unwrap());
+    println!("{}: {:?}", i, i * paring_params.g1srs[0].point());
   }
-  assert_eq!(
-    17u32 * *paring_params.g1srs.first().unwrap(),
-    AffinePoint::<PlutoExtendedCurve>::Infinity
-  );
+  assert_eq!(17u32 * paring_params.g1srs[0].point(), AffinePoint::<PlutoExtendedCurve>::Infinity);
   dbg!(paring_params.g2srs.first().unwrap());
   for i in 0..17 {
-    println!("{}: {:?}", i, i * *paring_params.g2srs.first().unwrap());
+    println!("{}: {:?}", i, i * paring_params.g2srs[0].point());
   }
-  assert_eq!(
-    17u32 * *paring_params.g2srs.first().unwrap(),
-    AffinePoint::<PlutoExtendedCurve>::Infinity
-  );
+  assert_eq!(17u32 * paring_params.g2srs[0].point(), AffinePoint::<PlutoExtendedCurve>::Infinity);
 
   let valid = check(
     paring_params.p,
@@ -292,7 +316,7 @@ fn fake_proof<const D: usize>(
   let paring_params = commit_and_open(poly, eval_point);
   let valid = check(
     paring_params.p,
-    AffinePoint::<PlutoExtendedCurve>::Infinity, // fake proof
+    PrimeOrderPoint::<PlutoExtendedCurve>::IDENTITY, // fake proof
     paring_params.point,
     paring_params.point,
     paring_params.g1srs.clone(),
@@ -303,12 +327,12 @@ fn fake_proof<const D: usize>(
 
 /// Pairing params for testing pairing
 pub struct PairingParams {
-  pub p:     AffinePoint<PlutoExtendedCurve>,
-  pub q:     AffinePoint<PlutoExtendedCurve>,
+  pub p:     PrimeOrderPoint<PlutoExtendedCurve>,
+  pub q:     PrimeOrderPoint<PlutoExtendedCurve>,
   pub point: PlutoScalarField,
   pub value: PlutoScalarField,
-  pub g1srs: Vec<AffinePoint<PlutoExtendedCurve>>,
-  pub g2srs: Vec<AffinePoint<PlutoExtendedCurve>>,
+  pub g1srs: Vec<PrimeOrderPoint<PlutoExtendedCurve>>,
+  pub g2srs: Vec<PrimeOrderPoint<PlutoExtendedCurve>>,
 }
 
 /// given a polynomial and eval point return the pairing params
@@ -326,12 +350,13 @@ pub fn commit_and_open<const D: usize>(
 #[test]
 fn pairing_params() {
   let params = commit_and_open(poly_1(), PlutoScalarField::new(4));
-  assert_eq!(params.p, AffinePoint::<PlutoExtendedCurve>::Infinity);
+  assert_eq!(params.p, PrimeOrderPoint::<PlutoExtendedCurve>::IDENTITY);
   assert_eq!(
     params.q,
-    AffinePoint::<PlutoExtendedCurve>::new(
+    PrimeOrderPoint::<PlutoExtendedCurve>::from_coordinates(
       PlutoBaseFieldExtension::from(26usize),
       PlutoBaseFieldExtension::from(45usize)
     )
+    .unwrap()
   );
 }
@@ -16,13 +16,16 @@
 //!   [`FiniteField`] traits.
 //! - Includes Discrete Fourier Transform (DFT) for polynomials in the [`Monomial`] basis to convert
 //!   into the [`Lagrange`] basis via evaluation at the roots of unity.
+//! - [`ring::PolyRing`] represents the quotient rings $\mathbb{F}_q\[X\]/(X^N + 1)$ of lattice
+//!   based cryptography, multiplied with the negacyclic NTT.
 
 use std::array;
 
 use super::*;
-use crate::algebra::field::FiniteField;
+use crate::algebra::field::{BatchArithmetic, FiniteField};
 
 pub mod arithmetic;
+pub mod ring;
 #[cfg(test)] mod tests;
 
 // https://people.inf.ethz.ch/gander/papers/changing.pdf
@@ -167,7 +170,7 @@ impl<F: FiniteField, const D: usize> Polynomial<Monomial, F, D> {
   /// - A tuple of two polynomials in [`Monomial`] basis:
   ///   - The first element is the quotient polynomial.
   ///   - The second element is the remainder polynomial.
-  fn quotient_and_remainder<const D2: usize>(
+  pub fn quotient_and_remainder<const D2: usize>(
     self,
     rhs: Polynomial<Monomial, F, D2>,
   ) -> (Self, Self) {
@@ -177,31 +180,7 @@ impl<F: FiniteField, const D: usize> Polynomial<Monomial, F, D> {
     // Initial remainder value is our numerator polynomial
     let mut p_coeffs = self.coefficients.to_vec();
 
-    // Leading coefficient of the denominator
-    let c = rhs.leading_coefficient();
-
-    // Perform the repeated long division algorithm
-    while p_coeffs.iter().filter(|&&x| x != F::ZERO).count() > 0
-      && p_coeffs.len() >= rhs.coefficients.len()
-    {
-      // find degree of dividend, divisor
-      let p_degree = p_coeffs.iter().rposition(|&x| x != F::ZERO).unwrap();
-      let rhs_degree = rhs.coefficients.iter().rposition(|&x| x != F::ZERO).unwrap();
-
-      if p_degree < rhs_degree {
-        break;
-      }
-
-      let diff = p_degree - rhs_degree;
-      let s = p_coeffs[p_degree] * c.inverse().unwrap();
-      q_coeffs[diff] = s;
-
-      for (i, &coeff) in rhs.coefficients.iter().enumerate() {
-        p_coeffs[diff + i] -= coeff * s;
-      }
-
-      Polynomial::<Monomial, F, D>::trim_zeros(&mut p_coeffs);
-    }
+    Self::long_division(&mut p_coeffs, &mut q_coeffs, &rhs.coefficients);
 
     let quotient = Polynomial {
       coefficients: q_coeffs.try_into().unwrap_or_else(|v: Vec<F>| {
@@ -224,6 +203,107 @@ impl<F: FiniteField, const D: usize> Polynomial<Monomial, F, D> {
     (quotient, remainder)
   }
 
+  /// Long division of `p_coeffs` by the nonzero `rhs` in place: the quotient is written to
+  /// `q_coeffs` and the remainder is left in `p_coeffs`, without its leading zeros. The divisor may
+  /// have more coefficients than the dividend, as only its degree matters.
+  fn long_division(p_coeffs: &mut Vec<F>, q_coeffs: &mut [F], rhs: &[F]) {
+    let rhs_degree = rhs.iter().rposition(|&x| x != F::ZERO).expect("division by zero");
+    let c_inverse = rhs[rhs_degree].inverse().unwrap();
+    Self::trim_zeros(p_coeffs);
+    while let Some(p_degree) = p_coeffs.len().checked_sub(1) {
+      if p_degree < rhs_degree {
+        break;
+      }
+      let diff = p_degree - rhs_degree;
+      let s = p_coeffs[p_degree] * c_inverse;
+      q_coeffs[diff] = s;
+      for (i, &coeff) in rhs[..=rhs_degree].iter().enumerate() {
+        p_coeffs[diff + i] -= coeff * s;
+      }
+      Self::trim_zeros(p_coeffs);
+    }
+  }
+
+  /// Returns whether every coefficient of the polynomial is zero.
+  pub fn is_zero(&self) -> bool { self.coefficients.iter().all(|&coeff| coeff == F::ZERO) }
+
+  /// Multiplies every coefficient of the polynomial by `scalar`.
+  pub fn scale(&self, scalar: F) -> Self {
+    Self::new(self.coefficients.map(|coeff| coeff * scalar))
+  }
+
+  /// Multiplies two polynomials whose product has degree less than `D`, so that it keeps `D` terms
+  /// unlike [`Mul`], and can be used where `D` is not generic.
+  ///
+  /// ## Panics
+  /// - If the degree of the product is `D` or more.
+  pub fn mul_within(&self, rhs: &Self) -> Self {
+    let product = self.product(rhs);
+    assert!(
+      product[D..].iter().all(|&coeff| coeff == F::ZERO),
+      "the product has degree {D} or more"
+    );
+    Self::new(array::from_fn(|i| product[i]))
+  }
+
+  /// Multiplies two polynomials and reduces the product modulo `modulus`.
+  ///
+  /// ## Panics
+  /// - If `modulus` is zero.
+  pub fn mul_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+    let mut product = self.product(rhs);
+    let mut quotient = vec![F::ZERO; product.len()];
+    Self::long_division(&mut product, &mut quotient, &modulus.coefficients);
+    Self::new(array::from_fn(|i| product.get(i).copied().unwrap_or(F::ZERO)))
+  }
+
+  /// Raises the polynomial to the power `exponent` modulo `modulus` by square-and-multiply.
+  ///
+  /// ## Panics
+  /// - If `modulus` is zero.
+  pub fn pow_mod(&self, exponent: usize, modulus: &Self) -> Self {
+    let base = *self % *modulus;
+    (0..usize::BITS - exponent.leading_zeros()).rev().fold(
+      Self::from([F::ONE]) % *modulus,
+      |acc, i| {
+        let acc = acc.mul_mod(&acc, modulus);
+        match (exponent >> i) & 1 {
+          1 => acc.mul_mod(&base, modulus),
+          _ => acc,
+        }
+      },
+    )
+  }
+
+  /// Divides the polynomial by its leading coefficient.
+  ///
+  /// ## Panics
+  /// - If the polynomial is zero.
+  pub fn monic(&self) -> Self {
+    self.scale(self.leading_coefficient().inverse().expect("the zero polynomial is not monic"))
+  }
+
+  /// The monic [greatest common divisor](https://en.wikipedia.org/wiki/Polynomial_greatest_common_divisor)
+  /// of two polynomials, not both zero, by the Euclidean algorithm.
+  pub fn gcd(&self, rhs: &Self) -> Self {
+    let (mut a, mut b) = (*self, *rhs);
+    while !b.is_zero() {
+      (a, b) = (b, a % b);
+    }
+    a.monic()
+  }
+
+  /// The `2D - 1` coefficients of the product of two polynomials, by the schoolbook algorithm.
+  fn product(&self, rhs: &Self) -> Vec<F> {
+    let mut product = vec![F::ZERO; 2 * D - 1];
+    for (i, &lhs) in self.coefficients.iter().enumerate().filter(|(_, &c)| c != F::ZERO) {
+      for (j, &rhs) in rhs.coefficients.iter().enumerate() {
+        product[i + j] += lhs * rhs;
+      }
+    }
+    product
+  }
+
   /// Computes the [Discrete Fourier Transform](https://en.wikipedia.org/wiki/Discrete_Fourier_transform)
   /// of the polynomial in the [`Monomial`] basis by evaluating the polynomial at the roots of
   /// unity.
@@ -356,9 +436,9 @@ impl<F: FiniteField, const D: usize> Polynomial<Lagrange<F>, F, D> {
   /// - This function will panic if the field does not have roots of unity for the length of the
   ///   polynomial.
   pub fn new(coefficients: [F; D]) -> Self {
-    // Check that the polynomial degree divides the field order so that there are roots of unity.
+    // Panics in `primitive_root_of_unity` unless the polynomial degree divides the order of the
+    // multiplicative group, i.e. there are roots of unity.
     let n = coefficients.len();
-    assert_eq!((F::ORDER - 1) % n, 0);
     let primitive_root = F::primitive_root_of_unity(n);
     let nodes: Vec<F> = (0..n).map(|i| primitive_root.pow(i)).collect();
     Self { coefficients, basis: Lagrange { nodes } }
@@ -380,38 +460,31 @@ impl<F: FiniteField, const D: usize> Polynomial<Lagrange<F>, F, D> {
   /// - The result of evaluating the polynomial at `x` which is an element of the associated
   ///   [`FiniteField`].
   pub fn evaluate(&self, x: F) -> F {
-    let n = self.coefficients.len();
+    let nodes = &self.basis.nodes;
+
+    // l(x) vanishes on the nodes, where the polynomial takes the value of the coefficient instead
+    if let Some(j) = nodes.iter().position(|&n| n == x) {
+      return self.coefficients[j];
+    }
 
     // w_j = \Pi_{m \neq j} (x_j - x_m)^{-1}
-    let mut weights = vec![F::ONE; n];
-    weights.iter_mut().enumerate().for_each(|(idx, w)| {
-      for m in 0..n {
-        if idx != m {
-          *w *= F::ONE.div(self.basis.nodes[idx] - self.basis.nodes[m]);
-        }
-      }
-    });
+    let weights = F::batch_inverse(std::array::from_fn::<_, D, _>(|j| {
+      (0..D).filter(|&m| m != j).map(|m| nodes[j] - nodes[m]).product()
+    }));
+
+    // (x - x_j)^{-1}, all nonzero as `x` is not a node
+    let denominators = F::batch_inverse(std::array::from_fn::<_, D, _>(|j| x - nodes[j]));
 
     // l(x) = \Pi_{i=0}^{n-1} (x - x_i)
-    let l = move |x: F| {
-      let mut val = F::ONE;
-      for i in 0..n {
-        val *= x - self.basis.nodes[i];
-      }
-      val
-    };
+    let l = nodes.iter().map(|&n| x - n).product::<F>();
 
     // L(x) = l(x) * \Sigma_{j=0}^{n-1}  (w_j / (x - x_j)) y_j
-    l(x)
-      * weights.iter().zip(self.coefficients.iter()).zip(self.basis.nodes.iter()).fold(
-        F::ZERO,
-        |acc, ((w, &c), &n)| {
-          if n == x {
-            return c;
-          }
-          acc + c * *w / (x - n)
-        },
-      )
+    l * (0..D)
+      .map(|j| {
+        let w = weights[j].expect("nodes are distinct");
+        self.coefficients[j] * w * denominators[j].unwrap()
+      })
+      .sum::<F>()
   }
 
   /// Computes the Inverse Fast Fourier Transform (IFFT) of a polynomial in the Lagrange basis.
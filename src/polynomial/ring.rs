@@ -0,0 +1,552 @@
+//! Quotient rings $R_q = \mathbb{F}_q\[X\]/(X^N + 1)$, the rings most lattice based cryptosystems
+//! (Kyber, Dilithium, Falcon, ...) are built on.
+//!
+//! For a power of two `N`, $X^N + 1$ is the $2N$-th cyclotomic polynomial, and multiplying by $X$
+//! rotates the coefficients while negating the one that wraps around, as $X^N = -1$. Products are
+//! thus *negacyclic* convolutions:
+//! $$ c_k = \sum_{i + j = k} a_i b_j - \sum_{i + j = k + N} a_i b_j. $$
+//!
+//! ## Negacyclic NTT
+//! When the field has a primitive $2N$-th root of unity $\psi$, the roots of $X^N + 1$ are the odd
+//! powers $\psi^{2i + 1}$, and evaluating at them maps $R_q$ to $N$ copies of $\mathbb{F}_q$ where
+//! products are computed coordinate by coordinate. This is the number theoretic transform (NTT),
+//! computed in $O(N \log N)$ with butterflies $(x, y) \mapsto (x + \zeta y, x - \zeta y)$ which
+//! split $X^{2m} - \zeta^2$ into $(X^m - \zeta)(X^m + \zeta)$ starting from $X^N + 1 = X^N -
+//! \psi^N$. When the field only has a primitive $N$-th root of unity, e.g. for Kyber's $q = 3329$
+//! and $N = 256$, the last layer is skipped and the NTT is *incomplete*: it leaves $N / 2$ residues
+//! of degree 1, multiplied modulo quadratics $X^2 - \gamma$. The twiddle factors are computed once
+//! per ring in an [`NttTable`]. Other fields fall back to the schoolbook convolution.
+//!
+//! ## Coefficients
+//! Over a [`PrimeField`], coefficients are also read as integers in $(-q/2, q/2]$, which is where
+//! the "small" polynomials of lattice schemes live: they are sampled from the uniform, ternary and
+//! centered binomial distributions, and their size is measured by
+//! [`PolyRing::infinity_norm`]. [`PolyRing::compress`] keeps only the `d` most significant bits of
+//! each coefficient.
+use std::{
+  any::{Any, TypeId},
+  array,
+  collections::HashMap,
+  sync::{Mutex, OnceLock},
+};
+
+use super::*;
+use crate::algebra::{
+  field::FiniteField,
+  limbs::{div_rem_small, sub_small},
+};
+
+/// An element of $\mathbb{F}\[X\]/(X^N + 1)$, stored as its `N` coefficients with the zeroth degree
+/// term first. `N` should be a power of two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PolyRing<F: FiniteField, const N: usize> {
+  /// Coefficients of the reduced polynomial in increasing order of degree.
+  pub coefficients: [F; N],
+}
+
+impl<F: FiniteField, const N: usize> PolyRing<F, N> {
+  /// Whether `N` is a power of two, evaluated once at compile time.
+  const IS_POWER_OF_TWO: () = assert!(N.is_power_of_two(), "N should be a power of two");
+  /// The multiplicative identity.
+  pub const ONE: Self = {
+    let mut coefficients = [F::ZERO; N];
+    coefficients[0] = F::ONE;
+    Self { coefficients }
+  };
+  /// The additive identity.
+  pub const ZERO: Self = Self { coefficients: [F::ZERO; N] };
+
+  /// Creates a new element of the ring from its coefficients.
+  pub fn new(coefficients: [F; N]) -> Self {
+    #[allow(clippy::let_unit_value)]
+    let _ = Self::IS_POWER_OF_TWO;
+    Self { coefficients }
+  }
+
+  /// Returns the monomial $X^k$, which is $\pm X^{k \bmod N}$ as $X^N = -1$.
+  pub fn monomial(k: usize) -> Self {
+    let mut coefficients = [F::ZERO; N];
+    coefficients[k % N] = if (k / N) % 2 == 0 { F::ONE } else { -F::ONE };
+    Self::new(coefficients)
+  }
+
+  /// Returns the polynomial of degree less than `N` representing the element.
+  pub fn to_polynomial(&self) -> Polynomial<Monomial, F, N> {
+    Polynomial::<Monomial, F, N>::new(self.coefficients)
+  }
+
+  /// Returns whether products can be computed with the negacyclic NTT, i.e. whether the field has
+  /// a primitive $N$-th root of unity. The NTT is complete if it also has a primitive $2N$-th root,
+  /// see [`NttTable`].
+  pub fn has_ntt() -> bool { NttTable::<F, N>::layers().is_some() }
+
+  /// Returns the twiddle factors of the negacyclic NTT of the ring, or `None` if the field has no
+  /// primitive $N$-th root of unity. The table is built on first use and shared by all threads, so
+  /// the process holds at most one table for each ring type.
+  pub fn ntt_table() -> Option<&'static NttTable<F, N>>
+  where F: Send + Sync {
+    type Tables = HashMap<TypeId, Option<&'static (dyn Any + Send + Sync)>>;
+    static TABLES: OnceLock<Mutex<Tables>> = OnceLock::new();
+    let mut tables = TABLES.get_or_init(Mutex::default).lock().unwrap();
+    let table = *tables.entry(TypeId::of::<Self>()).or_insert_with(|| {
+      NttTable::<F, N>::new()
+        .map(|table| Box::leak(Box::new(table)) as &'static (dyn Any + Send + Sync))
+    });
+    table.map(|table| table.downcast_ref().expect("tables are keyed by the type of their ring"))
+  }
+
+  /// Computes the negacyclic NTT of the element with the cached [`PolyRing::ntt_table`], see
+  /// [`NttTable::ntt`].
+  ///
+  /// ## Panics
+  /// If the field has no primitive $N$-th root of unity, see [`PolyRing::has_ntt`].
+  pub fn ntt(&self) -> [F; N]
+  where F: Send + Sync {
+    Self::ntt_table().expect("the field should have a primitive N-th root of unity").ntt(self)
+  }
+
+  /// Recovers an element from its negacyclic NTT with the cached [`PolyRing::ntt_table`], see
+  /// [`NttTable::from_ntt`].
+  ///
+  /// ## Panics
+  /// If the field has no primitive $N$-th root of unity, see [`PolyRing::has_ntt`].
+  pub fn from_ntt(values: [F; N]) -> Self
+  where F: Send + Sync {
+    Self::ntt_table()
+      .expect("the field should have a primitive N-th root of unity")
+      .from_ntt(values)
+  }
+
+  /// Multiplies by the schoolbook negacyclic convolution in $O(N^2)$, which works over any field.
+  pub fn schoolbook_mul(&self, rhs: &Self) -> Self {
+    let mut coefficients = [F::ZERO; N];
+    for i in 0..N {
+      for j in 0..N {
+        let product = self.coefficients[i] * rhs.coefficients[j];
+        match i + j < N {
+          true => coefficients[i + j] += product,
+          false => coefficients[i + j - N] -= product,
+        }
+      }
+    }
+    Self::new(coefficients)
+  }
+}
+
+/// The twiddle factors of the negacyclic NTT of $\mathbb{F}\[X\]/(X^N + 1)$, precomputed once per
+/// ring, see [`PolyRing::ntt_table`].
+///
+/// With a primitive $2^{L+1}$-th root of unity $r$, the transform runs $L$ layers of butterflies
+/// and leaves the residues modulo $X^s - \gamma_i$ for $s = N / 2^L$ and
+/// $\gamma_i = r^{2 \operatorname{brv}(i) + 1}$, where $\operatorname{brv}$ reverses the $L$ bits
+/// of `i`. The NTT is *complete* when $r$ is a $2N$-th root: $s = 1$ and the residues are the
+/// evaluations at the roots of $X^N + 1$. When the field only has $N$-th roots of unity, like
+/// Kyber's $q = 3329$ for $N = 256$, the last layer is skipped and products of the $N / 2$ residues
+/// of degree 1 are computed modulo $X^2 - \gamma_i$.
+#[derive(Clone, Debug)]
+pub struct NttTable<F: FiniteField, const N: usize> {
+  /// The number $L$ of layers of butterflies.
+  layers:        u32,
+  /// $r^{\operatorname{brv}(k)}$ for $k < 2^L$, where entry $2^l + b$ is used by block `b` of
+  /// layer `l`.
+  zetas:         [F; N],
+  /// The inverses $r^{-\operatorname{brv}(k)}$ of the zetas.
+  zeta_inverses: [F; N],
+  /// The constants $\gamma_i$ of the residues, for $i < 2^L$.
+  gammas:        [F; N],
+  /// $2^{-L}$, undoing the doubling of every inverse layer.
+  scale:         F,
+}
+
+impl<F: FiniteField, const N: usize> NttTable<F, N> {
+  /// Builds the table, or returns `None` if the field has no primitive $N$-th root of unity.
+  pub fn new() -> Option<Self> {
+    #[allow(clippy::let_unit_value)]
+    let _ = PolyRing::<F, N>::IS_POWER_OF_TWO;
+    let layers = Self::layers()?;
+    // the powers of r, from which the zetas and their inverses are read without any inversion
+    let order = 1 << (layers + 1);
+    let r = F::primitive_root_of_unity(order);
+    let mut powers = vec![F::ONE; order];
+    for i in 1..order {
+      powers[i] = powers[i - 1] * r;
+    }
+    let brv = |k: usize| match layers {
+      0 => 0,
+      _ => k.reverse_bits() >> (usize::BITS - layers),
+    };
+    let table =
+      |f: &dyn Fn(usize) -> F| array::from_fn(|k| if k < 1 << layers { f(k) } else { F::ZERO });
+    Some(Self {
+      layers,
+      zetas: table(&|k| powers[brv(k)]),
+      zeta_inverses: table(&|k| powers[(order - brv(k)) % order]),
+      gammas: table(&|i| powers[2 * brv(i) + 1]),
+      scale: F::from(1 << layers).inverse().expect("2 should be invertible in the field"),
+    })
+  }
+
+  /// Returns whether the NTT is complete, i.e. the field has a primitive $2N$-th root of unity and
+  /// the transform consists of evaluations.
+  pub fn is_complete(&self) -> bool { 1 << self.layers == N }
+
+  /// Computes the negacyclic NTT of an element: each layer splits the residues modulo
+  /// $X^{2m} - \zeta^2$ into the residues modulo $X^m \mp \zeta$ with the butterflies
+  /// $(x, y) \mapsto (x + \zeta y, x - \zeta y)$, starting from $X^N + 1 = X^N - r^{2^L}$.
+  ///
+  /// The residues come out in bit-reversed order: for a complete NTT, entry `i` is the evaluation
+  /// at $\gamma_i = r^{2 \operatorname{brv}(i) + 1}$, and otherwise entries `2i` and `2i + 1` are
+  /// the coefficients of the residue modulo $X^2 - \gamma_i$.
+  pub fn ntt(&self, element: &PolyRing<F, N>) -> [F; N] {
+    let mut values = element.coefficients;
+    for layer in 0..self.layers {
+      let m = N >> (layer + 1);
+      for (block, start) in (0..N).step_by(2 * m).enumerate() {
+        let zeta = self.zetas[(1 << layer) + block];
+        for j in start..start + m {
+          let t = zeta * values[j + m];
+          values[j + m] = values[j] - t;
+          values[j] += t;
+        }
+      }
+    }
+    values
+  }
+
+  /// Recovers an element from its negacyclic NTT, undoing the layers of [`NttTable::ntt`] in
+  /// reverse order with the inverse butterflies $(u, v) \mapsto (u + v, \zeta^{-1}(u - v))$ and
+  /// dividing by $2$ for each layer at the end.
+  pub fn from_ntt(&self, values: [F; N]) -> PolyRing<F, N> {
+    let mut values = values;
+    for layer in (0..self.layers).rev() {
+      let m = N >> (layer + 1);
+      for (block, start) in (0..N).step_by(2 * m).enumerate() {
+        let zeta_inv = self.zeta_inverses[(1 << layer) + block];
+        for j in start..start + m {
+          let t = values[j];
+          values[j] = t + values[j + m];
+          values[j + m] = zeta_inv * (t - values[j + m]);
+        }
+      }
+    }
+    PolyRing::new(values.map(|v| v * self.scale))
+  }
+
+  /// Multiplies two elements in the NTT domain: coordinate by coordinate for a complete NTT, and
+  /// as degree 1 polynomials modulo $X^2 - \gamma_i$ otherwise.
+  pub fn mul_ntt(&self, lhs: &[F; N], rhs: &[F; N]) -> [F; N] {
+    if self.is_complete() {
+      return array::from_fn(|i| lhs[i] * rhs[i]);
+    }
+    let mut product = [F::ZERO; N];
+    for i in 0..N / 2 {
+      let (a0, a1, b0, b1) = (lhs[2 * i], lhs[2 * i + 1], rhs[2 * i], rhs[2 * i + 1]);
+      product[2 * i] = a0 * b0 + self.gammas[i] * a1 * b1;
+      product[2 * i + 1] = a0 * b1 + a1 * b0;
+    }
+    product
+  }
+
+  /// Multiplies two elements with the NTT, in $O(N \log N)$.
+  pub fn mul(&self, lhs: &PolyRing<F, N>, rhs: &PolyRing<F, N>) -> PolyRing<F, N> {
+    self.from_ntt(self.mul_ntt(&self.ntt(lhs), &self.ntt(rhs)))
+  }
+
+  /// The number of layers of the NTT: $\log_2 N$ if the field has a primitive $2N$-th root of
+  /// unity, one less if it only has a primitive $N$-th root, and `None` otherwise.
+  fn layers() -> Option<u32> {
+    let q_minus_one = sub_small(&F::order(), 1);
+    let divides = |n: usize| div_rem_small(&q_minus_one, n as u64).1 == 0;
+    let log_n = N.trailing_zeros();
+    match () {
+      _ if divides(2 * N) => Some(log_n),
+      _ if N >= 2 && divides(N) => Some(log_n - 1),
+      _ => None,
+    }
+  }
+}
+
+impl<const Q: usize, const N: usize> PolyRing<PrimeField<Q>, N> {
+  /// Creates an element from signed integer coefficients, reduced modulo `Q`.
+  pub fn from_signed(coefficients: [i64; N]) -> Self {
+    Self::new(coefficients.map(|c| PrimeField::new((c as i128).rem_euclid(Q as i128) as usize)))
+  }
+
+  /// Returns the centered representatives of the coefficients, in $(-Q/2, Q/2]$.
+  pub fn centered(&self) -> [i64; N] {
+    self.coefficients.map(|c| {
+      let value = usize::from(c);
+      match value > Q / 2 {
+        true => -((Q - value) as i64),
+        false => value as i64,
+      }
+    })
+  }
+
+  /// Returns the largest absolute value of the [`PolyRing::centered`] coefficients.
+  pub fn infinity_norm(&self) -> u64 {
+    self.centered().iter().map(|c| c.unsigned_abs()).max().unwrap_or(0)
+  }
+
+  /// Samples an element with coefficients uniform in $\mathbb{F}_Q$.
+  pub fn sample_uniform<R: Rng + ?Sized>(rng: &mut R) -> Self {
+    Self::new(array::from_fn(|_| rng.gen()))
+  }
+
+  /// Samples an element with coefficients uniform in $\{-1, 0, 1\}$.
+  pub fn sample_ternary<R: Rng + ?Sized>(rng: &mut R) -> Self {
+    Self::from_signed(array::from_fn(|_| rng.gen_range(-1..=1)))
+  }
+
+  /// Samples an element from the centered binomial distribution $B_\eta$: every coefficient is
+  /// $\sum_{i < \eta} (a_i - b_i)$ for uniform bits $a_i, b_i$, so lies in $[-\eta, \eta]$ with
+  /// variance $\eta / 2$. This is how Kyber samples its secrets and errors.
+  pub fn sample_cbd<R: Rng + ?Sized>(eta: usize, rng: &mut R) -> Self {
+    Self::from_signed(array::from_fn(|_| {
+      (0..eta).map(|_| rng.gen::<bool>() as i64 - rng.gen::<bool>() as i64).sum()
+    }))
+  }
+
+  /// Compresses every coefficient to `d < 64` bits as $\lceil (2^d / Q) x \rfloor \bmod 2^d$.
+  /// [`PolyRing::decompress`] recovers each coefficient up to $\lceil Q / 2^{d+1} \rfloor$.
+  pub fn compress(&self, d: u32) -> [u64; N] {
+    self.coefficients.map(|c| {
+      let x = usize::from(c) as u128;
+      let rounded = ((x << d) + Q as u128 / 2) / Q as u128;
+      (rounded % (1 << d)) as u64
+    })
+  }
+
+  /// Decompresses `d`-bit values back to coefficients as $\lceil (Q / 2^d) y \rfloor$, the inverse
+  /// of [`PolyRing::compress`] up to rounding.
+  pub fn decompress(values: [u64; N], d: u32) -> Self {
+    Self::new(values.map(|y| {
+      let x = (y as u128 * Q as u128 + (1 << d) / 2) >> d;
+      PrimeField::new(x as usize)
+    }))
+  }
+}
+
+impl<F: FiniteField, const N: usize> Default for PolyRing<F, N> {
+  fn default() -> Self { Self::ZERO }
+}
+
+impl<F: FiniteField, const N: usize, const D: usize> From<Polynomial<Monomial, F, D>>
+  for PolyRing<F, N>
+{
+  /// Reduces a polynomial of any degree modulo $X^N + 1$.
+  fn from(polynomial: Polynomial<Monomial, F, D>) -> Self {
+    let mut coefficients = [F::ZERO; N];
+    for (i, &c) in polynomial.coefficients.iter().enumerate() {
+      match (i / N) % 2 == 0 {
+        true => coefficients[i % N] += c,
+        false => coefficients[i % N] -= c,
+      }
+    }
+    Self::new(coefficients)
+  }
+}
+
+impl<F: FiniteField, const N: usize> Add for PolyRing<F, N> {
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self {
+    Self::new(array::from_fn(|i| self.coefficients[i] + rhs.coefficients[i]))
+  }
+}
+
+impl<F: FiniteField, const N: usize> AddAssign for PolyRing<F, N> {
+  fn add_assign(&mut self, rhs: Self) { *self = *self + rhs; }
+}
+
+impl<F: FiniteField, const N: usize> Sub for PolyRing<F, N> {
+  type Output = Self;
+
+  fn sub(self, rhs: Self) -> Self {
+    Self::new(array::from_fn(|i| self.coefficients[i] - rhs.coefficients[i]))
+  }
+}
+
+impl<F: FiniteField, const N: usize> SubAssign for PolyRing<F, N> {
+  fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs; }
+}
+
+impl<F: FiniteField, const N: usize> Neg for PolyRing<F, N> {
+  type Output = Self;
+
+  fn neg(self) -> Self { Self::new(self.coefficients.map(|c| -c)) }
+}
+
+impl<F: FiniteField + Send + Sync, const N: usize> Mul for PolyRing<F, N> {
+  type Output = Self;
+
+  /// Multiplies with the cached [`PolyRing::ntt_table`] if the field has a primitive $N$-th root
+  /// of unity, and by the schoolbook negacyclic convolution otherwise.
+  fn mul(self, rhs: Self) -> Self {
+    match Self::ntt_table() {
+      Some(table) => table.mul(&self, &rhs),
+      None => self.schoolbook_mul(&rhs),
+    }
+  }
+}
+
+impl<F: FiniteField + Send + Sync, const N: usize> MulAssign for PolyRing<F, N> {
+  fn mul_assign(&mut self, rhs: Self) { *self = *self * rhs; }
+}
+
+impl<F: FiniteField, const N: usize> Mul<F> for PolyRing<F, N> {
+  type Output = Self;
+
+  fn mul(self, rhs: F) -> Self { Self::new(self.coefficients.map(|c| c * rhs)) }
+}
+
+#[cfg(test)]
+mod tests {
+  use rand::{rngs::StdRng, SeedableRng};
+
+  use super::*;
+  use crate::algebra::field::big_prime::Bn254ScalarField;
+
+  /// `97 - 1 = 2^5 * 3`, so rings up to `N = 16` have a negacyclic NTT.
+  type F97 = PrimeField<97>;
+  /// Dilithium's modulus `2^23 - 2^13 + 1`, with a primitive 512-th root of unity.
+  type Dilithium = PolyRing<PrimeField<8_380_417>, 256>;
+  /// Kyber's modulus `3329`, which only has 256-th roots of unity.
+  type Kyber = PolyRing<PrimeField<3329>, 256>;
+
+  #[test]
+  fn negacyclic_wrap() {
+    let x = PolyRing::<F97, 8>::monomial(1);
+    let mut power = PolyRing::ONE;
+    for _ in 0..8 {
+      power *= x;
+    }
+    assert_eq!(power, -PolyRing::ONE);
+    assert_eq!(PolyRing::<F97, 8>::monomial(8), -PolyRing::ONE);
+    assert_eq!(PolyRing::<F97, 8>::monomial(11), -PolyRing::monomial(3));
+    assert_eq!(PolyRing::<F97, 8>::monomial(19), PolyRing::monomial(3));
+  }
+
+  #[test]
+  fn reduction_of_polynomial_product() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let a = PolyRing::<F97, 8>::sample_uniform(&mut rng);
+    let b = PolyRing::<F97, 8>::sample_uniform(&mut rng);
+    let product = a.to_polynomial() * b.to_polynomial();
+    assert_eq!(PolyRing::<F97, 8>::from(product), a * b);
+    assert_eq!(PolyRing::<F97, 8>::from(product), a.schoolbook_mul(&b));
+  }
+
+  #[test]
+  fn ntt_evaluates_at_roots() {
+    let mut rng = StdRng::seed_from_u64(1);
+    let a = PolyRing::<F97, 16>::sample_uniform(&mut rng);
+    let psi = F97::primitive_root_of_unity(32);
+    let evaluations = a.ntt();
+    for (i, &e) in evaluations.iter().enumerate() {
+      let brv = i.reverse_bits() >> (usize::BITS - 4);
+      let root = psi.pow(2 * brv + 1);
+      assert_eq!(root.pow(16), -F97::ONE);
+      assert_eq!(a.to_polynomial().evaluate(root), e);
+    }
+    assert_eq!(PolyRing::from_ntt(evaluations), a);
+  }
+
+  #[test]
+  fn ntt_mul() {
+    assert!(Dilithium::ntt_table().unwrap().is_complete());
+    let mut rng = StdRng::seed_from_u64(2);
+    let a = Dilithium::sample_uniform(&mut rng);
+    let b = Dilithium::sample_uniform(&mut rng);
+    assert_eq!(Dilithium::from_ntt(a.ntt()), a);
+    assert_eq!(a * b, a.schoolbook_mul(&b));
+    assert_eq!(a * (b + Dilithium::ONE), a * b + a);
+  }
+
+  #[test]
+  fn incomplete_ntt() {
+    assert!(Kyber::has_ntt());
+    assert!(!Kyber::ntt_table().unwrap().is_complete());
+    let mut rng = StdRng::seed_from_u64(4);
+    let a = Kyber::sample_uniform(&mut rng);
+    let b = Kyber::sample_uniform(&mut rng);
+    assert_eq!(Kyber::from_ntt(a.ntt()), a);
+    assert_eq!(a * b, a.schoolbook_mul(&b));
+    assert_eq!(a * Kyber::monomial(256), -a);
+
+    // entries 2i and 2i + 1 are the residue modulo X^2 - γ_i, with γ_i = ζ^{2 brv(i) + 1} for a
+    // primitive 256-th root of unity ζ, so X^2 reduces to γ_i
+    let residues = a.ntt();
+    let zeta = PrimeField::<3329>::primitive_root_of_unity(256);
+    for i in 0..128_usize {
+      let gamma = zeta.pow(2 * (i.reverse_bits() >> (usize::BITS - 7)) + 1);
+      let reduce = |parity: usize| {
+        (0..128).map(|k| a.coefficients[2 * k + parity] * gamma.pow(k)).sum::<PrimeField<3329>>()
+      };
+      assert_eq!([residues[2 * i], residues[2 * i + 1]], [reduce(0), reduce(1)]);
+    }
+  }
+
+  #[test]
+  fn no_ntt() {
+    // 7 - 1 = 6 has no factor 4, so products fall back to the schoolbook convolution
+    type Ring = PolyRing<PrimeField<7>, 4>;
+    assert!(!Ring::has_ntt());
+    assert!(Ring::ntt_table().is_none());
+    let mut rng = StdRng::seed_from_u64(5);
+    let a = Ring::sample_uniform(&mut rng);
+    assert_eq!(a * Ring::monomial(4), -a);
+    assert_eq!(PolyRing::<PrimeField<7>, 2>::ntt_table().map(NttTable::is_complete), Some(false));
+    // while X^2 + 1 is left as a single residue of degree 1
+    let (b, c) =
+      (PolyRing::<PrimeField<7>, 2>::from_signed([1, 2]), PolyRing::from_signed([3, -1]));
+    assert_eq!(b * c, b.schoolbook_mul(&c));
+  }
+
+  #[test]
+  fn big_field_ntt() {
+    type Ring = PolyRing<Bn254ScalarField, 16>;
+    assert!(Ring::has_ntt());
+    let mut rng = StdRng::seed_from_u64(3);
+    let a = Ring::new(array::from_fn(|_| rng.gen()));
+    let b = Ring::new(array::from_fn(|_| rng.gen()));
+    assert_eq!(Ring::from_ntt(a.ntt()), a);
+    assert_eq!(a * b, a.schoolbook_mul(&b));
+  }
+
+  #[test]
+  fn centered_coefficients() {
+    let a = PolyRing::<F97, 4>::from_signed([-48, 48, -1, 100]);
+    assert_eq!(a.coefficients, [49, 48, 96, 3].map(F97::new));
+    assert_eq!(a.centered(), [49 - 97, 48, -1, 3]);
+    assert_eq!(a.infinity_norm(), 48);
+  }
+
+  #[rstest]
+  #[case(1)]
+  #[case(2)]
+  #[case(3)]
+  fn small_distributions(#[case] eta: usize) {
+    let mut rng = StdRng::seed_from_u64(eta as u64);
+    assert!(Kyber::sample_ternary(&mut rng).infinity_norm() <= 1);
+    let e = Kyber::sample_cbd(eta, &mut rng);
+    assert!(e.infinity_norm() <= eta as u64);
+    assert!(e.infinity_norm() > 0);
+  }
+
+  #[rstest]
+  #[case(1)]
+  #[case(4)]
+  #[case(10)]
+  #[case(11)]
+  fn compression(#[case] d: u32) {
+    let mut rng = StdRng::seed_from_u64(d as u64);
+    let a = Kyber::sample_uniform(&mut rng);
+    let compressed = a.compress(d);
+    assert!(compressed.iter().all(|&y| y < 1 << d));
+    let error = (Kyber::decompress(compressed, d) - a).infinity_norm();
+    assert!(error <= (3329 + (1 << d)) / (1 << (d + 1)));
+    // decompression followed by compression is the identity
+    assert_eq!(Kyber::decompress(compressed, d).compress(d), compressed);
+  }
+}
@@ -41,6 +41,12 @@ fn lagrange_evaluation(poly: Polynomial<Monomial, PlutoBaseField, 4>) {
   // Should get: 1 + 2*(2) + 3*(2)^2 + 4*(2)^3= 49
   let r = lagrange.evaluate(PlutoBaseField::new(2));
   assert_eq!(r, PlutoBaseField::new(49));
+
+  // At the nodes the polynomial takes the values it is given by
+  for (&node, &value) in lagrange.basis.nodes.iter().zip(lagrange.coefficients.iter()) {
+    assert_eq!(lagrange.evaluate(node), value);
+    assert_eq!(poly.evaluate(node), value);
+  }
 }
 
 #[test]
@@ -114,6 +120,24 @@ fn trim_zeros() {
   ]);
 }
 
+#[rstest]
+fn modular_arithmetic(poly: Polynomial<Monomial, PlutoBaseField, 4>) {
+  let f = |coeffs: [usize; 4]| {
+    Polynomial::<Monomial, PlutoBaseField, 4>::new(coeffs.map(PlutoBaseField::new))
+  };
+  // x^2 + 2, with more terms than the remainders
+  let modulus = f([2, 0, 1, 0]);
+  // 4x^3 + 3x^2 + 2x + 1 = -8x - 6 + 2x + 1 modulo x^2 + 2
+  assert_eq!(poly % modulus, f([96, 95, 0, 0]));
+  assert_eq!(poly.mul_mod(&poly, &modulus), (poly % modulus).pow_mod(2, &modulus));
+  assert_eq!(f([1, 1, 0, 0]).mul_within(&f([100, 1, 0, 0])), f([100, 0, 1, 0]));
+  // x^101 = -x in F_101[x]/(x^2 + 2)
+  assert_eq!(f([0, 1, 0, 0]).pow_mod(101, &modulus), f([0, 100, 0, 0]));
+  // gcd((x - 1)(x - 2), 3(x - 1)(x - 3)) = x - 1
+  assert_eq!(f([2, 98, 1, 0]).gcd(&f([9, 89, 3, 0])), f([100, 1, 0, 0]));
+  assert!(f([0; 4]).is_zero() && !poly.is_zero());
+}
+
 #[rstest]
 fn dft(poly: Polynomial<Monomial, PlutoBaseField, 4>) {
   assert_eq!(poly.dft().coefficients, [